@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use mewt::LanguageEngine;
+use mewt::languages::javascript::engine::JavaScriptLanguageEngine;
+use mewt::languages::solidity::engine::SolidityLanguageEngine;
+use mewt::types::{Hash, Target};
+use mewt::utils::{LineIndex, calculate_line_offset};
+
+const SIZES: &[usize] = &[50, 200, 800];
+
+fn solidity_source(functions: usize) -> String {
+    let mut src = String::from("pragma solidity ^0.8.0;\n\ncontract Bench {\n");
+    for i in 0..functions {
+        src.push_str(&format!(
+            "    function f{i}(uint a, uint b) public returns (uint) {{\n\
+             \x20       uint c = a + b * {i};\n\
+             \x20       if (a > b && c != 0) {{\n\
+             \x20           c -= a << 1;\n\
+             \x20       }}\n\
+             \x20       while (c < b) {{\n\
+             \x20           c += 1;\n\
+             \x20           if (c == {i}) {{ break; }}\n\
+             \x20       }}\n\
+             \x20       require(c >= a, \"underflow\");\n\
+             \x20       return helper(a, b) | c;\n\
+             \x20   }}\n"
+        ));
+    }
+    src.push_str("}\n");
+    src
+}
+
+fn javascript_source(functions: usize) -> String {
+    let mut src = String::new();
+    for i in 0..functions {
+        src.push_str(&format!(
+            "function f{i}(a, b) {{\n\
+             \x20 let c = a + b * {i};\n\
+             \x20 if (a > b || c !== 0) {{\n\
+             \x20   c -= a >> 1;\n\
+             \x20 }}\n\
+             \x20 for (let j = 0; j < b; j++) {{\n\
+             \x20   if (j === {i}) {{ continue; }}\n\
+             \x20   c += helper(a, j);\n\
+             \x20 }}\n\
+             \x20 return c % 7 === 0 ? true : false;\n\
+             }}\n"
+        ));
+    }
+    src
+}
+
+fn target(path: &str, language: &str, text: String) -> Target {
+    Target {
+        id: 0,
+        path: PathBuf::from(path),
+        file_hash: Hash::digest(text.clone()),
+        text,
+        language: language.to_string(),
+    }
+}
+
+fn bench_generation(c: &mut Criterion) {
+    let solidity = SolidityLanguageEngine::new();
+    let javascript = JavaScriptLanguageEngine::new();
+
+    let mut group = c.benchmark_group("generate_mutants");
+    for &size in SIZES {
+        let sol = target("Bench.sol", "Solidity", solidity_source(size));
+        group.throughput(Throughput::Bytes(sol.text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("solidity", size), &sol, |b, t| {
+            b.iter(|| solidity.apply_all_mutations(black_box(t)))
+        });
+
+        let js = target("bench.js", "JavaScript", javascript_source(size));
+        group.throughput(Throughput::Bytes(js.text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("javascript", size), &js, |b, t| {
+            b.iter(|| javascript.apply_all_mutations(black_box(t)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_line_lookup(c: &mut Criterion) {
+    let source = solidity_source(SIZES[SIZES.len() - 1]);
+    let offsets: Vec<usize> = (0..source.len()).step_by(97).collect();
+
+    let mut group = c.benchmark_group("line_lookup");
+    group.bench_function("calculate_line_offset", |b| {
+        b.iter(|| {
+            offsets
+                .iter()
+                .map(|&o| calculate_line_offset(black_box(&source), o))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("line_index", |b| {
+        b.iter(|| {
+            let index = LineIndex::new(black_box(&source));
+            offsets.iter().map(|&o| index.line_offset(o)).sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_generation, bench_line_lookup);
+criterion_main!(benches);
@@ -10,9 +10,11 @@ fn build_grammar(dir: &PathBuf, lib_name: &str) {
         build.file(scanner_c.clone());
     }
 
-    // Suppress the specific warning from vendored tree-sitter code
+    // Suppress the specific warnings from vendored tree-sitter code; the Python
+    // scanner (also used by Vyper) discards the result of `array_pop`
     if build.get_compiler().is_like_clang() || build.get_compiler().is_like_gnu() {
         build.flag("-Wno-unused-but-set-variable");
+        build.flag("-Wno-unused-value");
     }
 
     // Compile to object file and link directly
@@ -71,4 +73,28 @@ fn main() {
     // Build TSX grammar (TypeScript + JSX)
     let tsx_dir: PathBuf = ["grammars", "tsx", "src"].iter().collect();
     build_grammar(&tsx_dir, "tree-sitter-tsx");
+
+    // Build Python grammar
+    let python_dir: PathBuf = ["grammars", "python", "src"].iter().collect();
+    build_grammar(&python_dir, "tree-sitter-python");
+
+    // Build Vyper grammar (extends the Python grammar)
+    let vyper_dir: PathBuf = ["grammars", "vyper", "src"].iter().collect();
+    build_grammar(&vyper_dir, "tree-sitter-vyper");
+
+    // Build Cairo grammar
+    let cairo_dir: PathBuf = ["grammars", "cairo", "src"].iter().collect();
+    build_grammar(&cairo_dir, "tree-sitter-cairo");
+
+    // Build Move grammar
+    let move_dir: PathBuf = ["grammars", "move", "src"].iter().collect();
+    build_grammar(&move_dir, "tree-sitter-move");
+
+    // Build C grammar
+    let c_dir: PathBuf = ["grammars", "c", "src"].iter().collect();
+    build_grammar(&c_dir, "tree-sitter-c");
+
+    // Build C++ grammar
+    let cpp_dir: PathBuf = ["grammars", "cpp", "src"].iter().collect();
+    build_grammar(&cpp_dir, "tree-sitter-cpp");
 }
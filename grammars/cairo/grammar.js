@@ -0,0 +1,1020 @@
+/**
+ * @file Cairo grammar for tree-sitter
+ * @license MIT
+ * @see {@link https://book.cairo-lang.org|The Cairo Programming Language}
+ *
+ * Node kinds follow the vendored Rust grammar where the two languages agree, so the
+ * Cairo engine reads like the Rust one. Cairo's own syntax (short strings, snapshots,
+ * `ref` parameters, `impl Name of Trait`, typed enum variants) has its own rules.
+ * Maintained in this repo; regenerate src/ with `bash grammars/update.sh cairo false`.
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+const PREC = {
+  call: 15,
+  field: 14,
+  try: 13,
+  unary: 12,
+  multiplicative: 10,
+  additive: 9,
+  bitand: 7,
+  bitxor: 6,
+  bitor: 5,
+  comparative: 4,
+  and: 3,
+  or: 2,
+  range: 1,
+  assign: 0,
+  closure: -1,
+};
+
+const INTEGER_TYPES = [
+  'u8', 'u16', 'u32', 'u64', 'u128', 'u256', 'usize',
+  'i8', 'i16', 'i32', 'i64', 'i128', 'felt252',
+];
+
+const TOKEN_TREE_PUNCTUATION = [
+  '+', '-', '*', '/', '%', '^', '!', '&', '|', '&&', '||', '+=', '-=', '*=', '/=', '%=',
+  '^=', '&=', '|=', '=', '==', '!=', '>', '<', '>=', '<=', '@', '_', '.', '..', '..=', ',',
+  ';', ':', '::', '->', '=>', '#', '?', '~', '$',
+];
+
+module.exports = grammar({
+  name: 'cairo',
+
+  extras: $ => [/\s/, $.line_comment],
+
+  word: $ => $.identifier,
+
+  supertypes: $ => [
+    $._expression,
+    $._type,
+    $._literal,
+    $._pattern,
+    $._declaration_statement,
+  ],
+
+  inline: $ => [
+    $._path,
+    $._type_identifier,
+    $._field_identifier,
+    $._non_delim_token,
+    $._expression_ending_with_block,
+  ],
+
+  rules: {
+    source_file: $ => repeat($._statement),
+
+    _statement: $ => choice(
+      $.expression_statement,
+      $._declaration_statement,
+    ),
+
+    empty_statement: _ => ';',
+
+    expression_statement: $ => choice(
+      seq($._expression, ';'),
+      prec(1, $._expression_ending_with_block),
+    ),
+
+    _declaration_statement: $ => choice(
+      $.const_item,
+      $.macro_invocation,
+      $.empty_statement,
+      $.attribute_item,
+      $.inner_attribute_item,
+      $.mod_item,
+      $.struct_item,
+      $.enum_item,
+      $.type_item,
+      $.function_item,
+      $.function_signature_item,
+      $.impl_item,
+      $.impl_alias_item,
+      $.trait_item,
+      $.associated_type,
+      $.let_declaration,
+      $.use_declaration,
+    ),
+
+    // Section - Token trees
+
+    delim_token_tree: $ => choice(
+      seq('(', repeat($._delim_tokens), ')'),
+      seq('[', repeat($._delim_tokens), ']'),
+      seq('{', repeat($._delim_tokens), '}'),
+    ),
+
+    _delim_tokens: $ => choice(
+      $._non_delim_token,
+      alias($.delim_token_tree, $.token_tree),
+    ),
+
+    _non_delim_token: $ => choice(
+      $._literal, $.identifier, $.mutable_specifier, $.self, $.super, $.crate,
+      prec.right(repeat1(choice(...TOKEN_TREE_PUNCTUATION))),
+      'as', 'break', 'const', 'continue', 'else', 'enum', 'extern', 'fn', 'for', 'if', 'impl',
+      'let', 'loop', 'match', 'mod', 'nopanic', 'of', 'pub', 'ref', 'return', 'struct',
+      'trait', 'type', 'use', 'while',
+    ),
+
+    // Section - Declarations
+
+    // `#[starknet::contract]`, `#[derive(Drop, Serde)]`
+    attribute_item: $ => seq(
+      '#',
+      '[',
+      $.attribute,
+      ']',
+    ),
+
+    inner_attribute_item: $ => seq(
+      '#',
+      '!',
+      '[',
+      $.attribute,
+      ']',
+    ),
+
+    attribute: $ => seq(
+      $._path,
+      optional(choice(
+        seq('=', field('value', $._expression)),
+        field('arguments', alias($.delim_token_tree, $.token_tree)),
+      )),
+    ),
+
+    mod_item: $ => seq(
+      optional($.visibility_modifier),
+      'mod',
+      field('name', $.identifier),
+      choice(
+        ';',
+        field('body', $.declaration_list),
+      ),
+    ),
+
+    declaration_list: $ => seq(
+      '{',
+      repeat($._declaration_statement),
+      '}',
+    ),
+
+    struct_item: $ => seq(
+      optional($.visibility_modifier),
+      'struct',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      choice(
+        field('body', $.field_declaration_list),
+        ';',
+      ),
+    ),
+
+    field_declaration_list: $ => seq(
+      '{',
+      sepBy(',', seq(repeat($.attribute_item), $.field_declaration)),
+      optional(','),
+      '}',
+    ),
+
+    field_declaration: $ => seq(
+      optional($.visibility_modifier),
+      field('name', $._field_identifier),
+      ':',
+      field('type', $._type),
+    ),
+
+    enum_item: $ => seq(
+      optional($.visibility_modifier),
+      'enum',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      field('body', $.enum_variant_list),
+    ),
+
+    enum_variant_list: $ => seq(
+      '{',
+      sepBy(',', seq(repeat($.attribute_item), $.enum_variant)),
+      optional(','),
+      '}',
+    ),
+
+    // `Transfer: Transfer`, `None`
+    enum_variant: $ => seq(
+      field('name', $.identifier),
+      optional(seq(':', field('type', $._type))),
+    ),
+
+    type_item: $ => seq(
+      optional($.visibility_modifier),
+      'type',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      '=',
+      field('type', $._type),
+      ';',
+    ),
+
+    associated_type: $ => seq(
+      'type',
+      field('name', $._type_identifier),
+      ';',
+    ),
+
+    const_item: $ => seq(
+      optional($.visibility_modifier),
+      'const',
+      field('name', $.identifier),
+      ':',
+      field('type', $._type),
+      optional(seq('=', field('value', $._expression))),
+      ';',
+    ),
+
+    use_declaration: $ => seq(
+      optional($.visibility_modifier),
+      'use',
+      field('argument', $._use_clause),
+      ';',
+    ),
+
+    _use_clause: $ => choice(
+      $._path,
+      $.use_as_clause,
+      $.use_list,
+      $.scoped_use_list,
+      $.use_wildcard,
+    ),
+
+    scoped_use_list: $ => seq(
+      field('path', optional($._path)),
+      '::',
+      field('list', $.use_list),
+    ),
+
+    use_list: $ => seq(
+      '{',
+      sepBy(',', $._use_clause),
+      optional(','),
+      '}',
+    ),
+
+    use_as_clause: $ => seq(
+      field('path', $._path),
+      'as',
+      field('alias', $.identifier),
+    ),
+
+    use_wildcard: $ => seq(
+      optional(seq($._path, '::')),
+      '*',
+    ),
+
+    trait_item: $ => seq(
+      optional($.visibility_modifier),
+      'trait',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      field('body', $.declaration_list),
+    ),
+
+    // `impl ERC20Impl<T, +Drop<T>> of IERC20<T> { ... }`
+    impl_item: $ => seq(
+      optional($.visibility_modifier),
+      'impl',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      'of',
+      field('trait', $._type),
+      field('body', $.declaration_list),
+    ),
+
+    // `impl OwnableImpl = OwnableComponent::OwnableImpl<ContractState>;`
+    impl_alias_item: $ => seq(
+      optional($.visibility_modifier),
+      'impl',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      '=',
+      field('value', $._type),
+      ';',
+    ),
+
+    function_item: $ => seq(
+      optional($.visibility_modifier),
+      optional('extern'),
+      'fn',
+      field('name', $.identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      field('parameters', $.parameters),
+      optional(seq('->', field('return_type', $._type))),
+      optional($.implicits),
+      optional('nopanic'),
+      field('body', $.block),
+    ),
+
+    function_signature_item: $ => seq(
+      optional($.visibility_modifier),
+      optional('extern'),
+      'fn',
+      field('name', $.identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      field('parameters', $.parameters),
+      optional(seq('->', field('return_type', $._type))),
+      optional($.implicits),
+      optional('nopanic'),
+      ';',
+    ),
+
+    implicits: $ => seq(
+      'implicits',
+      '(',
+      sepBy(',', $._type),
+      optional(','),
+      ')',
+    ),
+
+    type_parameters: $ => seq(
+      '<',
+      sepBy1(',', seq(
+        repeat($.attribute_item),
+        choice(
+          $._type_identifier,
+          $.const_parameter,
+          $.impl_parameter,
+          $.constraint_parameter,
+        ),
+      )),
+      optional(','),
+      '>',
+    ),
+
+    // `const N: usize`
+    const_parameter: $ => seq(
+      'const',
+      field('name', $.identifier),
+      ':',
+      field('type', $._type),
+    ),
+
+    // `impl TDrop: Drop<T>`
+    impl_parameter: $ => seq(
+      'impl',
+      field('name', $.identifier),
+      ':',
+      field('trait', $._type),
+    ),
+
+    // `+Drop<T>`, `-Copy<T>`
+    constraint_parameter: $ => seq(
+      choice('+', '-'),
+      field('trait', $._type),
+    ),
+
+    parameters: $ => seq(
+      '(',
+      sepBy(',', seq(
+        repeat($.attribute_item),
+        $.parameter,
+      )),
+      optional(','),
+      ')',
+    ),
+
+    // `ref self: ContractState`, `mut amount: u256`
+    parameter: $ => seq(
+      optional(field('modifier', choice($.ref_modifier, $.mutable_specifier))),
+      field('pattern', choice($.self, $.identifier, '_')),
+      ':',
+      field('type', $._type),
+    ),
+
+    ref_modifier: _ => 'ref',
+
+    visibility_modifier: $ => prec.right(seq(
+      'pub',
+      optional(seq('(', 'crate', ')')),
+    )),
+
+    // Section - Types
+
+    _type: $ => choice(
+      $._type_identifier,
+      $.scoped_type_identifier,
+      $.generic_type,
+      $.tuple_type,
+      $.unit_type,
+      $.snapshot_type,
+      $.array_type,
+      $.never_type,
+    ),
+
+    scoped_type_identifier: $ => seq(
+      field('path', optional(choice(
+        $._path,
+        $.generic_type,
+      ))),
+      '::',
+      field('name', $._type_identifier),
+    ),
+
+    generic_type: $ => prec(1, seq(
+      field('type', choice(
+        $._type_identifier,
+        $.scoped_type_identifier,
+      )),
+      field('type_arguments', $.type_arguments),
+    )),
+
+    type_arguments: $ => seq(
+      token(prec(1, '<')),
+      sepBy1(',', choice(
+        $._type,
+        $._literal,
+        $.block,
+      )),
+      optional(','),
+      '>',
+    ),
+
+    tuple_type: $ => seq(
+      '(',
+      sepBy1(',', $._type),
+      optional(','),
+      ')',
+    ),
+
+    unit_type: _ => seq('(', ')'),
+
+    // `@ContractState`
+    snapshot_type: $ => seq(
+      '@',
+      field('type', $._type),
+    ),
+
+    // `[u32; 4]`
+    array_type: $ => seq(
+      '[',
+      field('element', $._type),
+      ';',
+      field('length', $._expression),
+      ']',
+    ),
+
+    never_type: _ => '!',
+
+    // Section - Expressions
+
+    _expression_except_range: $ => choice(
+      $.unary_expression,
+      $.try_expression,
+      $.binary_expression,
+      $.assignment_expression,
+      $.compound_assignment_expr,
+      $.call_expression,
+      $.return_expression,
+      $._literal,
+      prec.left($.identifier),
+      $.self,
+      $.scoped_identifier,
+      $.generic_function,
+      $.field_expression,
+      $.array_expression,
+      $.tuple_expression,
+      prec(1, $.macro_invocation),
+      $.unit_expression,
+      $.break_expression,
+      $.continue_expression,
+      $.index_expression,
+      $.closure_expression,
+      $.parenthesized_expression,
+      $.struct_expression,
+      $._expression_ending_with_block,
+    ),
+
+    _expression: $ => choice(
+      $._expression_except_range,
+      $.range_expression,
+    ),
+
+    _expression_ending_with_block: $ => choice(
+      $.block,
+      $.if_expression,
+      $.match_expression,
+      $.while_expression,
+      $.loop_expression,
+      $.for_expression,
+    ),
+
+    // `assert!(x > 0, "positive")`, `array![1, 2]`, `selector!("transfer")`
+    macro_invocation: $ => seq(
+      field('macro', choice(
+        $.scoped_identifier,
+        $.identifier,
+      )),
+      '!',
+      alias($.delim_token_tree, $.token_tree),
+    ),
+
+    _path: $ => choice(
+      $.self,
+      $.super,
+      $.crate,
+      $.identifier,
+      $.scoped_identifier,
+    ),
+
+    scoped_identifier: $ => seq(
+      field('path', optional(choice(
+        $._path,
+        alias($.generic_type_with_turbofish, $.generic_type),
+      ))),
+      '::',
+      field('name', choice($.identifier, $.super)),
+    ),
+
+    scoped_type_identifier_in_expression_position: $ => prec(-2, seq(
+      field('path', optional(choice(
+        $._path,
+        alias($.generic_type_with_turbofish, $.generic_type),
+      ))),
+      '::',
+      field('name', $._type_identifier),
+    )),
+
+    generic_type_with_turbofish: $ => seq(
+      field('type', choice(
+        $._type_identifier,
+        $.scoped_identifier,
+      )),
+      '::',
+      field('type_arguments', $.type_arguments),
+    ),
+
+    generic_function: $ => prec(1, seq(
+      field('function', choice(
+        $.identifier,
+        $.scoped_identifier,
+        $.field_expression,
+      )),
+      '::',
+      field('type_arguments', $.type_arguments),
+    )),
+
+    range_expression: $ => prec.left(PREC.range, seq(
+      $._expression,
+      choice('..', '..='),
+      $._expression,
+    )),
+
+    // `@self.balance`, `*snapshot`, `~mask`
+    unary_expression: $ => prec(PREC.unary, seq(
+      choice('-', '*', '!', '~', '@'),
+      $._expression,
+    )),
+
+    try_expression: $ => prec(PREC.try, seq(
+      $._expression,
+      '?',
+    )),
+
+    binary_expression: $ => {
+      const table = [
+        [PREC.and, '&&'],
+        [PREC.or, '||'],
+        [PREC.bitand, '&'],
+        [PREC.bitor, '|'],
+        [PREC.bitxor, '^'],
+        [PREC.comparative, choice('==', '!=', '<', '<=', '>', '>=')],
+        [PREC.additive, choice('+', '-')],
+        [PREC.multiplicative, choice('*', '/', '%')],
+      ];
+
+      // @ts-ignore
+      return choice(...table.map(([precedence, operator]) => prec.left(precedence, seq(
+        field('left', $._expression),
+        // @ts-ignore
+        field('operator', operator),
+        field('right', $._expression),
+      ))));
+    },
+
+    assignment_expression: $ => prec.left(PREC.assign, seq(
+      field('left', $._expression),
+      '=',
+      field('right', $._expression),
+    )),
+
+    compound_assignment_expr: $ => prec.left(PREC.assign, seq(
+      field('left', $._expression),
+      field('operator', choice('+=', '-=', '*=', '/=', '%=', '&=', '|=', '^=')),
+      field('right', $._expression),
+    )),
+
+    call_expression: $ => prec(PREC.call, seq(
+      field('function', $._expression_except_range),
+      field('arguments', $.arguments),
+    )),
+
+    arguments: $ => seq(
+      '(',
+      sepBy(',', seq(repeat($.attribute_item), $._argument)),
+      optional(','),
+      ')',
+    ),
+
+    _argument: $ => choice(
+      $._expression,
+      $.ref_argument,
+      $.named_argument,
+    ),
+
+    // `transfer(ref self, to, amount)`
+    ref_argument: $ => seq(
+      'ref',
+      $._expression,
+    ),
+
+    // `Point { x: 1, y: 2 }` style arguments: `new(x: 1)` and the shorthand `new(:x)`
+    named_argument: $ => seq(
+      optional(field('name', $.identifier)),
+      ':',
+      field('value', $._expression),
+    ),
+
+    array_expression: $ => seq(
+      '[',
+      sepBy(',', $._expression),
+      optional(','),
+      ']',
+    ),
+
+    parenthesized_expression: $ => seq(
+      '(',
+      $._expression,
+      ')',
+    ),
+
+    tuple_expression: $ => seq(
+      '(',
+      seq($._expression, ','),
+      repeat(seq($._expression, ',')),
+      optional($._expression),
+      ')',
+    ),
+
+    unit_expression: _ => seq('(', ')'),
+
+    struct_expression: $ => seq(
+      field('name', choice(
+        $._type_identifier,
+        alias($.scoped_type_identifier_in_expression_position, $.scoped_type_identifier),
+        $.generic_type_with_turbofish,
+      )),
+      field('body', $.field_initializer_list),
+    ),
+
+    field_initializer_list: $ => seq(
+      '{',
+      sepBy(',', choice(
+        $.shorthand_field_initializer,
+        $.field_initializer,
+        $.base_field_initializer,
+      )),
+      optional(','),
+      '}',
+    ),
+
+    shorthand_field_initializer: $ => $.identifier,
+
+    field_initializer: $ => seq(
+      field('field', $._field_identifier),
+      ':',
+      field('value', $._expression),
+    ),
+
+    base_field_initializer: $ => seq(
+      '..',
+      $._expression,
+    ),
+
+    if_expression: $ => prec.right(seq(
+      'if',
+      field('condition', $._condition),
+      field('consequence', $.block),
+      optional(field('alternative', $.else_clause)),
+    )),
+
+    let_condition: $ => seq(
+      'let',
+      field('pattern', $._pattern),
+      '=',
+      field('value', prec.left(PREC.and, $._expression)),
+    ),
+
+    _condition: $ => choice(
+      $._expression,
+      $.let_condition,
+    ),
+
+    else_clause: $ => seq(
+      'else',
+      choice(
+        $.block,
+        $.if_expression,
+      ),
+    ),
+
+    match_expression: $ => seq(
+      'match',
+      field('value', $._expression),
+      field('body', $.match_block),
+    ),
+
+    match_block: $ => seq(
+      '{',
+      optional(seq(
+        repeat($.match_arm),
+        alias($.last_match_arm, $.match_arm),
+      )),
+      '}',
+    ),
+
+    match_arm: $ => prec.right(seq(
+      repeat($.attribute_item),
+      field('pattern', $.match_pattern),
+      '=>',
+      choice(
+        seq(field('value', $._expression), ','),
+        field('value', prec(1, $._expression_ending_with_block)),
+      ),
+    )),
+
+    last_match_arm: $ => seq(
+      repeat($.attribute_item),
+      field('pattern', $.match_pattern),
+      '=>',
+      field('value', $._expression),
+      optional(','),
+    ),
+
+    match_pattern: $ => seq(
+      $._pattern,
+      optional(seq('if', field('condition', $._condition))),
+    ),
+
+    while_expression: $ => seq(
+      'while',
+      field('condition', $._condition),
+      field('body', $.block),
+    ),
+
+    loop_expression: $ => seq(
+      'loop',
+      field('body', $.block),
+    ),
+
+    for_expression: $ => seq(
+      'for',
+      field('pattern', $._pattern),
+      'in',
+      field('value', $._expression),
+      field('body', $.block),
+    ),
+
+    closure_expression: $ => prec(PREC.closure, seq(
+      field('parameters', $.closure_parameters),
+      choice(
+        seq(
+          '->',
+          field('return_type', $._type),
+          field('body', $.block),
+        ),
+        field('body', $._expression),
+      ),
+    )),
+
+    closure_parameters: $ => seq(
+      '|',
+      sepBy(',', choice(
+        $._pattern,
+        alias($.closure_parameter, $.parameter),
+      )),
+      '|',
+    ),
+
+    closure_parameter: $ => seq(
+      field('pattern', $._pattern),
+      ':',
+      field('type', $._type),
+    ),
+
+    break_expression: $ => prec.left(seq('break', optional($._expression))),
+
+    continue_expression: _ => 'continue',
+
+    index_expression: $ => prec(PREC.call, seq($._expression, '[', $._expression, ']')),
+
+    return_expression: $ => choice(
+      prec.left(seq('return', $._expression)),
+      prec(-1, 'return'),
+    ),
+
+    field_expression: $ => prec(PREC.field, seq(
+      field('value', $._expression),
+      '.',
+      field('field', choice(
+        $._field_identifier,
+        $.integer_literal,
+      )),
+    )),
+
+    block: $ => seq(
+      '{',
+      repeat($._statement),
+      optional($._expression),
+      '}',
+    ),
+
+    let_declaration: $ => seq(
+      'let',
+      optional($.mutable_specifier),
+      field('pattern', $._pattern),
+      optional(seq(
+        ':',
+        field('type', $._type),
+      )),
+      optional(seq(
+        '=',
+        field('value', $._expression),
+      )),
+      optional(seq(
+        'else',
+        field('alternative', $.block),
+      )),
+      ';',
+    ),
+
+    // Section - Patterns
+
+    _pattern: $ => choice(
+      $._literal_pattern,
+      $.identifier,
+      $.scoped_identifier,
+      $.tuple_pattern,
+      $.tuple_struct_pattern,
+      $.struct_pattern,
+      $.ref_pattern,
+      $.mut_pattern,
+      $.or_pattern,
+      '_',
+    ),
+
+    tuple_pattern: $ => seq(
+      '(',
+      sepBy(',', $._pattern),
+      optional(','),
+      ')',
+    ),
+
+    // `Option::Some(value)`
+    tuple_struct_pattern: $ => seq(
+      field('type', choice(
+        $.identifier,
+        $.scoped_identifier,
+        alias($.generic_type_with_turbofish, $.generic_type),
+      )),
+      '(',
+      sepBy(',', $._pattern),
+      optional(','),
+      ')',
+    ),
+
+    struct_pattern: $ => seq(
+      field('type', choice(
+        $._type_identifier,
+        $.scoped_type_identifier,
+      )),
+      '{',
+      sepBy(',', choice($.field_pattern, $.remaining_field_pattern)),
+      optional(','),
+      '}',
+    ),
+
+    field_pattern: $ => seq(
+      optional($.ref_modifier),
+      optional($.mutable_specifier),
+      choice(
+        field('name', alias($.identifier, $.shorthand_field_identifier)),
+        seq(
+          field('name', $._field_identifier),
+          ':',
+          field('pattern', $._pattern),
+        ),
+      ),
+    ),
+
+    remaining_field_pattern: _ => '..',
+
+    ref_pattern: $ => seq(
+      $.ref_modifier,
+      $._pattern,
+    ),
+
+    mut_pattern: $ => prec(-1, seq(
+      $.mutable_specifier,
+      $._pattern,
+    )),
+
+    or_pattern: $ => prec.left(-2, choice(
+      seq($._pattern, '|', $._pattern),
+      seq('|', $._pattern),
+    )),
+
+    // Section - Literals
+
+    _literal: $ => choice(
+      $.string_literal,
+      $.short_string_literal,
+      $.boolean_literal,
+      $.integer_literal,
+    ),
+
+    _literal_pattern: $ => choice(
+      $.string_literal,
+      $.short_string_literal,
+      $.boolean_literal,
+      $.integer_literal,
+      $.negative_literal,
+    ),
+
+    negative_literal: $ => seq('-', $.integer_literal),
+
+    // `42`, `0x1f`, `10_u8`, `1_000_felt252`
+    integer_literal: _ => token(seq(
+      choice(
+        /[0-9][0-9_]*/,
+        /0x[0-9a-fA-F_]+/,
+        /0b[01_]+/,
+        /0o[0-7_]+/,
+      ),
+      optional(seq(optional('_'), choice(...INTEGER_TYPES))),
+    )),
+
+    // `"over limit"` is a ByteArray
+    string_literal: _ => token(seq(
+      '"',
+      repeat(choice(/[^"\\]/, /\\./)),
+      '"',
+    )),
+
+    // `'zero amount'` is a felt252 holding up to 31 ASCII characters
+    short_string_literal: _ => token(seq(
+      '\'',
+      repeat(choice(/[^'\\\n]/, /\\./)),
+      '\'',
+      optional(seq('_', choice(...INTEGER_TYPES))),
+    )),
+
+    boolean_literal: _ => choice('true', 'false'),
+
+    line_comment: _ => token(seq('//', /.*/)),
+
+    _type_identifier: $ => alias($.identifier, $.type_identifier),
+    _field_identifier: $ => alias($.identifier, $.field_identifier),
+
+    mutable_specifier: _ => 'mut',
+    self: _ => 'self',
+    super: _ => 'super',
+    crate: _ => 'crate',
+
+    identifier: _ => /[_a-zA-Z][_a-zA-Z0-9]*/,
+  },
+});
+
+/**
+ * Creates a rule to match one or more of the rules separated by the separator.
+ *
+ * @param {RuleOrLiteral} sep - The separator to use.
+ * @param {RuleOrLiteral} rule
+ *
+ * @returns {SeqRule}
+ */
+function sepBy1(sep, rule) {
+  return seq(rule, repeat(seq(sep, rule)));
+}
+
+/**
+ * Creates a rule to optionally match one or more of the rules separated by the separator.
+ *
+ * @param {RuleOrLiteral} sep - The separator to use.
+ * @param {RuleOrLiteral} rule
+ *
+ * @returns {ChoiceRule}
+ */
+function sepBy(sep, rule) {
+  return optional(sepBy1(sep, rule));
+}
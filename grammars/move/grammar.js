@@ -0,0 +1,912 @@
+/**
+ * @file Move grammar for tree-sitter
+ * @license MIT
+ * @see {@link https://aptos.dev/en/build/smart-contracts/book|The Move Book (Aptos)}
+ * @see {@link https://move-book.com|The Move Book (Sui)}
+ *
+ * Covers Aptos Move 2 and Sui Move 2024: modules (including `module a::m;` labels),
+ * scripts, abilities, `acquires`, enums and `match`, lambdas, `for` loops and
+ * receiver-style calls. Specification blocks are kept as opaque bodies.
+ * Maintained in this repo; regenerate src/ with `bash grammars/update.sh move false`.
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+const PREC = {
+  call: 15,
+  field: 14,
+  unary: 12,
+  cast: 11,
+  multiplicative: 10,
+  additive: 9,
+  shift: 8,
+  bitand: 7,
+  bitxor: 6,
+  bitor: 5,
+  comparative: 4,
+  and: 3,
+  or: 2,
+  range: 1,
+  assign: 0,
+  lambda: -1,
+};
+
+const INTEGER_TYPES = ['u8', 'u16', 'u32', 'u64', 'u128', 'u256'];
+
+module.exports = grammar({
+  name: 'move',
+
+  extras: $ => [/\s/, $.line_comment, $.block_comment],
+
+  word: $ => $.identifier,
+
+  supertypes: $ => [
+    $._expression,
+    $._type,
+    $._literal,
+    $._pattern,
+  ],
+
+  // `a < b` and `f<T>(x)` only differ once the `>` is reached
+  conflicts: $ => [
+    [$._primary_expression, $.generic_name],
+  ],
+
+  inline: $ => [
+    $._type_identifier,
+    $._field_identifier,
+    $._path,
+  ],
+
+  rules: {
+    source_file: $ => repeat(choice(
+      $.module_definition,
+      $.address_block,
+      $.script_block,
+    )),
+
+    // Section - Top level
+
+    // `address 0x1 { module M { ... } }`
+    address_block: $ => seq(
+      'address',
+      field('address', choice($.num_literal, $.identifier)),
+      '{',
+      repeat($.module_definition),
+      '}',
+    ),
+
+    // `module 0x1::coin { ... }`, or `module pkg::coin;` followed by the module's items
+    module_definition: $ => seq(
+      repeat($.attributes),
+      'module',
+      field('name', choice($.identifier, $.module_identity)),
+      field('body', $.module_body),
+    ),
+
+    module_identity: $ => seq(
+      field('address', choice($.num_literal, $.identifier)),
+      '::',
+      field('module', $.identifier),
+    ),
+
+    module_body: $ => choice(
+      seq('{', repeat($._module_item), '}'),
+      prec.right(seq(';', repeat($._module_item))),
+    ),
+
+    script_block: $ => seq(
+      'script',
+      '{',
+      repeat($._module_item),
+      '}',
+    ),
+
+    _module_item: $ => choice(
+      $.use_declaration,
+      $.friend_declaration,
+      $.constant_declaration,
+      $.struct_definition,
+      $.enum_definition,
+      $.function_definition,
+      $.spec_block,
+    ),
+
+    // `#[test(account = @0x1)]`, `#[expected_failure(abort_code = 1)]`
+    attributes: $ => seq(
+      '#',
+      '[',
+      sepBy1(',', $.attribute),
+      optional(','),
+      ']',
+    ),
+
+    attribute: $ => seq(
+      field('name', $._path),
+      optional(choice(
+        seq('=', field('value', choice($._literal, $._path))),
+        seq('(', sepBy(',', $.attribute), optional(','), ')'),
+      )),
+    ),
+
+    // Section - Declarations
+
+    use_declaration: $ => seq(
+      repeat($.attributes),
+      repeat($._modifier),
+      'use',
+      field('argument', choice($._use_clause, $.use_fun)),
+      ';',
+    ),
+
+    _use_clause: $ => choice(
+      $._path,
+      $.use_as_clause,
+      $.scoped_use_list,
+    ),
+
+    scoped_use_list: $ => seq(
+      field('path', $._path),
+      '::',
+      field('list', $.use_list),
+    ),
+
+    use_list: $ => seq(
+      '{',
+      sepBy(',', $._use_clause),
+      optional(','),
+      '}',
+    ),
+
+    use_as_clause: $ => seq(
+      field('path', $._path),
+      'as',
+      field('alias', $.identifier),
+    ),
+
+    // `use fun coin::value as Coin.amount`
+    use_fun: $ => seq(
+      'fun',
+      field('function', $._path),
+      'as',
+      field('type', $._path),
+      '.',
+      field('method', $.identifier),
+    ),
+
+    friend_declaration: $ => seq(
+      repeat($.attributes),
+      'friend',
+      field('module', $._path),
+      ';',
+    ),
+
+    constant_declaration: $ => seq(
+      repeat($.attributes),
+      'const',
+      field('name', $.identifier),
+      ':',
+      field('type', $._type),
+      '=',
+      field('value', $._expression),
+      ';',
+    ),
+
+    // `struct Coin<phantom T> has key, store { value: u64 }`, `public struct Id(u64) has copy, drop;`
+    struct_definition: $ => seq(
+      repeat($.attributes),
+      repeat($._modifier),
+      'struct',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      optional(field('abilities', $.abilities)),
+      choice(
+        seq(
+          field('body', choice($.field_declaration_list, $.positional_fields)),
+          optional(seq(field('abilities', $.abilities), ';')),
+        ),
+        ';',
+      ),
+    ),
+
+    field_declaration_list: $ => seq(
+      '{',
+      sepBy(',', $.field_declaration),
+      optional(','),
+      '}',
+    ),
+
+    field_declaration: $ => seq(
+      field('name', $._field_identifier),
+      ':',
+      field('type', $._type),
+    ),
+
+    positional_fields: $ => seq(
+      '(',
+      sepBy(',', $._type),
+      optional(','),
+      ')',
+    ),
+
+    enum_definition: $ => seq(
+      repeat($.attributes),
+      repeat($._modifier),
+      'enum',
+      field('name', $._type_identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      optional(field('abilities', $.abilities)),
+      field('body', $.enum_variant_list),
+      optional(seq(field('abilities', $.abilities), ';')),
+    ),
+
+    enum_variant_list: $ => seq(
+      '{',
+      sepBy(',', $.enum_variant),
+      optional(','),
+      '}',
+    ),
+
+    enum_variant: $ => seq(
+      field('name', $.identifier),
+      optional(field('body', choice($.field_declaration_list, $.positional_fields))),
+    ),
+
+    // `has copy, drop, store`
+    abilities: $ => seq(
+      'has',
+      sepBy1(',', $.ability),
+    ),
+
+    ability: _ => choice('copy', 'drop', 'store', 'key'),
+
+    function_definition: $ => seq(
+      repeat($.attributes),
+      repeat($._modifier),
+      'fun',
+      field('name', $.identifier),
+      optional(field('type_parameters', $.type_parameters)),
+      field('parameters', $.parameters),
+      optional(seq(':', field('return_type', $._type))),
+      optional(field('acquires', $.acquires_clause)),
+      choice(
+        field('body', $.block),
+        ';',
+      ),
+    ),
+
+    // Each item only allows some of these, which the compiler checks
+    _modifier: $ => choice($.visibility_modifier, 'entry', 'native', 'inline'),
+
+    // `public`, `public(friend)`, `public(package)`
+    visibility_modifier: _ => prec.right(seq(
+      'public',
+      optional(seq('(', choice('friend', 'package', 'script'), ')')),
+    )),
+
+    type_parameters: $ => seq(
+      '<',
+      sepBy1(',', $.type_parameter),
+      optional(','),
+      '>',
+    ),
+
+    // `phantom T: copy + drop`
+    type_parameter: $ => seq(
+      optional('phantom'),
+      field('name', $._type_identifier),
+      optional(seq(':', field('constraints', $.constraints))),
+    ),
+
+    constraints: $ => sepBy1('+', $.ability),
+
+    parameters: $ => seq(
+      '(',
+      sepBy(',', $.parameter),
+      optional(','),
+      ')',
+    ),
+
+    parameter: $ => seq(
+      optional($.mutable_specifier),
+      field('pattern', $.identifier),
+      ':',
+      field('type', $._type),
+    ),
+
+    // `acquires Coin, Config`
+    acquires_clause: $ => seq(
+      'acquires',
+      sepBy1(',', choice($._type_identifier, $.scoped_type_identifier, $.generic_type)),
+    ),
+
+    // `spec module { ... }`, `spec transfer { ... }`; bodies aren't parsed
+    spec_block: $ => seq(
+      'spec',
+      optional(field('target', choice(
+        'module',
+        seq(optional(choice('schema', 'fun')), $.identifier, optional($.type_parameters)),
+      ))),
+      optional($.parameters),
+      optional(seq(':', $._type)),
+      field('body', $.spec_body),
+    ),
+
+    spec_body: $ => seq(
+      '{',
+      repeat(choice($.spec_body, /[^{}]+/)),
+      '}',
+    ),
+
+    // Section - Types
+
+    _type: $ => choice(
+      $._type_identifier,
+      $.scoped_type_identifier,
+      $.generic_type,
+      $.reference_type,
+      $.tuple_type,
+      $.unit_type,
+      $.function_type,
+    ),
+
+    scoped_type_identifier: $ => seq(
+      field('path', $._path),
+      '::',
+      field('name', $._type_identifier),
+    ),
+
+    generic_type: $ => prec(1, seq(
+      field('type', choice(
+        $._type_identifier,
+        $.scoped_type_identifier,
+        alias('vector', $.type_identifier),
+      )),
+      field('type_arguments', $.type_arguments),
+    )),
+
+    type_arguments: $ => seq(
+      '<',
+      sepBy1(',', $._type),
+      optional(','),
+      '>',
+    ),
+
+    // `&signer`, `&mut Coin<T>`
+    reference_type: $ => seq(
+      '&',
+      optional($.mutable_specifier),
+      field('type', $._type),
+    ),
+
+    tuple_type: $ => seq(
+      '(',
+      sepBy1(',', $._type),
+      optional(','),
+      ')',
+    ),
+
+    unit_type: _ => seq('(', ')'),
+
+    // `|u64, u64| bool`
+    function_type: $ => prec.right(seq(
+      choice(
+        seq('|', sepBy(',', $._type), '|'),
+        '||',
+      ),
+      optional(field('return_type', $._type)),
+    )),
+
+    // Section - Statements
+
+    block: $ => seq(
+      '{',
+      repeat($._statement),
+      optional($._expression),
+      '}',
+    ),
+
+    _statement: $ => choice(
+      $.use_declaration,
+      $.let_statement,
+      $.expression_statement,
+      $.spec_block,
+      $.empty_statement,
+    ),
+
+    empty_statement: _ => ';',
+
+    // Move 2024 makes the `;` after `if`, `while` and blocks optional
+    expression_statement: $ => choice(
+      seq($._expression, ';'),
+      prec.right(1, seq($._expression_ending_with_block, optional(';'))),
+    ),
+
+    let_statement: $ => seq(
+      'let',
+      field('pattern', $._pattern),
+      optional(seq(':', field('type', $._type))),
+      optional(seq('=', field('value', $._expression))),
+      ';',
+    ),
+
+    // Section - Expressions
+
+    _expression: $ => choice(
+      $.assignment_expression,
+      $.compound_assignment_expression,
+      $.binary_expression,
+      alias($._ordering_expression, $.binary_expression),
+      $.range_expression,
+      $.cast_expression,
+      $.unary_expression,
+      $.borrow_expression,
+      $.dereference_expression,
+      $.move_or_copy_expression,
+      $.lambda_expression,
+      $.return_expression,
+      $.abort_expression,
+      $.break_expression,
+      $.continue_expression,
+      $._expression_ending_with_block,
+      $._primary_expression,
+    ),
+
+    _expression_ending_with_block: $ => choice(
+      $.block,
+      $.if_expression,
+      $.while_expression,
+      $.loop_expression,
+      $.for_expression,
+      $.match_expression,
+    ),
+
+    _primary_expression: $ => choice(
+      $.identifier,
+      $.scoped_identifier,
+      $.generic_name,
+      $.call_expression,
+      $.method_call_expression,
+      $.macro_call_expression,
+      $.pack_expression,
+      $.field_expression,
+      $.index_expression,
+      $.vector_expression,
+      $.annotation_expression,
+      $.parenthesized_expression,
+      $.tuple_expression,
+      $.unit_expression,
+      $._literal,
+    ),
+
+    _path: $ => choice(
+      $.identifier,
+      $.scoped_identifier,
+    ),
+
+    // `coin::value`, `0x1::signer::address_of`, `vector::empty`
+    scoped_identifier: $ => seq(
+      field('path', choice(
+        $._path,
+        $.num_literal,
+        alias('vector', $.identifier),
+      )),
+      '::',
+      field('name', $.identifier),
+    ),
+
+    // `borrow_global_mut<Coin>`, `vector::empty<u8>`
+    generic_name: $ => prec.dynamic(1, seq(
+      field('name', $._path),
+      field('type_arguments', $.type_arguments),
+    )),
+
+    call_expression: $ => prec(PREC.call, seq(
+      field('function', choice($._path, $.generic_name)),
+      field('arguments', $.arguments),
+    )),
+
+    // `coin.value()`, `v.push_back<u8>(1)`
+    method_call_expression: $ => prec(PREC.call, seq(
+      field('receiver', $._expression),
+      '.',
+      field('method', $.identifier),
+      optional(field('type_arguments', $.type_arguments)),
+      field('arguments', $.arguments),
+    )),
+
+    // `assert!(balance >= amount, EINSUFFICIENT)`
+    macro_call_expression: $ => prec(PREC.call, seq(
+      field('macro', $._path),
+      '!',
+      optional(field('type_arguments', $.type_arguments)),
+      field('arguments', $.arguments),
+    )),
+
+    arguments: $ => seq(
+      '(',
+      sepBy(',', $._expression),
+      optional(','),
+      ')',
+    ),
+
+    // `Coin { value: 0 }`, `Coin<T> { value }`
+    pack_expression: $ => prec(1, seq(
+      field('name', choice($._path, $.generic_name)),
+      field('body', $.field_initializer_list),
+    )),
+
+    field_initializer_list: $ => seq(
+      '{',
+      sepBy(',', choice(
+        $.field_initializer,
+        $.shorthand_field_initializer,
+      )),
+      optional(','),
+      '}',
+    ),
+
+    field_initializer: $ => seq(
+      field('field', $._field_identifier),
+      ':',
+      field('value', $._expression),
+    ),
+
+    shorthand_field_initializer: $ => $.identifier,
+
+    field_expression: $ => prec(PREC.field, seq(
+      field('value', $._expression),
+      '.',
+      field('field', choice($._field_identifier, $.num_literal)),
+    )),
+
+    index_expression: $ => prec(PREC.call, seq(
+      field('value', $._expression),
+      '[',
+      field('index', $._expression),
+      ']',
+    )),
+
+    // `vector[1, 2]`, `vector<u8>[]`
+    vector_expression: $ => seq(
+      'vector',
+      optional(field('type_arguments', $.type_arguments)),
+      '[',
+      sepBy(',', $._expression),
+      optional(','),
+      ']',
+    ),
+
+    // `(amount as u128)`
+    annotation_expression: $ => seq(
+      '(',
+      field('value', $._expression),
+      ':',
+      field('type', $._type),
+      ')',
+    ),
+
+    parenthesized_expression: $ => seq(
+      '(',
+      $._expression,
+      ')',
+    ),
+
+    tuple_expression: $ => seq(
+      '(',
+      $._expression,
+      repeat1(seq(',', $._expression)),
+      optional(','),
+      ')',
+    ),
+
+    unit_expression: _ => seq('(', ')'),
+
+    assignment_expression: $ => prec.right(PREC.assign, seq(
+      field('left', $._expression),
+      '=',
+      field('right', $._expression),
+    )),
+
+    compound_assignment_expression: $ => prec.right(PREC.assign, seq(
+      field('left', $._expression),
+      field('operator', choice('+=', '-=', '*=', '/=', '%=', '&=', '|=', '^=', '<<=', '>>=')),
+      field('right', $._expression),
+    )),
+
+    binary_expression: $ => {
+      const table = [
+        [PREC.or, '||'],
+        [PREC.and, '&&'],
+        [PREC.comparative, choice('==', '!=', '<=', '>=')],
+        [PREC.bitor, '|'],
+        [PREC.bitxor, '^'],
+        [PREC.bitand, '&'],
+        [PREC.shift, choice('<<', '>>')],
+        [PREC.additive, choice('+', '-')],
+        [PREC.multiplicative, choice('*', '/', '%')],
+      ];
+
+      // @ts-ignore
+      return choice(...table.map(([precedence, operator]) => prec.left(precedence, seq(
+        field('left', $._expression),
+        // @ts-ignore
+        field('operator', operator),
+        field('right', $._expression),
+      ))));
+    },
+
+    // `f<A<B>>(x)` also reads as `(f < A<B>) > (x)`; the call is meant
+    _ordering_expression: $ => prec.dynamic(-1, prec.left(PREC.comparative, seq(
+      field('left', $._expression),
+      field('operator', choice('<', '>')),
+      field('right', $._expression),
+    ))),
+
+    range_expression: $ => prec.left(PREC.range, seq(
+      $._expression,
+      '..',
+      $._expression,
+    )),
+
+    cast_expression: $ => prec.left(PREC.cast, seq(
+      field('value', $._expression),
+      'as',
+      field('type', $._type),
+    )),
+
+    unary_expression: $ => prec(PREC.unary, seq(
+      '!',
+      $._expression,
+    )),
+
+    // `&coin.value`, `&mut balances`
+    borrow_expression: $ => prec(PREC.unary, seq(
+      '&',
+      optional($.mutable_specifier),
+      field('value', $._expression),
+    )),
+
+    dereference_expression: $ => prec(PREC.unary, seq(
+      '*',
+      field('value', $._expression),
+    )),
+
+    move_or_copy_expression: $ => prec(PREC.unary, seq(
+      choice('move', 'copy'),
+      field('value', $.identifier),
+    )),
+
+    // `|x| x + 1`, `|a: u64, b| { a + b }`
+    lambda_expression: $ => prec(PREC.lambda, seq(
+      field('parameters', $.lambda_parameters),
+      optional(seq('->', field('return_type', $._type))),
+      field('body', $._expression),
+    )),
+
+    lambda_parameters: $ => choice(
+      seq('|', sepBy(',', $.lambda_parameter), '|'),
+      '||',
+    ),
+
+    lambda_parameter: $ => seq(
+      field('pattern', $._pattern),
+      optional(seq(':', field('type', $._type))),
+    ),
+
+    if_expression: $ => prec.right(seq(
+      'if',
+      '(',
+      field('condition', $._expression),
+      ')',
+      field('consequence', $._expression),
+      optional(seq('else', field('alternative', $._expression))),
+    )),
+
+    while_expression: $ => prec.right(seq(
+      optional(seq(field('label', $.label), ':')),
+      'while',
+      '(',
+      field('condition', $._expression),
+      ')',
+      field('body', $.block),
+    )),
+
+    loop_expression: $ => prec.right(seq(
+      optional(seq(field('label', $.label), ':')),
+      'loop',
+      field('body', $.block),
+    )),
+
+    // `for (i in 0..len) { ... }`
+    for_expression: $ => seq(
+      optional(seq(field('label', $.label), ':')),
+      'for',
+      '(',
+      field('pattern', $._pattern),
+      'in',
+      field('value', $._expression),
+      ')',
+      field('body', $.block),
+    ),
+
+    match_expression: $ => seq(
+      'match',
+      '(',
+      field('value', $._expression),
+      ')',
+      field('body', $.match_block),
+    ),
+
+    match_block: $ => seq(
+      '{',
+      sepBy(',', $.match_arm),
+      optional(','),
+      '}',
+    ),
+
+    match_arm: $ => seq(
+      field('pattern', $._pattern),
+      optional(seq('if', '(', field('condition', $._expression), ')')),
+      '=>',
+      field('value', $._expression),
+    ),
+
+    return_expression: $ => choice(
+      prec.left(seq('return', $._expression)),
+      prec(-1, 'return'),
+    ),
+
+    // `abort EINSUFFICIENT_BALANCE`
+    abort_expression: $ => choice(
+      prec.left(seq('abort', field('code', $._expression))),
+      prec(-1, 'abort'),
+    ),
+
+    break_expression: $ => prec.right(seq('break', optional($.label))),
+
+    continue_expression: $ => prec.right(seq('continue', optional($.label))),
+
+    label: _ => /'[_a-zA-Z][_a-zA-Z0-9]*/,
+
+    // Section - Patterns
+
+    _pattern: $ => choice(
+      $.identifier,
+      $.mut_pattern,
+      $.tuple_pattern,
+      $.struct_pattern,
+      $.positional_pattern,
+      $.or_pattern,
+      $._literal,
+      $.scoped_identifier,
+      '_',
+    ),
+
+    mut_pattern: $ => seq(
+      $.mutable_specifier,
+      $.identifier,
+    ),
+
+    tuple_pattern: $ => seq(
+      '(',
+      sepBy(',', $._pattern),
+      optional(','),
+      ')',
+    ),
+
+    // `Coin { value }`, `Coin<T> { value: amount }`
+    struct_pattern: $ => seq(
+      field('type', choice($._path, $.generic_name)),
+      '{',
+      sepBy(',', choice($.field_pattern, '..')),
+      optional(','),
+      '}',
+    ),
+
+    field_pattern: $ => choice(
+      field('name', alias($.identifier, $.shorthand_field_identifier)),
+      seq(
+        optional($.mutable_specifier),
+        field('name', alias($.identifier, $.shorthand_field_identifier)),
+      ),
+      seq(
+        field('name', $._field_identifier),
+        ':',
+        field('pattern', $._pattern),
+      ),
+    ),
+
+    // `Shape::Circle(radius)`
+    positional_pattern: $ => seq(
+      field('type', choice($._path, $.generic_name)),
+      '(',
+      sepBy(',', $._pattern),
+      optional(','),
+      ')',
+    ),
+
+    or_pattern: $ => prec.left(-2, seq($._pattern, '|', $._pattern)),
+
+    // Section - Literals
+
+    _literal: $ => choice(
+      $.num_literal,
+      $.bool_literal,
+      $.address_literal,
+      $.byte_string_literal,
+      $.hex_string_literal,
+    ),
+
+    // `42`, `0xff`, `1_000u64`
+    num_literal: _ => token(seq(
+      choice(
+        /[0-9][0-9_]*/,
+        /0x[0-9a-fA-F_]+/,
+      ),
+      optional(choice(...INTEGER_TYPES)),
+    )),
+
+    bool_literal: _ => choice('true', 'false'),
+
+    // `@0x1`, `@aptos_framework`
+    address_literal: $ => seq(
+      '@',
+      choice($.num_literal, $.identifier),
+    ),
+
+    // `b"hello"`
+    byte_string_literal: _ => token(seq(
+      'b"',
+      repeat(choice(/[^"\\]/, /\\./)),
+      '"',
+    )),
+
+    // `x"deadbeef"`
+    hex_string_literal: _ => token(seq(
+      'x"',
+      /[0-9a-fA-F]*/,
+      '"',
+    )),
+
+    line_comment: _ => token(seq('//', /.*/)),
+
+    block_comment: _ => token(seq(
+      '/*',
+      /[^*]*\*+([^/*][^*]*\*+)*/,
+      '/',
+    )),
+
+    _type_identifier: $ => alias($.identifier, $.type_identifier),
+    _field_identifier: $ => alias($.identifier, $.field_identifier),
+
+    mutable_specifier: _ => 'mut',
+
+    identifier: _ => /[_a-zA-Z][_a-zA-Z0-9]*/,
+  },
+});
+
+/**
+ * Creates a rule to match one or more of the rules separated by the separator.
+ *
+ * @param {RuleOrLiteral} sep - The separator to use.
+ * @param {RuleOrLiteral} rule
+ *
+ * @returns {SeqRule}
+ */
+function sepBy1(sep, rule) {
+  return seq(rule, repeat(seq(sep, rule)));
+}
+
+/**
+ * Creates a rule to optionally match one or more of the rules separated by the separator.
+ *
+ * @param {RuleOrLiteral} sep - The separator to use.
+ * @param {RuleOrLiteral} rule
+ *
+ * @returns {ChoiceRule}
+ */
+function sepBy(sep, rule) {
+  return optional(sepBy1(sep, rule));
+}
@@ -19,6 +19,9 @@ declare -A REPO_URLS=(
   ["javascript"]="https://github.com/tree-sitter/tree-sitter-javascript"
   ["typescript"]="https://github.com/tree-sitter/tree-sitter-typescript"
   ["tsx"]="https://github.com/tree-sitter/tree-sitter-typescript"
+  ["python"]="https://github.com/tree-sitter/tree-sitter-python"
+  ["c"]="https://github.com/tree-sitter/tree-sitter-c"
+  ["cpp"]="https://github.com/tree-sitter/tree-sitter-cpp"
 )
 
 declare -A GRAMMAR_PATHS=(
@@ -28,25 +31,82 @@ declare -A GRAMMAR_PATHS=(
   ["javascript"]="" # repo root
   ["typescript"]="typescript" # grammar is in typescript/ subdirectory
   ["tsx"]="tsx" # grammar is in tsx/ subdirectory
+  ["python"]="" # repo root
+  ["c"]="" # repo root
+  ["cpp"]="" # repo root
+)
+
+# Grammars maintained in this repo instead of vendored, for languages without a usable
+# upstream tree-sitter grammar. Their src/ is regenerated from grammar.js (and the
+# tree-sitter.json next to it, which selects ABI 15) with the tree-sitter CLI.
+declare -A LOCAL_GRAMMARS=(
+  ["vyper"]="extends grammars/python/grammar.js and reuses its scanner"
+  ["move"]="Aptos Move 2 and Sui Move 2024"
+  ["cairo"]="Cairo 1; the tree-sitter-cairo crate predates it"
 )
 
 # Validate language argument
 if [ -z "$language" ]; then
   echo "Error: Language argument is required"
   echo "Usage: $0 <language> [dry_run]"
-  echo "Supported languages: ${!REPO_URLS[*]}"
+  echo "Supported languages: ${!REPO_URLS[*]} ${!LOCAL_GRAMMARS[*]}"
   exit 1
 fi
 
 # Check if language is supported
-if [[ ! -v REPO_URLS["$language"] ]]; then
+if [[ ! -v REPO_URLS["$language"] && ! -v LOCAL_GRAMMARS["$language"] ]]; then
   echo "Error: Language '$language' is not supported"
-  echo "Supported languages: ${!REPO_URLS[*]}"
+  echo "Supported languages: ${!REPO_URLS[*]} ${!LOCAL_GRAMMARS[*]}"
   echo ""
   echo "To add support for a new language, add entries to REPO_URLS and GRAMMAR_PATHS in this script"
   exit 1
 fi
 
+# Check that the crate still builds and parses with the new grammar
+verify_build() {
+  if command -v cargo >/dev/null 2>&1; then
+    echo "Testing compilation..."
+    if ! cargo check; then
+      echo "Error: Compilation failed after grammar update"
+      echo "You may need to update node type mappings in src/parser/$language.rs"
+      exit 1
+    fi
+  else
+    echo "cargo not found, skipping compilation test"
+  fi
+
+  if command -v cargo >/dev/null 2>&1; then
+    echo "Running parser tests..."
+    if ! cargo test parser; then
+      echo "Warning: Parser tests failed - you may need to update node type mappings"
+      echo "Check src/parser/$language.rs NodeType::from() mappings"
+      exit 1
+    fi
+  else
+    echo "cargo not found, skipping parser tests"
+  fi
+}
+
+# In-house grammars are regenerated in place rather than cloned
+if [[ -v LOCAL_GRAMMARS["$language"] ]]; then
+  echo "$language grammar is maintained in this repo (${LOCAL_GRAMMARS[$language]})"
+  if [ "$dry_run" = "true" ]; then
+    echo "DRY RUN: Would regenerate grammars/$language/src from grammars/$language/grammar.js"
+    echo "Run 'bash grammars/update.sh $language false' to perform the actual update"
+    exit 0
+  fi
+  if ! command -v tree-sitter >/dev/null 2>&1; then
+    echo "Error: the tree-sitter CLI is required to regenerate grammars/$language/src"
+    exit 1
+  fi
+  echo "Regenerating $language grammar..."
+  (cd "grammars/$language" && tree-sitter generate)
+  verify_build
+  echo "Grammar regeneration completed successfully!"
+  echo "Ready to commit the changes"
+  exit 0
+fi
+
 # Get configuration for the specified language
 repo_url="${REPO_URLS[$language]}"
 grammar_path="${GRAMMAR_PATHS[$language]}"
@@ -139,29 +199,8 @@ EOF
 echo "Cleaning up..."
 rm -rf "$TEMP_DIR"
 
-# Step 6: Test compilation
-if command -v cargo >/dev/null 2>&1; then
-  echo "Testing compilation..."
-  if ! cargo check; then
-    echo "Error: Compilation failed after grammar update"
-    echo "You may need to update node type mappings in src/parser/$language.rs"
-    exit 1
-  fi
-else
-  echo "cargo not found, skipping compilation test"
-fi
-
-# Step 7: Run parser tests
-if command -v cargo >/dev/null 2>&1; then
-  echo "Running parser tests..."
-  if ! cargo test parser; then
-    echo "Warning: Parser tests failed - you may need to update node type mappings"
-    echo "Check src/parser/$language.rs NodeType::from() mappings"
-    exit 1
-  fi
-else
-  echo "cargo not found, skipping parser tests"
-fi
+# Steps 6-7: Test compilation and run parser tests
+verify_build
 
 echo "Grammar update completed successfully!"
 echo "Consider running 'just check && just build' to verify everything works"
@@ -0,0 +1,114 @@
+/**
+ * @file Vyper grammar for tree-sitter
+ * @license MIT
+ * @see {@link https://docs.vyperlang.org/en/stable/structure-of-a-contract.html|Structure of a contract}
+ *
+ * Vyper's statements and expressions are Python's, so this extends the vendored Python
+ * grammar (and uses its indentation scanner) with Vyper's declarations and statements.
+ * Maintained in this repo; regenerate src/ with `bash grammars/update.sh vyper false`.
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+const Python = require('../python/grammar');
+
+// Below any other use of a call, so `extcall a.b(c).d(e)` takes the whole chain
+const PREC_EXTERNAL_CALL = -1;
+
+module.exports = grammar(Python, {
+  name: 'vyper',
+
+  rules: {
+    _simple_statement: ($, previous) => choice(
+      previous,
+      $.log_statement,
+      $.module_directive,
+    ),
+
+    _compound_statement: ($, previous) => choice(
+      previous,
+      $.event_definition,
+      $.struct_definition,
+      $.flag_definition,
+      $.interface_definition,
+    ),
+
+    // `log Transfer(sender, receiver, value)`
+    log_statement: $ => seq(
+      'log',
+      field('event', $.call),
+    ),
+
+    // `implements: IERC20`, `uses: ownable`, `initializes: ownable`, `exports: ownable.owner`
+    module_directive: $ => seq(
+      field('kind', choice('implements', 'uses', 'initializes', 'exports')),
+      ':',
+      commaSep1(field('value', $.expression)),
+    ),
+
+    // Members are `name: type` lines
+    event_definition: $ => seq(
+      'event',
+      field('name', $.identifier),
+      ':',
+      field('body', $._suite),
+    ),
+
+    struct_definition: $ => seq(
+      'struct',
+      field('name', $.identifier),
+      ':',
+      field('body', $._suite),
+    ),
+
+    // `enum` is the pre-0.4 spelling of `flag`
+    flag_definition: $ => seq(
+      choice('flag', 'enum'),
+      field('name', $.identifier),
+      ':',
+      field('body', $._suite),
+    ),
+
+    // Members are `def name(...) -> type: mutability` signatures
+    interface_definition: $ => seq(
+      'interface',
+      field('name', $.identifier),
+      ':',
+      field('body', $._suite),
+    ),
+
+    // `for i: uint256 in range(10):`
+    for_statement: $ => seq(
+      'for',
+      field('left', $._left_hand_side),
+      optional(seq(':', field('type', $.primary_expression))),
+      'in',
+      field('right', $._expressions),
+      ':',
+      field('body', $._suite),
+    ),
+
+    primary_expression: ($, previous) => choice(
+      previous,
+      $.external_call,
+    ),
+
+    // `extcall token.transfer(to, value)`, `staticcall token.balanceOf(owner)`
+    external_call: $ => prec(PREC_EXTERNAL_CALL, seq(
+      field('kind', choice('extcall', 'staticcall')),
+      field('call', $.call),
+    )),
+  },
+});
+
+/**
+ * Creates a rule to match one or more of the rules separated by a comma
+ *
+ * @param {RuleOrLiteral} rule
+ *
+ * @returns {SeqRule}
+ */
+function commaSep1(rule) {
+  return seq(rule, repeat(seq(',', rule)));
+}
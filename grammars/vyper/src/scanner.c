@@ -0,0 +1,9 @@
+// Vyper keeps Python's external tokens in the same order, so it reuses the vendored
+// Python indentation scanner under Vyper's symbol names rather than a copy of it
+#define tree_sitter_python_external_scanner_create tree_sitter_vyper_external_scanner_create
+#define tree_sitter_python_external_scanner_destroy tree_sitter_vyper_external_scanner_destroy
+#define tree_sitter_python_external_scanner_scan tree_sitter_vyper_external_scanner_scan
+#define tree_sitter_python_external_scanner_serialize tree_sitter_vyper_external_scanner_serialize
+#define tree_sitter_python_external_scanner_deserialize tree_sitter_vyper_external_scanner_deserialize
+
+#include "../../python/src/scanner.c"
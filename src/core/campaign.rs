@@ -0,0 +1,417 @@
+//! Library entry point for mutation campaigns.
+//!
+//! A [`Campaign`] is built from an explicit [`Config`], [`LanguageRegistry`] and [`SqlStore`]
+//! and holds no process-wide state, so several campaigns can be driven from one process. Progress is reported as [`CampaignEvent`]s and the outcome is returned
+//! as a [`CampaignResult`].
+//!
+//! ```no_run
+//! # async fn example(registry: std::sync::Arc<mewt::LanguageRegistry>) -> mewt::types::AppResult<()> {
+//! use mewt::{Campaign, SqlStore, types::config::Config};
+//!
+//! let store = SqlStore::new("sqlite:mewt.sqlite".to_string()).await?;
+//! let result = Campaign::builder(Config::default(), registry, store)
+//!     .targets(vec!["src".to_string()])
+//!     .test_cmd("cargo test")
+//!     .on_event(|event| println!("{event:?}"))
+//!     .build()
+//!     .run()
+//!     .await?;
+//! println!("{} uncaught", result.summary.uncaught);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use log::{info, warn};
+use serde::Serialize;
+
+use crate::LanguageRegistry;
+use crate::SqlStore;
+use crate::core::logging::ActiveBar;
+use crate::core::runner::TestRunner;
+use crate::types::config::{Config, ResolvedTargets};
+use crate::types::{AppResult, CampaignSummary, Status, Target};
+
+/// Callback receiving campaign progress events
+pub type EventHandler = Arc<dyn Fn(&CampaignEvent) + Send + Sync>;
+
+/// Progress of a running campaign, in the order things happen
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum CampaignEvent {
+    /// New mutants were generated and saved for a target
+    MutantsGenerated { target: PathBuf, count: usize },
+    /// The unmutated test suite is about to run for a test command
+    BaselineStarted { test_cmd: String },
+    /// The baseline failed, so the targets using this test command are not tested
+    BaselineFailed { test_cmd: String, duration_ms: u32 },
+    /// The baseline passed; mutants are tested with `timeout_secs`
+    BaselinePassed {
+        test_cmd: String,
+        duration_ms: u32,
+        timeout_secs: u32,
+    },
+    /// Testing started for the targets sharing a test command
+    CampaignStarted {
+        test_cmd: String,
+        targets: usize,
+        untested: usize,
+        retest: usize,
+        timeout_secs: u32,
+    },
+    /// Testing of a target's mutants started
+    TargetStarted { target: PathBuf, mutants: usize },
+    /// A mutant is about to be tested
+    MutantStarted {
+        target: PathBuf,
+        mutant_id: i64,
+        mutation_slug: String,
+        line: u32,
+    },
+    /// A mutant was tested
+    MutantTested(MutantResult),
+    /// A mutant was not tested
+    MutantSkipped {
+        target: PathBuf,
+        mutant_id: i64,
+        mutation_slug: String,
+        line: u32,
+        reason: SkipReason,
+    },
+    /// Testing of a target's mutants finished
+    TargetFinished { target: PathBuf, duration_ms: u32 },
+    /// The campaign ended, possibly cancelled
+    Finished {
+        summary: CampaignSummary,
+        interrupted: bool,
+    },
+}
+
+/// Why a mutant was not tested
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// A more severe mutant on the same line was uncaught; recorded as `Skipped`
+    UncaughtHigherSeverity,
+    /// The mutation is not in the campaign's mutation list
+    MutationFiltered,
+    /// The target file changed since its mutants were generated
+    TargetChanged,
+}
+
+/// Outcome of testing one mutant
+#[derive(Debug, Clone, Serialize)]
+pub struct MutantResult {
+    pub target: PathBuf,
+    pub mutant_id: i64,
+    pub mutation_slug: String,
+    pub line: u32,
+    pub status: Status,
+    pub duration_ms: u32,
+}
+
+/// What a campaign did
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CampaignResult {
+    /// Totals over every outcome in the store, including earlier campaigns
+    pub summary: CampaignSummary,
+    /// Number of new mutants generated and saved
+    pub generated: usize,
+    /// Mutants tested by this campaign, in test order
+    pub results: Vec<MutantResult>,
+    /// Whether the campaign was cancelled before finishing
+    pub interrupted: bool,
+}
+
+/// A configured mutation campaign; see [`Campaign::builder`]
+pub struct Campaign {
+    config: Config,
+    registry: Arc<LanguageRegistry>,
+    store: SqlStore,
+    targets: Option<ResolvedTargets>,
+    mutations: Option<Vec<String>>,
+    test_cmd: Option<String>,
+    test_timeout: Option<u32>,
+    comprehensive: bool,
+    verbose: bool,
+    running: Arc<AtomicBool>,
+    on_event: Option<EventHandler>,
+    progress: Option<ActiveBar>,
+}
+
+/// Builder for [`Campaign`]. Unset options fall back to the campaign's config.
+pub struct CampaignBuilder {
+    config: Config,
+    registry: Arc<LanguageRegistry>,
+    store: SqlStore,
+    targets: Option<ResolvedTargets>,
+    mutations: Option<Vec<String>>,
+    test_cmd: Option<String>,
+    test_timeout: Option<u32>,
+    comprehensive: Option<bool>,
+    verbose: bool,
+    running: Option<Arc<AtomicBool>>,
+    on_event: Option<EventHandler>,
+    progress: Option<ActiveBar>,
+}
+
+impl CampaignBuilder {
+    /// Generate mutants for `include` (files, directories or globs) before testing, using
+    /// the config's ignore settings. Without targets, only mutants already in the store
+    /// that have no outcome yet (or timed out) are tested.
+    pub fn targets(mut self, include: Vec<String>) -> Self {
+        let targets = self.config.targets();
+        self.targets = Some(ResolvedTargets {
+            include,
+            ignore: targets.and_then(|t| t.ignore.clone()).unwrap_or_default(),
+            respect_ignore_files: targets.is_none_or(|t| t.respect_ignore_files()),
+            ignore_filename: Some(self.config.ignore_filename()),
+        });
+        self
+    }
+
+    /// Like [`CampaignBuilder::targets`], with fully resolved target settings
+    pub fn resolved_targets(mut self, targets: ResolvedTargets) -> Self {
+        self.targets = Some(targets);
+        self
+    }
+
+    /// Only generate and test these mutation slugs, instead of `run.mutations`
+    pub fn mutations(mut self, slugs: Vec<String>) -> Self {
+        self.mutations = Some(slugs);
+        self
+    }
+
+    /// Test every target with this command, instead of the `[test]` settings
+    pub fn test_cmd(mut self, cmd: impl Into<String>) -> Self {
+        self.test_cmd = Some(cmd.into());
+        self
+    }
+
+    /// Per-mutant timeout in seconds, instead of the `[test]` settings
+    pub fn test_timeout(mut self, secs: u32) -> Self {
+        self.test_timeout = Some(secs);
+        self
+    }
+
+    /// Test every mutant, even if a more severe one on the same line was uncaught
+    pub fn comprehensive(mut self, comprehensive: bool) -> Self {
+        self.comprehensive = Some(comprehensive);
+        self
+    }
+
+    /// Log the output of test runs
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Share a running flag; storing `false` cancels the campaign
+    pub fn running(mut self, running: Arc<AtomicBool>) -> Self {
+        self.running = Some(running);
+        self
+    }
+
+    /// Receive progress events
+    pub fn on_event(mut self, handler: impl Fn(&CampaignEvent) + Send + Sync + 'static) -> Self {
+        self.on_event = Some(Arc::new(handler));
+        self
+    }
+
+    /// Draw a progress bar on stderr, keeping the logger's lines above it; see
+    /// [`init_logging`](crate::core::logging::init_logging)
+    pub fn progress(mut self, active: ActiveBar) -> Self {
+        self.progress = Some(active);
+        self
+    }
+
+    pub fn build(self) -> Campaign {
+        let mutations = self
+            .mutations
+            .or_else(|| self.config.resolve_mutations(None));
+        let comprehensive = self
+            .comprehensive
+            .or_else(|| self.config.run().and_then(|r| r.comprehensive))
+            .unwrap_or(false);
+        Campaign {
+            config: self.config,
+            registry: self.registry,
+            store: self.store,
+            targets: self.targets,
+            mutations,
+            test_cmd: self.test_cmd,
+            test_timeout: self.test_timeout,
+            comprehensive,
+            verbose: self.verbose,
+            running: self
+                .running
+                .unwrap_or_else(|| Arc::new(AtomicBool::new(true))),
+            on_event: self.on_event,
+            progress: self.progress,
+        }
+    }
+}
+
+impl Campaign {
+    pub fn builder(
+        config: Config,
+        registry: Arc<LanguageRegistry>,
+        store: SqlStore,
+    ) -> CampaignBuilder {
+        CampaignBuilder {
+            config,
+            registry,
+            store,
+            targets: None,
+            mutations: None,
+            test_cmd: None,
+            test_timeout: None,
+            comprehensive: None,
+            verbose: false,
+            running: None,
+            on_event: None,
+            progress: None,
+        }
+    }
+
+    /// Stop the campaign after the mutant being tested; the target file is restored
+    pub fn cancel(&self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+
+    /// Generate mutants for the targets (if any), then test every untested mutant
+    pub async fn run(&self) -> AppResult<CampaignResult> {
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let events: EventHandler = {
+            let results = Arc::clone(&results);
+            let on_event = self.on_event.clone();
+            Arc::new(move |event: &CampaignEvent| {
+                if let CampaignEvent::MutantTested(result) = event {
+                    results.lock().unwrap().push(result.clone());
+                }
+                if let Some(on_event) = &on_event {
+                    on_event(event);
+                }
+            })
+        };
+
+        let mutations = self.mutations.as_deref();
+        let mut generated = 0;
+        let targets = if let Some(resolved) = &self.targets {
+            // Generate new mutants for the specified targets
+            let targets = Target::load_targets(resolved, &self.store, &self.registry).await?;
+            let all_mutants = {
+                // Parsing is CPU-bound; run it on the blocking pool
+                let (targets, registry) = (targets.clone(), Arc::clone(&self.registry));
+                let (mutations, config) = (self.mutations.clone(), self.config.clone());
+                tokio::task::spawn_blocking(move || {
+                    Target::generate_all_mutants(&targets, &registry, mutations.as_deref(), &config)
+                })
+                .await
+                .map_err(io::Error::other)?
+            };
+            for (target, mutants_res) in targets.iter().zip(all_mutants) {
+                if let Ok(mutants) = mutants_res {
+                    let new_ids = self.store.add_mutants(&mutants).await?;
+                    let mut count = 0;
+                    for (mut mutant, new_id) in mutants.into_iter().zip(new_ids) {
+                        if let Some(id) = new_id {
+                            mutant.id = id;
+                            info!("  Saved new mutant: {}", mutant.display(target));
+                            count += 1;
+                        }
+                    }
+                    if count > 0 {
+                        events(&CampaignEvent::MutantsGenerated {
+                            target: target.path.clone(),
+                            count,
+                        });
+                    }
+                    generated += count;
+                }
+            }
+            targets
+        } else {
+            // Skip mutation generation, get targets for existing mutants to test (no outcomes + timeouts)
+            let (mutants_to_test, _, _) = self.store.get_mutants_to_test().await?;
+            if mutants_to_test.is_empty() {
+                info!("No mutants to test found in database");
+            }
+
+            // Get unique targets for these mutants
+            let mut target_ids: Vec<i64> = mutants_to_test.iter().map(|m| m.target_id).collect();
+            target_ids.sort_unstable();
+            target_ids.dedup();
+
+            let mut targets = Vec::new();
+            for target_id in target_ids {
+                targets.push(self.store.get_target(target_id).await?);
+            }
+            targets
+        };
+
+        // Group targets by resolved (test_cmd, timeout)
+        let mut groups: HashMap<(String, Option<u32>), Vec<Target>> = HashMap::new();
+        for target in targets.into_iter() {
+            let (maybe_cmd, timeout) = self.config.resolve_test_for_path(
+                &target.path,
+                self.test_cmd.as_deref(),
+                self.test_timeout,
+            );
+            if let Some(cmd) = maybe_cmd {
+                groups.entry((cmd, timeout)).or_default().push(target);
+            } else {
+                warn!("No test command provided for target {}", target.display());
+            }
+        }
+
+        // For each group, create a runner (baseline once per unique cmd) and run campaign
+        for ((cmd, timeout), group_targets) in groups.into_iter() {
+            if !self.running.load(Ordering::SeqCst) {
+                warn!("Mutation campaign cancelled before execution");
+                break;
+            }
+
+            let mut runner = TestRunner::new_with_baseline(
+                cmd,
+                timeout,
+                Arc::clone(&self.running),
+                self.store.clone(),
+                self.comprehensive,
+                self.verbose,
+                Arc::clone(&self.registry),
+                self.config.clone(),
+                Some(Arc::clone(&events)),
+            )
+            .await?;
+            if let Some(active) = &self.progress {
+                runner.show_progress(active.clone());
+            }
+
+            runner
+                .run_mutation_campaign(group_targets, mutations.map(|v| v.join(",")))
+                .await?;
+        }
+
+        // Query DB once at the end for final counts
+        let summary = self.store.get_campaign_summary().await?;
+        let interrupted = !self.running.load(Ordering::SeqCst);
+        events(&CampaignEvent::Finished {
+            summary: summary.clone(),
+            interrupted,
+        });
+
+        let results = std::mem::take(&mut *results.lock().unwrap());
+        Ok(CampaignResult {
+            summary,
+            generated,
+            results,
+            interrupted,
+        })
+    }
+}
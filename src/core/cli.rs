@@ -1,5 +1,7 @@
 use clap::{Parser, Subcommand};
 
+use crate::core::events::EventsOutput;
+
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
@@ -20,6 +22,10 @@ pub struct Args {
     #[arg(long = "log.color", global = true)]
     pub log_color: Option<String>,
 
+    /// Overlay the named [profile.<name>] section of the config file
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -27,7 +33,7 @@ pub struct Args {
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Initialize a new workspace (config + database)
-    Init,
+    Init(InitArgs),
     /// Run a mutation testing campaign
     Run(RunArgs),
 
@@ -54,6 +60,30 @@ pub enum Commands {
 
     /// Purge targets, mutants, and outcomes from the database
     Purge(PurgeArgs),
+
+    /// Serve a JSON-RPC API for querying results and queueing mutants to test
+    Serve(ServeArgs),
+
+    /// Run a language server over stdio showing surviving mutants as diagnostics
+    Lsp(LspArgs),
+
+    /// Inspect the config file
+    Config {
+        #[command(subcommand)]
+        command: ConfigArgs,
+    },
+}
+
+/// Arguments for the init command
+#[derive(Parser, Debug)]
+pub struct InitArgs {
+    /// Never prompt; an existing config file is left unchanged unless --force is given
+    #[arg(long)]
+    pub non_interactive: bool,
+
+    /// Overwrite an existing config file without asking
+    #[arg(long)]
+    pub force: bool,
 }
 
 /// Arguments for the run command
@@ -67,11 +97,17 @@ pub struct RunArgs {
     #[arg(value_name = "TARGET")]
     pub targets: Vec<String>,
 
-    /// Comma-separated substrings; any target path containing any will be ignored.
+    /// Comma-separated glob patterns; matching target paths will be ignored.
+    /// A pattern without a '/' (e.g. "vendor" or "*.gen.rs") matches a name at any depth.
     /// Replaces config [targets].ignore if provided.
     #[arg(long = "ignore-targets")]
     pub ignore_targets: Option<String>,
 
+    /// Don't skip files listed in .gitignore, .ignore or .mewtignore files. Hidden
+    /// entries are still skipped. Overrides config [targets].respect_ignore_files.
+    #[arg(long = "no-ignore")]
+    pub no_ignore: bool,
+
     /// Comma-separated list of mutation slugs to test (e.g., "ER,CR").
     /// Use 'print mutations' subcommand for a full list of available slugs.
     /// If omitted, all mutation types are enabled.
@@ -97,6 +133,11 @@ pub struct RunArgs {
     /// Stream stdout and stderr from baseline test to stdout
     #[arg(long)]
     pub verbose: bool,
+
+    /// Emit progress events as newline-delimited JSON.
+    /// "jsonl" writes them to stdout (logs go to stderr instead); "jsonl=<path>" writes a file.
+    #[arg(long, value_name = "jsonl[=PATH]")]
+    pub events: Option<EventsOutput>,
 }
 
 /// Arguments for the mutate command
@@ -109,10 +150,16 @@ pub struct MutateArgs {
     #[arg(value_name = "TARGET", required = true)]
     pub targets: Vec<String>,
 
-    /// Comma-separated substrings; any target path containing any will be ignored.
+    /// Comma-separated glob patterns; matching target paths will be ignored.
+    /// A pattern without a '/' (e.g. "vendor" or "*.gen.rs") matches a name at any depth.
     /// Replaces config [targets].ignore if provided.
     #[arg(long = "ignore-targets")]
     pub ignore_targets: Option<String>,
+
+    /// Don't skip files listed in .gitignore, .ignore or .mewtignore files. Hidden
+    /// entries are still skipped. Overrides config [targets].respect_ignore_files.
+    #[arg(long = "no-ignore")]
+    pub no_ignore: bool,
 }
 
 /// Arguments for the list-mutations command
@@ -150,6 +197,13 @@ pub enum PrintArgs {
     Config(PrintConfigArgs),
 }
 
+/// Arguments for the config command
+#[derive(Subcommand, Debug)]
+pub enum ConfigArgs {
+    /// Report every problem in the config file and exit non-zero if there are any
+    Check,
+}
+
 /// Arguments for the print targets subcommand
 #[derive(Parser, Debug)]
 pub struct PrintTargetsArgs {
@@ -197,7 +251,7 @@ pub struct ResultsArgs {
     #[arg(long, default_value = "false")]
     pub all: bool,
 
-    /// Filter by status (e.g., Uncaught, TestFail, Skipped, Timeout)
+    /// Filter by status (e.g., Uncaught, TestFail, Skipped, Timeout, Equivalent)
     #[arg(long)]
     pub status: Option<String>,
 
@@ -281,6 +335,47 @@ pub struct TestArgs {
     pub verbose: bool,
 }
 
+/// Arguments for the serve command
+#[derive(Parser, Debug)]
+pub struct ServeArgs {
+    /// Unix socket path to listen on, or a localhost TCP address such as "127.0.0.1:7070"
+    #[arg(long, value_name = "PATH|ADDR")]
+    pub socket: String,
+
+    /// Token TCP clients must send in an `auth` request before any other.
+    /// A random one is generated and logged if omitted; Unix sockets don't use it.
+    #[arg(long)]
+    pub token: Option<String>,
+
+    /// Test command for all targets.
+    /// Replaces config [test].cmd if provided.
+    #[arg(long = "test.cmd")]
+    pub test_cmd: Option<String>,
+
+    /// Test timeout in seconds.
+    /// Replaces config [test].timeout if provided.
+    #[arg(long = "test.timeout")]
+    pub test_timeout: Option<u32>,
+
+    /// Stream stdout and stderr from tests to the log
+    #[arg(long)]
+    pub verbose: bool,
+}
+
+/// Arguments for the lsp command
+#[derive(Parser, Debug)]
+pub struct LspArgs {
+    /// Test command used to re-test mutants.
+    /// Replaces config [test].cmd if provided.
+    #[arg(long = "test.cmd")]
+    pub test_cmd: Option<String>,
+
+    /// Test timeout in seconds.
+    /// Replaces config [test].timeout if provided.
+    #[arg(long = "test.timeout")]
+    pub test_timeout: Option<u32>,
+}
+
 /// Arguments for the purge command
 #[derive(Parser, Debug)]
 pub struct PurgeArgs {
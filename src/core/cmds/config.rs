@@ -0,0 +1,31 @@
+use log::{error, info};
+
+use crate::LanguageRegistry;
+use crate::types::config::{Config, check_nearest_config};
+
+/// Check the nearest config file for `namespace` and report every problem found.
+/// Returns the number of problems.
+pub async fn execute_config_check(
+    namespace: &str,
+    registry: &LanguageRegistry,
+    profile: Option<&str>,
+) -> usize {
+    let (path, issues) = check_nearest_config(namespace, &registry.mutation_slugs(), profile);
+    for issue in &issues {
+        error!("{issue}");
+    }
+    let Some(path) = path else {
+        info!(
+            "No {} found; using defaults",
+            Config::for_namespace(namespace).config_filename()
+        );
+        return issues.len();
+    };
+
+    if issues.is_empty() {
+        info!("{} is valid", path.display());
+    } else {
+        error!("Found {} configuration problem(s)", issues.len());
+    }
+    issues.len()
+}
@@ -1,30 +1,132 @@
 use std::fs;
-use std::io::Write;
+use std::io::{self, BufRead, IsTerminal, Write};
 use std::path::PathBuf;
 
 use log::{info, warn};
 
+use crate::core::cli::InitArgs;
 use crate::types::AppResult;
-use crate::types::config::{get_config_filename, get_namespace};
+use crate::types::config::Config;
+
+pub mod detect;
+
+use detect::{DetectedProject, detect_project};
 
 const EXAMPLE_CONFIG: &str = include_str!("../../example.toml");
 
-pub async fn execute_init() -> AppResult<()> {
+pub async fn execute_init(args: InitArgs, config: &Config) -> AppResult<()> {
     info!("Initializing config file...");
 
-    let config_filename = get_config_filename();
-    let cfg_path = PathBuf::from(config_filename);
-    if cfg_path.exists() {
-        warn!("{} already exists; leaving it unchanged", config_filename);
-    } else {
-        // Replace {namespace} placeholder with actual namespace
-        let namespace = get_namespace();
-        let config_content = EXAMPLE_CONFIG.replace("{namespace}", namespace);
-
-        let mut f = fs::File::create(&cfg_path)?;
-        f.write_all(config_content.as_bytes())?;
-        info!("Created {}", cfg_path.display());
+    let config_filename = config.config_filename();
+    let cfg_path = PathBuf::from(&config_filename);
+    if cfg_path.exists() && !args.force {
+        let interactive = !args.non_interactive && io::stdin().is_terminal();
+        if !interactive {
+            warn!(
+                "{} already exists; leaving it unchanged (use --force to overwrite)",
+                config_filename
+            );
+            return Ok(());
+        }
+        if !confirm(&format!("{config_filename} already exists. Overwrite it?"))? {
+            info!("Leaving {} unchanged", config_filename);
+            return Ok(());
+        }
     }
 
+    let detected = detect_project(&std::env::current_dir()?);
+    match &detected {
+        Some(project) => info!("Detected {} project", project.kind),
+        None => info!("No known project type detected; writing the generic template"),
+    }
+
+    let config_content = render_config(config.namespace(), detected.as_ref());
+    let mut f = fs::File::create(&cfg_path)?;
+    f.write_all(config_content.as_bytes())?;
+    info!("Created {}", cfg_path.display());
+
     Ok(())
 }
+
+/// Ask a yes/no question on stderr, defaulting to no
+fn confirm(question: &str) -> io::Result<bool> {
+    eprint!("{question} [y/N] ");
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Fill the example config for `namespace`, uncommenting the settings a detected project provides
+fn render_config(namespace: &str, detected: Option<&DetectedProject>) -> String {
+    // Replace {namespace}/{NAMESPACE} placeholders with actual namespace
+    let mut content = EXAMPLE_CONFIG
+        .replace("{namespace}", namespace)
+        .replace("{NAMESPACE}", &namespace.to_uppercase().replace('-', "_"));
+
+    if let Some(project) = detected {
+        let list = |values: &[String]| {
+            toml::Value::Array(values.iter().cloned().map(toml::Value::String).collect())
+        };
+        content = set_template_value(&content, "targets", "include", list(&project.include));
+        content = set_template_value(&content, "targets", "ignore", list(&project.ignore));
+        content = set_template_value(
+            &content,
+            "test",
+            "cmd",
+            toml::Value::String(project.test_cmd.clone()),
+        );
+        content = format!("## Detected project: {}\n\n{content}", project.kind);
+    }
+    content
+}
+
+/// Replace the first commented-out `key = ...` line in `[section]` with `key = value`
+fn set_template_value(template: &str, section: &str, key: &str, value: toml::Value) -> String {
+    let header = format!("[{section}]");
+    let commented = format!("# {key} = ");
+    let mut in_section = false;
+    let mut replaced = false;
+    let mut lines = Vec::new();
+    for line in template.lines() {
+        if line.starts_with('[') {
+            in_section = line.trim() == header;
+        }
+        if in_section && !replaced && line.starts_with(&commented) {
+            lines.push(format!("{key} = {value}"));
+            replaced = true;
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::config::parse_config;
+    use std::path::Path;
+
+    #[test]
+    fn rendered_configs_are_valid() {
+        let project = DetectedProject {
+            kind: "Go module",
+            test_cmd: "go test ./...".to_string(),
+            include: vec![".".to_string()],
+            ignore: vec!["vendor/".to_string(), "*_test.go".to_string()],
+        };
+        let rendered = render_config("mewt", Some(&project));
+        let cfg = parse_config(Path::new("mewt.toml"), &rendered, None).unwrap();
+        assert_eq!(cfg.test().cmd(), Some("go test ./..."));
+        assert_eq!(cfg.targets().unwrap().include, Some(vec![".".to_string()]));
+        assert_eq!(cfg.targets().unwrap().ignore, Some(project.ignore.clone()));
+        // Per-target examples further down stay commented out
+        assert!(cfg.test().per_target().is_empty());
+
+        let generic = render_config("mewt", None);
+        let cfg = parse_config(Path::new("mewt.toml"), &generic, None).unwrap();
+        assert_eq!(cfg.db.as_deref(), Some("mewt.sqlite"));
+        assert!(cfg.targets().unwrap().include.is_none());
+    }
+}
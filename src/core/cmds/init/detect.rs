@@ -0,0 +1,231 @@
+use std::fs;
+use std::path::Path;
+
+/// Settings inferred from the marker files of a project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedProject {
+    /// Human-readable project kind, e.g. "Foundry"
+    pub kind: &'static str,
+    pub test_cmd: String,
+    pub include: Vec<String>,
+    pub ignore: Vec<String>,
+}
+
+/// Detect the project in `dir` from its marker files.
+/// Solidity frameworks are checked first since their projects usually have a package.json too.
+pub fn detect_project(dir: &Path) -> Option<DetectedProject> {
+    detect_foundry(dir)
+        .or_else(|| detect_hardhat(dir))
+        .or_else(|| detect_cargo(dir))
+        .or_else(|| detect_go(dir))
+        .or_else(|| detect_node(dir))
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+fn read_toml(path: &Path) -> Option<toml::Table> {
+    toml::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn detect_foundry(dir: &Path) -> Option<DetectedProject> {
+    let path = dir.join("foundry.toml");
+    if !path.is_file() {
+        return None;
+    }
+    // Sources live in [profile.default].src, "src" by default
+    let src = read_toml(&path)
+        .and_then(|t| {
+            let default = t.get("profile")?.get("default")?;
+            default.get("src")?.as_str().map(str::to_string)
+        })
+        .unwrap_or_else(|| "src".to_string());
+    Some(DetectedProject {
+        kind: "Foundry",
+        test_cmd: "forge test".to_string(),
+        include: vec![src],
+        ignore: strings(&["lib/", "out/", "cache/", "*.t.sol", "*.s.sol"]),
+    })
+}
+
+fn detect_hardhat(dir: &Path) -> Option<DetectedProject> {
+    ["js", "ts", "cjs", "mjs", "cts", "mts"]
+        .iter()
+        .any(|ext| dir.join(format!("hardhat.config.{ext}")).is_file())
+        .then(|| DetectedProject {
+            kind: "Hardhat",
+            test_cmd: "npx hardhat test".to_string(),
+            include: strings(&["contracts"]),
+            ignore: strings(&["node_modules/", "artifacts/", "cache/", "typechain-types/"]),
+        })
+}
+
+fn detect_cargo(dir: &Path) -> Option<DetectedProject> {
+    let manifest = read_toml(&dir.join("Cargo.toml"))?;
+    let members: Vec<String> = manifest
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(|m| m.as_array())
+        .map(|members| {
+            members
+                .iter()
+                .filter_map(|m| m.as_str())
+                .map(|m| format!("{}/src", m.trim_end_matches('/')))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut include = Vec::new();
+    if manifest.contains_key("package") || members.is_empty() {
+        include.push("src".to_string());
+    }
+    let workspace = !members.is_empty();
+    include.extend(members);
+
+    Some(DetectedProject {
+        kind: if workspace {
+            "Cargo workspace"
+        } else {
+            "Cargo"
+        },
+        test_cmd: if workspace {
+            "cargo test --workspace".to_string()
+        } else {
+            "cargo test".to_string()
+        },
+        include,
+        ignore: strings(&["target/"]),
+    })
+}
+
+fn detect_go(dir: &Path) -> Option<DetectedProject> {
+    dir.join("go.mod").is_file().then(|| DetectedProject {
+        kind: "Go module",
+        test_cmd: "go test ./...".to_string(),
+        include: strings(&["."]),
+        ignore: strings(&["vendor/", "testdata/", "*_test.go"]),
+    })
+}
+
+fn detect_node(dir: &Path) -> Option<DetectedProject> {
+    let contents = fs::read_to_string(dir.join("package.json")).ok()?;
+    let package: serde_json::Value = serde_json::from_str(&contents).ok()?;
+
+    let runner = if dir.join("pnpm-lock.yaml").is_file() {
+        "pnpm"
+    } else if dir.join("yarn.lock").is_file() {
+        "yarn"
+    } else {
+        "npm"
+    };
+    let has_dependency = |name: &str| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|deps| package.get(deps).and_then(|d| d.get(name)).is_some())
+    };
+    // npm init writes a placeholder test script that always fails
+    let test_script = package
+        .get("scripts")
+        .and_then(|s| s.get("test"))
+        .and_then(|t| t.as_str())
+        .filter(|t| !t.contains("no test specified"));
+
+    let test_cmd = if test_script.is_some() {
+        format!("{runner} test")
+    } else if has_dependency("vitest") {
+        "npx vitest run".to_string()
+    } else if has_dependency("jest") {
+        "npx jest".to_string()
+    } else {
+        format!("{runner} test")
+    };
+    let include = ["src", "lib"]
+        .iter()
+        .find(|d| dir.join(d).is_dir())
+        .map_or_else(|| ".".to_string(), |d| d.to_string());
+
+    Some(DetectedProject {
+        kind: "Node.js",
+        test_cmd,
+        include: vec![include],
+        ignore: strings(&[
+            "node_modules/",
+            "dist/",
+            "build/",
+            "coverage/",
+            "__tests__/",
+            "*.test.*",
+            "*.spec.*",
+        ]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn project(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        for (path, contents) in files {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn detects_cargo_workspace_members() {
+        let dir = project(&[(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\", \"cli/\"]\n",
+        )]);
+        let detected = detect_project(dir.path()).unwrap();
+        assert_eq!(detected.kind, "Cargo workspace");
+        assert_eq!(detected.test_cmd, "cargo test --workspace");
+        assert_eq!(detected.include, vec!["crates/*/src", "cli/src"]);
+    }
+
+    #[test]
+    fn foundry_wins_over_package_json() {
+        let dir = project(&[
+            ("foundry.toml", "[profile.default]\nsrc = \"contracts\"\n"),
+            ("package.json", "{\"scripts\": {\"test\": \"jest\"}}"),
+        ]);
+        let detected = detect_project(dir.path()).unwrap();
+        assert_eq!(detected.kind, "Foundry");
+        assert_eq!(detected.test_cmd, "forge test");
+        assert_eq!(detected.include, vec!["contracts"]);
+    }
+
+    #[test]
+    fn detects_node_test_runner() {
+        let dir = project(&[
+            (
+                "package.json",
+                r#"{"scripts": {"test": "echo \"Error: no test specified\" && exit 1"},
+                    "devDependencies": {"vitest": "^1.0.0"}}"#,
+            ),
+            ("lib/index.js", ""),
+        ]);
+        let detected = detect_project(dir.path()).unwrap();
+        assert_eq!(detected.test_cmd, "npx vitest run");
+        assert_eq!(detected.include, vec!["lib"]);
+
+        let dir = project(&[
+            ("package.json", r#"{"scripts": {"test": "jest"}}"#),
+            ("yarn.lock", ""),
+        ]);
+        let detected = detect_project(dir.path()).unwrap();
+        assert_eq!(detected.test_cmd, "yarn test");
+        assert_eq!(detected.include, vec!["."]);
+    }
+
+    #[test]
+    fn unknown_projects_are_not_detected() {
+        let dir = project(&[("README.md", "")]);
+        assert_eq!(detect_project(dir.path()), None);
+    }
+}
@@ -0,0 +1,860 @@
+//! `lsp`: a language server over stdio that shows surviving mutants next to the code.
+//!
+//! `Uncaught` and `Timeout` mutants are published as diagnostics on the text they replace.
+//! Hovering one shows the mutated line, and its code actions queue it for re-testing or
+//! mark it as equivalent. Diagnostics are refreshed whenever the database changes, and only
+//! shown for files whose contents match what was mutated, since positions in an edited
+//! file can't be trusted.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{debug, error, info, warn};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification,
+    PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, ExecuteCommand, HoverRequest, Request};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Command, Diagnostic,
+    DiagnosticSeverity, ExecuteCommandOptions, ExecuteCommandParams, Hover, HoverContents,
+    HoverParams, HoverProviderCapability, InitializeResult, MarkupContent, MarkupKind,
+    NumberOrString, Position, PublishDiagnosticsParams, Range, ServerCapabilities, ServerInfo,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use crate::LanguageRegistry;
+use crate::SqlStore;
+use crate::core::campaign::CampaignEvent;
+use crate::core::cli::LspArgs;
+use crate::core::main_shared::open_store;
+use crate::core::queue::TestQueue;
+use crate::types::config::Config;
+use crate::types::{AppError, AppResult, Mutant, Outcome, Status, Target};
+
+const RETEST_COMMAND: &str = "mewt.retest";
+const MARK_EQUIVALENT_COMMAND: &str = "mewt.markEquivalent";
+
+// JSON-RPC 2.0 and LSP error codes
+const SERVER_NOT_INITIALIZED: i64 = -32002;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+/// An `Uncaught` or `Timeout` mutant
+#[derive(Debug, Clone)]
+struct Survivor {
+    mutant: Mutant,
+    target: Target,
+    status: Status,
+}
+
+impl Survivor {
+    /// The replaced text's range in the target, if the offsets fit its text
+    fn range(&self) -> Option<Range> {
+        let start = self.mutant.byte_offset as usize;
+        let end = start + self.mutant.old_text.len();
+        Some(Range::new(
+            position_at(&self.target.text, start)?,
+            position_at(&self.target.text, end)?,
+        ))
+    }
+
+    fn diagnostic(&self) -> Option<Diagnostic> {
+        let (severity, verdict) = match self.status {
+            Status::Uncaught => (DiagnosticSeverity::WARNING, "No test caught"),
+            _ => (DiagnosticSeverity::INFORMATION, "Tests timed out with"),
+        };
+        Some(Diagnostic {
+            range: self.range()?,
+            severity: Some(severity),
+            code: Some(NumberOrString::String(self.mutant.mutation_slug.clone())),
+            source: Some("mewt".to_string()),
+            message: format!(
+                "{verdict} `{}` in place of `{}` (mutant {})",
+                self.mutant.new_text, self.mutant.old_text, self.mutant.id
+            ),
+            data: Some(json!({ "mutant_id": self.mutant.id })),
+            ..Default::default()
+        })
+    }
+
+    /// Markdown showing the line(s) as the mutant rewrote them
+    fn hover_text(&self) -> String {
+        let fence = self.target.language.to_lowercase();
+        let mutated = mutated_lines(&self.mutant, &self.target)
+            .unwrap_or_else(|| self.mutant.new_text.clone());
+        format!(
+            "**{}** mutant {} (`{}`)\n\n```{fence}\n{mutated}\n```",
+            self.status, self.mutant.id, self.mutant.mutation_slug
+        )
+    }
+}
+
+/// Line/UTF-16 position of a byte offset in `text`
+fn position_at(text: &str, byte_offset: usize) -> Option<Position> {
+    let before = text.get(..byte_offset)?;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() as u32;
+    let character = before[line_start..].encode_utf16().count() as u32;
+    Some(Position::new(line, character))
+}
+
+/// The full line(s) spanned by the mutated text, after mutation
+fn mutated_lines(mutant: &Mutant, target: &Target) -> Option<String> {
+    let mutated = target.mutate(mutant).ok()?;
+    let start = mutant.byte_offset as usize;
+    let end = start + mutant.new_text.len();
+    let line_start = mutated.get(..start)?.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = mutated
+        .get(end..)?
+        .find('\n')
+        .map_or(mutated.len(), |i| end + i);
+    Some(mutated[line_start..line_end].to_string())
+}
+
+fn overlaps(a: &Range, b: &Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+#[derive(Debug)]
+struct LspError {
+    code: i64,
+    message: String,
+}
+
+impl LspError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<AppError> for LspError {
+    fn from(e: AppError) -> Self {
+        LspError::new(INTERNAL_ERROR, e.to_string())
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, LspError> {
+    serde_json::from_value(params).map_err(|e| LspError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn to_value(value: impl serde::Serialize) -> Result<Value, LspError> {
+    serde_json::to_value(value).map_err(|e| LspError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+struct LanguageServer {
+    store: SqlStore,
+    queue: Arc<TestQueue>,
+    /// The workspace root the editor opened, which relative target paths and the database
+    /// path are resolved against
+    root: PathBuf,
+    /// Surviving mutants by absolute target path, for every stored version of the file
+    survivors: Mutex<HashMap<PathBuf, Vec<Survivor>>>,
+    /// Text of the documents open in the editor, by absolute path
+    documents: Mutex<HashMap<PathBuf, String>>,
+    /// Files that currently have diagnostics, so they can be cleared
+    published: Mutex<HashSet<PathBuf>>,
+    output: mpsc::UnboundedSender<Value>,
+}
+
+pub async fn execute_lsp(
+    args: LspArgs,
+    config: Config,
+    running: Arc<AtomicBool>,
+    registry: Arc<LanguageRegistry>,
+) -> AppResult<()> {
+    let (output, outgoing) = mpsc::unbounded_channel();
+    let writer = tokio::spawn(write_messages(outgoing));
+    let (incoming, mut messages) = mpsc::unbounded_channel();
+    // A blocking read of stdin on the runtime would keep it alive after `exit`
+    std::thread::spawn(move || read_messages(io::stdin().lock(), incoming));
+    info!("Language server started");
+
+    let Some(initialize) = wait_for_initialize(&mut messages, &output, &running).await else {
+        drop(output);
+        let _ = writer.await;
+        return Ok(());
+    };
+    let params = initialize.get("params").cloned().unwrap_or(Value::Null);
+    let root = match workspace_root(&params) {
+        Some(root) => root.canonicalize().unwrap_or(root),
+        None => std::env::current_dir()?,
+    };
+    // Re-tests run their command from the root, as `test` does from the project directory
+    std::env::set_current_dir(&root)?;
+    let db = root.join(config.db());
+    info!("Workspace root: {}", root.display());
+    let store = open_store(&db).await?;
+
+    // Refresh when the database changes, or when the queue reports a result in case the
+    // change isn't seen (e.g. on file systems without notifications)
+    let (changed, mut changes) = mpsc::unbounded_channel();
+    let tested = changed.clone();
+    let queue = Arc::new(TestQueue::new(
+        store.clone(),
+        registry,
+        config,
+        args.test_cmd,
+        args.test_timeout,
+        false,
+        Arc::new(move |event: &CampaignEvent| {
+            if matches!(event, CampaignEvent::MutantTested(_)) {
+                let _ = tested.send(());
+            }
+        }),
+    ));
+    let _watcher = match watch_database(&db, changed) {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            warn!("Not watching the database for changes: {e}");
+            None
+        }
+    };
+
+    let server = Arc::new(LanguageServer {
+        store,
+        queue: Arc::clone(&queue),
+        root,
+        survivors: Mutex::new(HashMap::new()),
+        documents: Mutex::new(HashMap::new()),
+        published: Mutex::new(HashSet::new()),
+        output,
+    });
+    let worker = tokio::spawn(queue.work());
+    let refresher = {
+        let server = Arc::clone(&server);
+        tokio::spawn(async move {
+            while changes.recv().await.is_some() {
+                // A campaign writes in bursts; wait for it to settle
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                while changes.try_recv().is_ok() {}
+                server.refresh().await;
+            }
+        })
+    };
+
+    server.handle_message(initialize).await;
+    while running.load(Ordering::SeqCst) {
+        let message = tokio::select! {
+            message = messages.recv() => message,
+            _ = tokio::time::sleep(Duration::from_millis(200)) => continue,
+        };
+        let Some(message) = message else {
+            break;
+        };
+        if message.get("method").and_then(Value::as_str) == Some("exit") {
+            break;
+        }
+        server.handle_message(message).await;
+    }
+
+    // Interrupt the current test (its target is restored) and let the worker finish
+    info!("Shutting down language server...");
+    server.queue.stop();
+    refresher.abort();
+    let _ = refresher.await;
+    if let Err(e) = worker.await {
+        error!("Test worker failed: {e}");
+    }
+    drop(server);
+    let _ = writer.await;
+    Ok(())
+}
+
+/// Wait for the `initialize` request, refusing any other request until then. Returns
+/// `None` if the client exits or the input closes first.
+async fn wait_for_initialize(
+    messages: &mut mpsc::UnboundedReceiver<Value>,
+    output: &mpsc::UnboundedSender<Value>,
+    running: &AtomicBool,
+) -> Option<Value> {
+    while running.load(Ordering::SeqCst) {
+        let message = tokio::select! {
+            message = messages.recv() => message?,
+            _ = tokio::time::sleep(Duration::from_millis(200)) => continue,
+        };
+        let method = message.get("method").and_then(Value::as_str);
+        match (method, message.get("id")) {
+            (Some("initialize"), _) => return Some(message),
+            (Some("exit"), _) => return None,
+            (Some(method), Some(id)) => {
+                let _ = output.send(json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": {
+                        "code": SERVER_NOT_INITIALIZED,
+                        "message": format!("`{method}` before `initialize`"),
+                    },
+                }));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The first workspace folder, or the (deprecated) root URI or path, of `initialize` params
+fn workspace_root(params: &Value) -> Option<PathBuf> {
+    let uri = params
+        .pointer("/workspaceFolders/0/uri")
+        .or_else(|| params.get("rootUri"))
+        .and_then(Value::as_str);
+    match uri {
+        Some(uri) => Url::parse(uri).ok()?.to_file_path().ok(),
+        None => params
+            .get("rootPath")
+            .and_then(Value::as_str)
+            .map(PathBuf::from),
+    }
+}
+
+/// Send a notification whenever the database file (or its journal) is written
+fn watch_database(
+    db: &Path,
+    changed: mpsc::UnboundedSender<()>,
+) -> notify::Result<notify::RecommendedWatcher> {
+    let name = db
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // Readers touch the `-shm` index, so only the database and its journals count
+    let names: HashSet<String> = ["", "-wal", "-journal"]
+        .iter()
+        .map(|suffix| format!("{name}{suffix}"))
+        .collect();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            return;
+        }
+        let ours = event.paths.iter().any(|path| {
+            path.file_name()
+                .is_some_and(|file| names.contains(file.to_string_lossy().as_ref()))
+        });
+        if ours {
+            let _ = changed.send(());
+        }
+    })?;
+    let dir = match db.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    Ok(watcher)
+}
+
+/// Read `Content-Length` framed messages until the input closes
+fn read_messages(mut input: impl BufRead, messages: mpsc::UnboundedSender<Value>) {
+    loop {
+        match read_message(&mut input) {
+            Ok(Some(message)) => {
+                if messages.send(message).is_err() {
+                    break;
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                error!("Failed to read message: {e}");
+                break;
+            }
+        }
+    }
+}
+
+fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let length = length.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length header")
+    })?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+async fn write_messages(mut messages: mpsc::UnboundedReceiver<Value>) {
+    let mut stdout = tokio::io::stdout();
+    while let Some(message) = messages.recv().await {
+        let body = message.to_string();
+        let framed = format!("Content-Length: {}\r\n\r\n{body}", body.len());
+        if stdout.write_all(framed.as_bytes()).await.is_err() || stdout.flush().await.is_err() {
+            break;
+        }
+    }
+}
+
+impl LanguageServer {
+    fn send(&self, message: Value) {
+        let _ = self.output.send(message);
+    }
+
+    fn notify<N: Notification>(&self, params: N::Params) {
+        self.send(json!({ "jsonrpc": "2.0", "method": N::METHOD, "params": params }));
+    }
+
+    async fn handle_message(&self, message: Value) {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to requests we never send
+            return;
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        let Some(id) = message.get("id").cloned() else {
+            if let Err(e) = self.handle_notification(method, params).await {
+                warn!("Failed to handle {method}: {}", e.message);
+            }
+            return;
+        };
+        let response = match self.handle_request(method, params).await {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": e.code, "message": e.message },
+            }),
+        };
+        self.send(response);
+    }
+
+    async fn handle_request(&self, method: &str, params: Value) -> Result<Value, LspError> {
+        match method {
+            "initialize" => to_value(InitializeResult {
+                capabilities: ServerCapabilities {
+                    text_document_sync: Some(TextDocumentSyncCapability::Kind(
+                        TextDocumentSyncKind::FULL,
+                    )),
+                    hover_provider: Some(HoverProviderCapability::Simple(true)),
+                    code_action_provider: Some(true.into()),
+                    execute_command_provider: Some(ExecuteCommandOptions {
+                        commands: vec![
+                            RETEST_COMMAND.to_string(),
+                            MARK_EQUIVALENT_COMMAND.to_string(),
+                        ],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                server_info: Some(ServerInfo {
+                    name: "mewt".to_string(),
+                    version: Some(env!("CARGO_PKG_VERSION").to_string()),
+                }),
+            }),
+            "shutdown" => Ok(Value::Null),
+            HoverRequest::METHOD => {
+                let p: HoverParams = parse_params(params)?;
+                let position = p.text_document_position_params.position;
+                let path = self.path_of(&p.text_document_position_params.text_document.uri);
+                let survivors: Vec<(Survivor, Range)> = self
+                    .current_survivors(&path)
+                    .into_iter()
+                    .filter_map(|s| s.range().map(|range| (s, range)))
+                    .filter(|(_, range)| range.start <= position && position <= range.end)
+                    .collect();
+                let Some((_, range)) = survivors.first() else {
+                    return Ok(Value::Null);
+                };
+                to_value(Hover {
+                    range: Some(*range),
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: survivors
+                            .iter()
+                            .map(|(s, _)| s.hover_text())
+                            .collect::<Vec<_>>()
+                            .join("\n\n---\n\n"),
+                    }),
+                })
+            }
+            CodeActionRequest::METHOD => {
+                let p: CodeActionParams = parse_params(params)?;
+                let path = self.path_of(&p.text_document.uri);
+                let mut actions = Vec::new();
+                for survivor in self.current_survivors(&path) {
+                    let Some(diagnostic) = survivor.diagnostic() else {
+                        continue;
+                    };
+                    if !overlaps(&diagnostic.range, &p.range) {
+                        continue;
+                    }
+                    let id = survivor.mutant.id;
+                    for (title, command) in [
+                        (format!("Re-test mutant {id}"), RETEST_COMMAND),
+                        (
+                            format!("Mark mutant {id} as equivalent"),
+                            MARK_EQUIVALENT_COMMAND,
+                        ),
+                    ] {
+                        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                            title: title.clone(),
+                            kind: Some(CodeActionKind::QUICKFIX),
+                            diagnostics: Some(vec![diagnostic.clone()]),
+                            command: Some(Command {
+                                title,
+                                command: command.to_string(),
+                                arguments: Some(vec![json!(id)]),
+                            }),
+                            ..Default::default()
+                        }));
+                    }
+                }
+                to_value(actions)
+            }
+            ExecuteCommand::METHOD => {
+                let p: ExecuteCommandParams = parse_params(params)?;
+                let id = p
+                    .arguments
+                    .first()
+                    .and_then(Value::as_i64)
+                    .ok_or_else(|| LspError::new(INVALID_PARAMS, "expected a mutant id"))?;
+                if self.store.get_mutant(id).await.is_err() {
+                    return Err(LspError::new(
+                        INVALID_PARAMS,
+                        format!("unknown mutant id {id}"),
+                    ));
+                }
+                match p.command.as_str() {
+                    RETEST_COMMAND => {
+                        self.queue.push([id]);
+                    }
+                    MARK_EQUIVALENT_COMMAND => {
+                        self.store
+                            .add_outcome(Outcome {
+                                mutant_id: id,
+                                status: Status::Equivalent,
+                                output: "Marked as equivalent".to_string(),
+                                time: Utc::now(),
+                                duration_ms: 0,
+                            })
+                            .await
+                            .map_err(AppError::from)?;
+                        self.refresh().await;
+                    }
+                    command => {
+                        return Err(LspError::new(
+                            INVALID_PARAMS,
+                            format!("unknown command `{command}`"),
+                        ));
+                    }
+                }
+                Ok(Value::Null)
+            }
+            _ => Err(LspError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method `{method}`"),
+            )),
+        }
+    }
+
+    async fn handle_notification(&self, method: &str, params: Value) -> Result<(), LspError> {
+        match method {
+            "initialized" => self.refresh().await,
+            DidOpenTextDocument::METHOD => {
+                let p: <DidOpenTextDocument as Notification>::Params = parse_params(params)?;
+                let path = self.path_of(&p.text_document.uri);
+                self.documents
+                    .lock()
+                    .unwrap()
+                    .insert(path.clone(), p.text_document.text);
+                self.publish(&path);
+            }
+            DidChangeTextDocument::METHOD => {
+                let p: <DidChangeTextDocument as Notification>::Params = parse_params(params)?;
+                let path = self.path_of(&p.text_document.uri);
+                // Full sync: the last change holds the whole document
+                if let Some(change) = p.content_changes.into_iter().last() {
+                    self.documents
+                        .lock()
+                        .unwrap()
+                        .insert(path.clone(), change.text);
+                }
+                self.publish(&path);
+            }
+            DidCloseTextDocument::METHOD => {
+                let p: <DidCloseTextDocument as Notification>::Params = parse_params(params)?;
+                let path = self.path_of(&p.text_document.uri);
+                self.documents.lock().unwrap().remove(&path);
+                self.publish(&path);
+            }
+            _ => debug!("Ignoring notification {method}"),
+        }
+        Ok(())
+    }
+
+    /// Absolute path of a document, resolving symlinks where possible
+    fn path_of(&self, uri: &Url) -> PathBuf {
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from(uri.path()));
+        path.canonicalize().unwrap_or(path)
+    }
+
+    fn absolute(&self, path: &Path) -> PathBuf {
+        let path = self.root.join(path);
+        path.canonicalize().unwrap_or(path)
+    }
+
+    /// Surviving mutants of the stored version of `path` matching its current contents
+    fn current_survivors(&self, path: &Path) -> Vec<Survivor> {
+        let open = self.documents.lock().unwrap().get(path).cloned();
+        let Some(text) = open.or_else(|| std::fs::read_to_string(path).ok()) else {
+            return Vec::new();
+        };
+        self.survivors
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|survivors| {
+                survivors
+                    .iter()
+                    .filter(|s| s.target.text == text)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reload surviving mutants from the database and republish every file's diagnostics
+    async fn refresh(&self) {
+        let mut survivors: HashMap<PathBuf, Vec<Survivor>> = HashMap::new();
+        for status in [Status::Uncaught, Status::Timeout] {
+            let outcomes = match self
+                .store
+                .get_outcomes_filtered(None, Some(status.to_string()), None, None, None)
+                .await
+            {
+                Ok(outcomes) => outcomes,
+                Err(e) => {
+                    error!("Failed to load {status} mutants: {e}");
+                    return;
+                }
+            };
+            for (mutant, target, outcome) in outcomes {
+                survivors
+                    .entry(self.absolute(&target.path))
+                    .or_default()
+                    .push(Survivor {
+                        mutant,
+                        target,
+                        status: outcome.status,
+                    });
+            }
+        }
+        let paths: HashSet<PathBuf> = survivors
+            .keys()
+            .cloned()
+            .chain(self.published.lock().unwrap().iter().cloned())
+            .collect();
+        *self.survivors.lock().unwrap() = survivors;
+        for path in paths {
+            self.publish(&path);
+        }
+    }
+
+    /// Publish the diagnostics of one file, clearing them if it has none left
+    fn publish(&self, path: &Path) {
+        let diagnostics: Vec<Diagnostic> = self
+            .current_survivors(path)
+            .iter()
+            .filter_map(Survivor::diagnostic)
+            .collect();
+        {
+            let mut published = self.published.lock().unwrap();
+            if diagnostics.is_empty() {
+                if !published.remove(path) {
+                    return;
+                }
+            } else {
+                published.insert(path.to_path_buf());
+            }
+        }
+        let Ok(uri) = Url::from_file_path(path) else {
+            return;
+        };
+        self.notify::<PublishDiagnostics>(PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Hash;
+
+    fn survivor(text: &str, old: &str, new: &str) -> Survivor {
+        let byte_offset = text.find(old).unwrap();
+        Survivor {
+            mutant: Mutant {
+                id: 7,
+                target_id: 1,
+                byte_offset: byte_offset as u32,
+                line_offset: text[..byte_offset].matches('\n').count() as u32,
+                old_text: old.to_string(),
+                new_text: new.to_string(),
+                mutation_slug: "AOS".to_string(),
+            },
+            target: Target {
+                id: 1,
+                path: PathBuf::from("src/lib.rs"),
+                file_hash: Hash::digest(text.to_string()),
+                text: text.to_string(),
+                language: "Rust".to_string(),
+            },
+            status: Status::Uncaught,
+        }
+    }
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let text = "let s = \"é😀\";\nx + y\n";
+        assert_eq!(position_at(text, 0), Some(Position::new(0, 0)));
+        assert_eq!(
+            position_at(text, text.find(';').unwrap()),
+            Some(Position::new(0, 13))
+        );
+        assert_eq!(
+            position_at(text, text.find('+').unwrap()),
+            Some(Position::new(1, 2))
+        );
+        // Not a char boundary
+        assert_eq!(position_at(text, 10), None);
+    }
+
+    #[test]
+    fn diagnostics_cover_the_replaced_text() {
+        let survivor = survivor("fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n", "+", "-");
+        let diagnostic = survivor.diagnostic().unwrap();
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(1, 6), Position::new(1, 7))
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diagnostic.data, Some(json!({ "mutant_id": 7 })));
+        assert_eq!(
+            survivor.hover_text(),
+            "**Uncaught** mutant 7 (`AOS`)\n\n```rust\n    a - b\n```"
+        );
+    }
+
+    #[test]
+    fn messages_are_content_length_framed() {
+        let input = "Content-Length: 17\r\nContent-Type: application/vscode-jsonrpc\r\n\r\n{\"method\":\"exit\"}";
+        let mut reader = input.as_bytes();
+        assert_eq!(
+            read_message(&mut reader).unwrap(),
+            Some(json!({ "method": "exit" }))
+        );
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn workspace_folders_take_precedence_over_the_root() {
+        let params = json!({
+            "rootUri": "file:///a",
+            "workspaceFolders": [{ "uri": "file:///b", "name": "b" }],
+        });
+        assert_eq!(workspace_root(&params), Some(PathBuf::from("/b")));
+        assert_eq!(
+            workspace_root(&json!({ "rootUri": "file:///a", "workspaceFolders": null })),
+            Some(PathBuf::from("/a"))
+        );
+        assert_eq!(
+            workspace_root(&json!({ "rootUri": null, "rootPath": "/c" })),
+            Some(PathBuf::from("/c"))
+        );
+        assert_eq!(workspace_root(&json!({ "rootUri": null })), None);
+    }
+
+    #[tokio::test]
+    async fn retesting_leaves_a_file_edited_since_generation_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("lib.rs");
+        let source = "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+        std::fs::write(&src, source).unwrap();
+        let db = dir.path().join("mewt.sqlite");
+        let store = SqlStore::new(format!("sqlite:{}?mode=rwc", db.display()))
+            .await
+            .unwrap();
+        let mut registry = LanguageRegistry::new();
+        registry.register(crate::languages::rust::engine::RustLanguageEngine::new());
+        let registry = Arc::new(registry);
+        crate::Campaign::builder(Config::default(), Arc::clone(&registry), store.clone())
+            .targets(vec![src.display().to_string()])
+            .test_cmd("true")
+            .test_timeout(30)
+            .build()
+            .run()
+            .await
+            .unwrap();
+
+        // Saved in the editor after the campaign, then a survivor is re-tested
+        let edited = source.replace("a + b", "b + a");
+        std::fs::write(&src, &edited).unwrap();
+        let queue = Arc::new(TestQueue::new(
+            store.clone(),
+            registry,
+            Config::default(),
+            Some("true".to_string()),
+            Some(30),
+            false,
+            Arc::new(|_: &CampaignEvent| {}),
+        ));
+        let server = LanguageServer {
+            store: store.clone(),
+            queue: Arc::clone(&queue),
+            root: dir.path().to_path_buf(),
+            survivors: Mutex::new(HashMap::new()),
+            documents: Mutex::new(HashMap::new()),
+            published: Mutex::new(HashSet::new()),
+            output: mpsc::unbounded_channel().0,
+        };
+        let worker = tokio::spawn(Arc::clone(&queue).work());
+        let target = store.get_all_targets().await.unwrap().remove(0);
+        let id = store.get_mutants(target.id).await.unwrap()[0].id;
+        server
+            .handle_request(
+                ExecuteCommand::METHOD,
+                json!({ "command": RETEST_COMMAND, "arguments": [id] }),
+            )
+            .await
+            .unwrap();
+        while queue.snapshot() != (None, vec![]) {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        queue.stop();
+        worker.await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&src).unwrap(), edited);
+    }
+}
@@ -1,20 +1,26 @@
 pub mod clean;
+pub mod config;
 pub mod init;
+pub mod lsp;
 pub mod mutate;
 pub mod print;
 pub mod purge;
 pub mod results;
 pub mod run;
+pub mod serve;
 pub mod status;
 pub mod test;
 
 // Re-export commands for easier access
 pub use clean::execute_clean;
+pub use config::execute_config_check;
 pub use init::execute_init;
+pub use lsp::execute_lsp;
 pub use mutate::execute_mutate;
 pub use print::execute_print;
 pub use purge::execute_purge;
 pub use results::execute_results;
 pub use run::execute_run;
+pub use serve::execute_serve;
 pub use status::execute_status;
 pub use test::execute_test;
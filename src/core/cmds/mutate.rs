@@ -4,11 +4,12 @@ use std::sync::Arc;
 use crate::LanguageRegistry;
 use crate::SqlStore;
 use crate::core::cli::MutateArgs;
-use crate::types::config::ResolvedTargets;
+use crate::types::config::{Config, ResolvedTargets};
 use crate::types::{AppResult, Target};
 
 pub async fn execute_mutate(
     _args: MutateArgs,
+    config: Config,
     store: SqlStore,
     registry: Arc<LanguageRegistry>,
     resolved_targets: ResolvedTargets,
@@ -19,44 +20,44 @@ pub async fn execute_mutate(
         resolved_targets.include
     );
 
-    let mutations_slice = mutations.as_deref();
-
     // Load targets from the resolved configuration
-    let targets =
-        Target::load_targets(&resolved_targets, &store, &registry, mutations_slice).await?;
+    let targets = Target::load_targets(&resolved_targets, &store, &registry).await?;
 
     let mut total_mutants = 0;
 
     // Generate and save mutants for each target
     let mut new_mutants = 0;
-    for target in targets.iter() {
-        let mutants_res = target.generate_mutants(&registry, mutations_slice);
-        if let Ok(mutants) = mutants_res {
-            info!(
-                "Generated {} mutants for {}",
-                mutants.len(),
-                target.display()
-            );
-            total_mutants += mutants.len();
+    let generated = {
+        // Parsing is CPU-bound; run it on the blocking pool
+        let (targets, registry) = (targets.clone(), Arc::clone(&registry));
+        tokio::task::spawn_blocking(move || {
+            Target::generate_all_mutants(&targets, &registry, mutations.as_deref(), &config)
+        })
+        .await
+        .map_err(std::io::Error::other)?
+    };
+    for (target, mutants_res) in targets.iter().zip(generated) {
+        match mutants_res {
+            Ok(mutants) => {
+                info!(
+                    "Generated {} mutants for {}",
+                    mutants.len(),
+                    target.display()
+                );
+                total_mutants += mutants.len();
 
-            for mutant in mutants {
-                let mut new_mutant = mutant.clone();
-                let id_res = store
-                    .add_mutant(mutant)
-                    .await
-                    .expect("failed to add mutant");
-                if let Some(id) = id_res {
-                    new_mutants += 1;
-                    new_mutant.id = id;
-                    info!("Saved mutant: {}", new_mutant.display(target));
+                let ids = store.add_mutants(&mutants).await?;
+                for (mut mutant, id_res) in mutants.into_iter().zip(ids) {
+                    if let Some(id) = id_res {
+                        new_mutants += 1;
+                        mutant.id = id;
+                        info!("Saved mutant: {}", mutant.display(target));
+                    }
                 }
             }
-        } else {
-            error!(
-                "Failed to generate mutants for {}: {}",
-                target.display(),
-                mutants_res.err().unwrap()
-            );
+            Err(e) => {
+                error!("Failed to generate mutants for {}: {}", target.display(), e);
+            }
         }
     }
 
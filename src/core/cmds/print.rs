@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::LanguageRegistry;
 use crate::SqlStore;
+use crate::types::config::Config;
 use crate::types::{AppError, AppResult};
 
 pub mod config;
@@ -29,7 +30,7 @@ pub enum PrintCommand {
     Targets(String),
     Mutant(i64),
     Mutants(MutantsFilters),
-    Config(String),
+    Config(Box<Config>, String),
 }
 
 pub async fn execute_print(
@@ -68,6 +69,6 @@ pub async fn execute_print(
                 ))
             }
         }
-        PrintCommand::Config(format) => config::execute(format).await,
+        PrintCommand::Config(cfg, format) => config::execute(&cfg, format).await,
     }
 }
@@ -1,10 +1,11 @@
 use log::info;
 
 use crate::types::AppResult;
-use crate::types::config::config;
+use crate::types::config::Config;
 
-pub async fn execute(format: String) -> AppResult<()> {
-    let effective_config = config().to_effective();
+pub async fn execute(config: &Config, format: String) -> AppResult<()> {
+    let effective_config = config.to_effective();
+    let from = |key: &str| format!("  ({})", effective_config.source(key));
 
     if format == "json" {
         println!("{}", serde_json::to_string_pretty(&effective_config)?);
@@ -13,15 +14,27 @@ pub async fn execute(format: String) -> AppResult<()> {
         info!("Effective Configuration:");
         info!("");
         info!("Global:");
-        info!("  db: {}", effective_config.db.as_ref().unwrap());
+        info!(
+            "  db: {}{}",
+            effective_config.db.as_ref().unwrap(),
+            from("db")
+        );
+        match &effective_config.active_profile {
+            Some(profile) => info!("  profile: {}", profile),
+            None => info!("  profile: (none)"),
+        }
 
         info!("");
         info!("Log:");
         if let Some(log) = &effective_config.log {
-            info!("  level: {}", log.level.as_ref().unwrap());
+            info!(
+                "  level: {}{}",
+                log.level.as_ref().unwrap(),
+                from("log.level")
+            );
             match log.color {
-                Some(true) => info!("  color: on"),
-                Some(false) => info!("  color: off"),
+                Some(true) => info!("  color: on{}", from("log.color")),
+                Some(false) => info!("  color: off{}", from("log.color")),
                 None => info!("  color: auto"),
             }
         }
@@ -30,24 +43,29 @@ pub async fn execute(format: String) -> AppResult<()> {
         info!("Targets:");
         if let Some(targets) = &effective_config.targets {
             if let Some(include) = &targets.include {
-                if include.is_empty() {
-                    info!("  include: []");
-                } else {
-                    info!("  include: [{}]", include.join(", "));
-                }
+                info!(
+                    "  include: [{}]{}",
+                    include.join(", "),
+                    from("targets.include")
+                );
             } else {
                 info!("  include: (not set)");
             }
 
             if let Some(ignore) = &targets.ignore {
-                if ignore.is_empty() {
-                    info!("  ignore: []");
-                } else {
-                    info!("  ignore: [{}]", ignore.join(", "));
-                }
+                info!(
+                    "  ignore: [{}]{}",
+                    ignore.join(", "),
+                    from("targets.ignore")
+                );
             } else {
                 info!("  ignore: (not set)");
             }
+            info!(
+                "  respect_ignore_files: {}{}",
+                targets.respect_ignore_files(),
+                from("targets.respect_ignore_files")
+            );
         } else {
             info!("  (not configured)");
         }
@@ -56,12 +74,35 @@ pub async fn execute(format: String) -> AppResult<()> {
         info!("Run:");
         if let Some(run) = &effective_config.run {
             if let Some(mutations) = &run.mutations {
-                info!("  mutations: [{}]", mutations.join(", "));
+                info!(
+                    "  mutations: [{}]{}",
+                    mutations.join(", "),
+                    from("run.mutations")
+                );
             } else {
                 info!("  mutations: all enabled");
             }
             if let Some(comprehensive) = run.comprehensive {
-                info!("  comprehensive: {}", comprehensive);
+                info!(
+                    "  comprehensive: {}{}",
+                    comprehensive,
+                    from("run.comprehensive")
+                );
+            }
+            if !run.per_target().is_empty() {
+                info!("  per_target:{}", from("run.per_target"));
+                for rule in run.per_target() {
+                    info!("    - glob: {}", rule.glob);
+                    if let Some(mutations) = &rule.mutations {
+                        info!("      mutations: [{}]", mutations.join(", "));
+                    }
+                    if let Some(excluded) = &rule.exclude_mutations {
+                        info!("      exclude_mutations: [{}]", excluded.join(", "));
+                    }
+                    for (slug, severity) in rule.severity.iter().flatten() {
+                        info!("      severity: {} = {}", slug, severity);
+                    }
+                }
             }
         } else {
             info!("  mutations: all enabled");
@@ -71,20 +112,20 @@ pub async fn execute(format: String) -> AppResult<()> {
         info!("Test:");
         if let Some(test) = &effective_config.test {
             if let Some(cmd) = &test.cmd {
-                info!("  cmd: {}", cmd);
+                info!("  cmd: {}{}", cmd, from("test.cmd"));
             } else {
                 info!("  cmd: (not set)");
             }
 
             if let Some(timeout) = test.timeout {
-                info!("  timeout: {}s", timeout);
+                info!("  timeout: {}s{}", timeout, from("test.timeout"));
             } else {
                 info!("  timeout: (not set)");
             }
 
             if let Some(per_target) = &test.per_target {
                 if !per_target.is_empty() {
-                    info!("  per_target:");
+                    info!("  per_target:{}", from("test.per_target"));
                     for rule in per_target {
                         info!("    - glob: {}", rule.glob);
                         if let Some(cmd) = &rule.cmd {
@@ -111,7 +111,7 @@ impl OutcomeCounter {
         }
     }
     fn record(&mut self, status: &Status) {
-        if *status != Status::Skipped {
+        if *status != Status::Skipped && *status != Status::Equivalent {
             self.eligible += 1;
             if *status == Status::TestFail {
                 self.caught += 1;
@@ -135,7 +135,7 @@ fn normalize_status(status_str: Option<String>) -> Option<String> {
 // Print outcome details and verbose information if requested
 fn print_outcome(mutant: &Mutant, target: &Target, outcome: &Outcome, verbose: bool) {
     info!(
-        "  {:<9} | {}",
+        "  {:<10} | {}",
         &outcome.status.display(),
         mutant.display(target)
     );
@@ -321,12 +321,9 @@ async fn print_table_format(
     registry: &LanguageRegistry,
 ) -> AppResult<()> {
     // If mutant_id is provided, special handling
-    if filters.id.is_some() {
+    if let Some(id) = filters.id {
         if data.is_empty() {
-            info!(
-                "No outcome found for mutant with ID: {}",
-                filters.id.unwrap()
-            );
+            info!("No outcome found for mutant with ID: {}", id);
         } else {
             let (mutant, target, outcome) = &data[0];
             info!("Target: {}", target.display());
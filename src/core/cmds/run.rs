@@ -1,108 +1,49 @@
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-
-use log::{info, warn};
-use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
 
 use crate::LanguageRegistry;
 use crate::SqlStore;
+use crate::core::campaign::{Campaign, CampaignResult};
 use crate::core::cli::RunArgs;
-use crate::core::runner::TestRunner;
-use crate::types::config::{ResolvedTargets, config, resolve_test_for_path};
-use crate::types::{AppResult, CampaignSummary, Target};
+use crate::core::events::jsonl_handler;
+use crate::core::logging::ActiveBar;
+use crate::types::AppResult;
+use crate::types::config::{Config, ResolvedTargets};
 
-#[allow(clippy::too_many_arguments)]
+/// Run a campaign with `config`, overridden by the command-line arguments
 pub async fn execute_run(
     args: RunArgs,
+    config: Config,
     store: SqlStore,
     running: Arc<AtomicBool>,
     registry: Arc<LanguageRegistry>,
     resolved_targets: Option<ResolvedTargets>,
-    mutations: Option<Vec<String>>,
-    test_cmd: Option<String>,
-    test_timeout: Option<u32>,
-) -> AppResult<Option<CampaignSummary>> {
-    let mutations_slice = mutations.as_deref();
-
-    let targets = if let Some(resolved) = resolved_targets {
-        // Generate new mutants for the specified targets
-        let targets = Target::load_targets(&resolved, &store, &registry, mutations_slice).await?;
-        for target in targets.iter() {
-            let mutants_res = target.generate_mutants(&registry, mutations_slice);
-            if let Ok(mutants) = mutants_res {
-                for mut mutant in mutants {
-                    let new_id = store
-                        .add_mutant(mutant.clone())
-                        .await
-                        .expect("failed to add mutant");
-                    if let Some(id) = new_id {
-                        mutant.id = id;
-                        info!("  Saved new mutant: {}", mutant.display(target));
-                    }
-                }
-            }
-        }
-        targets
-    } else {
-        // Skip mutation generation, get targets for existing mutants to test (no outcomes + timeouts)
-        let (mutants_to_test, _, _) = store.get_mutants_to_test().await?;
-        if mutants_to_test.is_empty() {
-            info!("No mutants to test found in database");
-            return Ok(None);
-        }
-
-        // Get unique targets for these mutants
-        let mut target_ids: Vec<i64> = mutants_to_test.iter().map(|m| m.target_id).collect();
-        target_ids.sort_unstable();
-        target_ids.dedup();
-
-        let mut targets = Vec::new();
-        for target_id in target_ids {
-            targets.push(store.get_target(target_id).await?);
-        }
-        targets
-    };
-
-    // Group targets by resolved (test_cmd, timeout)
-    let mut groups: HashMap<(String, Option<u32>), Vec<Target>> = HashMap::new();
-    for target in targets.into_iter() {
-        let (maybe_cmd, timeout) =
-            resolve_test_for_path(&target.path, test_cmd.as_deref(), test_timeout);
-        if let Some(cmd) = maybe_cmd {
-            groups.entry((cmd, timeout)).or_default().push(target);
-        } else {
-            warn!("No test command provided for target {}", target.display());
-        }
+    progress: ActiveBar,
+) -> AppResult<CampaignResult> {
+    let mutations = config.resolve_mutations(args.mutations.as_deref());
+    let mut campaign = Campaign::builder(config, registry, store)
+        .running(running)
+        .verbose(args.verbose)
+        .progress(progress);
+    if let Some(targets) = resolved_targets {
+        campaign = campaign.resolved_targets(targets);
     }
-
-    // For each group, create a runner (baseline once per unique cmd) and run campaign
-    for ((cmd, timeout), group_targets) in groups.into_iter() {
-        if !running.load(Ordering::SeqCst) {
-            warn!("Mutation campaign cancelled before execution");
-            break;
-        }
-
-        let mut runner = match TestRunner::new_with_baseline(
-            cmd,
-            timeout.or(config().test().timeout()),
-            Arc::clone(&running),
-            store.clone(),
-            args.comprehensive,
-            args.verbose,
-            Arc::clone(&registry),
-        )
-        .await
-        {
-            Ok(runner) => runner,
-            Err(e) => return Err(e.into()),
-        };
-
-        runner
-            .run_mutation_campaign(group_targets, mutations_slice.map(|v| v.join(",")))
-            .await?;
+    if let Some(mutations) = mutations {
+        campaign = campaign.mutations(mutations);
+    }
+    if let Some(cmd) = args.test_cmd {
+        campaign = campaign.test_cmd(cmd);
+    }
+    if let Some(timeout) = args.test_timeout {
+        campaign = campaign.test_timeout(timeout);
+    }
+    if args.comprehensive {
+        campaign = campaign.comprehensive(true);
+    }
+    if let Some(output) = &args.events {
+        let handler = jsonl_handler(output)?;
+        campaign = campaign.on_event(move |event| handler(event));
     }
 
-    // Query DB once at the end for final counts
-    let final_summary = store.get_campaign_summary().await?;
-    Ok(Some(final_summary))
+    campaign.build().run().await
 }
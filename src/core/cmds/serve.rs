@@ -0,0 +1,687 @@
+//! `serve`: a daemon answering JSON-RPC 2.0 requests over a Unix socket or a localhost TCP
+//! port, so tools can query results and queue mutants without re-spawning the CLI.
+//!
+//! Messages are newline-delimited JSON. Queued mutants are tested one at a time by a single
+//! worker; its progress is sent to every client as `event` notifications carrying the
+//! same objects as `run --events jsonl`.
+//!
+//! Only the owner can connect to the Unix socket. Any local user can reach a TCP port, so
+//! TCP clients must first send an `auth` request carrying the server's token.
+
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use log::{error, info, warn};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+
+use crate::LanguageRegistry;
+use crate::SqlStore;
+use crate::core::campaign::CampaignEvent;
+use crate::core::cli::ServeArgs;
+use crate::core::cmds::status::generate_status_report;
+use crate::core::queue::TestQueue;
+use crate::types::config::Config;
+use crate::types::{AppError, AppResult, Mutant, Outcome, Target};
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+// Server error for requests on a TCP connection that hasn't authenticated
+const UNAUTHORIZED: i64 = -32001;
+
+/// Address given to `--socket`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Endpoint {
+    Unix(PathBuf),
+    Tcp(SocketAddr),
+}
+
+impl Endpoint {
+    /// TCP addresses must be loopback; anything that isn't an address is a socket path
+    fn parse(socket: &str) -> io::Result<Self> {
+        let addr = match socket.strip_prefix("localhost:") {
+            Some(port) => port
+                .parse::<u16>()
+                .ok()
+                .map(|port| SocketAddr::from(([127, 0, 0, 1], port))),
+            None => socket.parse::<SocketAddr>().ok(),
+        };
+        match addr {
+            Some(addr) if addr.ip().is_loopback() => Ok(Endpoint::Tcp(addr)),
+            Some(addr) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Refusing to listen on non-loopback address {addr}"),
+            )),
+            None => Ok(Endpoint::Unix(PathBuf::from(socket))),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<AppError> for RpcError {
+    fn from(e: AppError) -> Self {
+        RpcError::new(INTERNAL_ERROR, e.to_string())
+    }
+}
+
+type RpcResult = Result<Value, RpcError>;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct MutantsParams {
+    target: Option<String>,
+    line: Option<u32>,
+    mutation_type: Option<String>,
+    tested: bool,
+    untested: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct OutcomesParams {
+    target: Option<String>,
+    status: Option<String>,
+    language: Option<String>,
+    mutation_type: Option<String>,
+    line: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MutantParams {
+    mutant_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TestParams {
+    ids: Vec<i64>,
+}
+
+struct Server {
+    store: SqlStore,
+    registry: Arc<LanguageRegistry>,
+    queue: Arc<TestQueue>,
+    events: broadcast::Sender<CampaignEvent>,
+    /// Required from TCP clients; `None` for Unix sockets
+    token: Option<String>,
+}
+
+pub async fn execute_serve(
+    args: ServeArgs,
+    config: Config,
+    store: SqlStore,
+    running: Arc<AtomicBool>,
+    registry: Arc<LanguageRegistry>,
+) -> AppResult<()> {
+    let endpoint = Endpoint::parse(&args.socket)?;
+    let token = match (&endpoint, args.token) {
+        (Endpoint::Tcp(_), Some(token)) => Some(token),
+        (Endpoint::Tcp(_), None) => {
+            let token = generate_token()?;
+            // Straight to stderr, never through the logger, so the secret stays out of log files
+            eprintln!("Clients must authenticate with token {token}");
+            Some(token)
+        }
+        (Endpoint::Unix(_), token) => {
+            if token.is_some() {
+                warn!("--token only applies to TCP; ignoring it for the Unix socket");
+            }
+            None
+        }
+    };
+    let (events, _) = broadcast::channel(1024);
+    let sender = events.clone();
+    let queue = Arc::new(TestQueue::new(
+        store.clone(),
+        Arc::clone(&registry),
+        config,
+        args.test_cmd,
+        args.test_timeout,
+        args.verbose,
+        Arc::new(move |event: &CampaignEvent| {
+            let _ = sender.send(event.clone());
+        }),
+    ));
+    let server = Arc::new(Server {
+        store,
+        registry,
+        queue: Arc::clone(&queue),
+        events,
+        token,
+    });
+
+    let worker = tokio::spawn(queue.work());
+    let served = match &endpoint {
+        Endpoint::Tcp(addr) => serve_tcp(*addr, &server, &running).await,
+        Endpoint::Unix(path) => serve_unix(path, &server, &running).await,
+    };
+
+    info!("Shutting down server...");
+    server.queue.stop();
+    if let Err(e) = worker.await {
+        error!("Test worker failed: {e}");
+    }
+    served?;
+    Ok(())
+}
+
+/// Accept connections until `running` is cleared (by Ctrl-C)
+async fn serve_tcp(addr: SocketAddr, server: &Arc<Server>, running: &AtomicBool) -> io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("Listening on {}", listener.local_addr()?);
+    while running.load(Ordering::SeqCst) {
+        if let Ok(accepted) =
+            tokio::time::timeout(Duration::from_millis(200), listener.accept()).await
+        {
+            match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(Arc::clone(server).handle_connection(stream));
+                }
+                Err(e) => warn!("Failed to accept connection: {e}"),
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn serve_unix(
+    path: &std::path::Path,
+    server: &Arc<Server>,
+    running: &AtomicBool,
+) -> io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+    use tokio::net::{UnixListener, UnixStream};
+
+    // A socket file left behind by a server that died can be replaced; a live one or any
+    // other kind of file can't
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            ));
+        }
+        if UnixStream::connect(path).await.is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("Another server is listening on {}", path.display()),
+            ));
+        }
+        std::fs::remove_file(path)?;
+    }
+    // Only the owner may drive the server. The socket is bound in a private directory and
+    // moved into place once restricted, so it's never reachable with looser permissions.
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(std::path::Path::new("."));
+    let private = parent.join(format!(".mewt-serve-{}", std::process::id()));
+    std::fs::DirBuilder::new().mode(0o700).create(&private)?;
+    let staged = private.join("socket");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&staged);
+    let _ = std::fs::remove_dir(&private);
+    let listener = bound?;
+    info!("Listening on {}", path.display());
+
+    while running.load(Ordering::SeqCst) {
+        if let Ok(accepted) =
+            tokio::time::timeout(Duration::from_millis(200), listener.accept()).await
+        {
+            match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(Arc::clone(server).handle_connection(stream));
+                }
+                Err(e) => warn!("Failed to accept connection: {e}"),
+            }
+        }
+    }
+    let _ = std::fs::remove_file(path);
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn serve_unix(
+    path: &std::path::Path,
+    _server: &Arc<Server>,
+    _running: &AtomicBool,
+) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "Unix sockets are not supported on this platform; use a localhost address instead of {}",
+            path.display()
+        ),
+    ))
+}
+
+/// 128 random bits, hex-encoded
+fn generate_token() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)?;
+    Ok(hex::encode(bytes))
+}
+
+/// Compare in constant time so response timing doesn't leak the token
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn to_value(value: impl serde::Serialize) -> RpcResult {
+    serde_json::to_value(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+/// A mutant with the path of its target
+fn mutant_entry(mutant: &Mutant, target: &Target) -> Value {
+    let mut entry = json!(mutant);
+    entry["target"] = json!(target.path);
+    entry["line"] = json!(mutant.get_lines().0);
+    entry
+}
+
+impl Server {
+    async fn handle_connection<S: AsyncRead + AsyncWrite + Send + 'static>(
+        self: Arc<Self>,
+        stream: S,
+    ) {
+        let (reader, mut writer) = tokio::io::split(stream);
+        let mut lines = BufReader::new(reader).lines();
+        let mut events = self.events.subscribe();
+        let mut authorized = self.token.is_none();
+        loop {
+            // A failed `auth` is answered and then the connection is closed
+            let mut close = false;
+            let message = tokio::select! {
+                line = lines.next_line() => match line {
+                    Ok(Some(line)) if !authorized => match self.authenticate(&line) {
+                        Ok(response) => {
+                            authorized = true;
+                            response
+                        }
+                        Err(response) => {
+                            close = true;
+                            response
+                        }
+                    },
+                    Ok(Some(line)) => match self.handle_line(&line).await {
+                        Some(response) => response,
+                        None => continue,
+                    },
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!("Failed to read request: {e}");
+                        break;
+                    }
+                },
+                event = events.recv(), if authorized => match event {
+                    Ok(event) => json!({ "jsonrpc": "2.0", "method": "event", "params": event }),
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        warn!("Client fell behind; dropped {missed} events");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+            };
+            let mut bytes = message.to_string().into_bytes();
+            bytes.push(b'\n');
+            if writer.write_all(&bytes).await.is_err() || close {
+                break;
+            }
+        }
+    }
+
+    /// Accept an `auth` request carrying the server's token; anything else is rejected
+    fn authenticate(&self, line: &str) -> Result<Value, Value> {
+        let request: Value = serde_json::from_str(line).unwrap_or(Value::Null);
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let given = match request.get("method").and_then(Value::as_str) {
+            Some("auth") => request.pointer("/params/token").and_then(Value::as_str),
+            _ => None,
+        };
+        match (given, &self.token) {
+            (Some(given), Some(token)) if tokens_match(given, token) => {
+                Ok(json!({ "jsonrpc": "2.0", "id": id, "result": true }))
+            }
+            _ => Err(error_response(
+                id,
+                UNAUTHORIZED,
+                "expected an `auth` request with the server's token".to_string(),
+            )),
+        }
+    }
+
+    /// Answer one line holding a request, a notification or a batch of them
+    async fn handle_line(&self, line: &str) -> Option<Value> {
+        if line.trim().is_empty() {
+            return None;
+        }
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => return Some(error_response(Value::Null, PARSE_ERROR, e.to_string())),
+        };
+        match message {
+            Value::Array(batch) if batch.is_empty() => Some(error_response(
+                Value::Null,
+                INVALID_REQUEST,
+                "empty batch".to_string(),
+            )),
+            Value::Array(batch) => {
+                let mut responses = Vec::new();
+                for request in batch {
+                    responses.extend(self.handle_request(request).await);
+                }
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_request(request).await,
+        }
+    }
+
+    /// Answer a request; notifications (requests without an id) get no response
+    async fn handle_request(&self, request: Value) -> Option<Value> {
+        let id = request.get("id").cloned();
+        let method = request.get("method").and_then(Value::as_str);
+        let (Some(method), true) = (method, request.get("jsonrpc") == Some(&json!("2.0"))) else {
+            return Some(error_response(
+                id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "expected a JSON-RPC 2.0 request".to_string(),
+            ));
+        };
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        let params = if params.is_null() { json!({}) } else { params };
+
+        let result = self.call(method, params).await;
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => error_response(id, e.code, e.message),
+        })
+    }
+
+    async fn call(&self, method: &str, params: Value) -> RpcResult {
+        match method {
+            "targets.list" => to_value(self.store.get_all_targets().await.map_err(AppError::from)?),
+            "mutants.list" => {
+                let p: MutantsParams = parse_params(params)?;
+                let mutants = self
+                    .store
+                    .get_mutants_filtered(p.target, p.line, p.mutation_type, p.tested, p.untested)
+                    .await
+                    .map_err(AppError::from)?;
+                Ok(Value::Array(
+                    mutants.iter().map(|(m, t)| mutant_entry(m, t)).collect(),
+                ))
+            }
+            "outcomes.list" => {
+                let p: OutcomesParams = parse_params(params)?;
+                let outcomes = self
+                    .store
+                    .get_outcomes_filtered(p.target, p.status, p.language, p.mutation_type, p.line)
+                    .await
+                    .map_err(AppError::from)?;
+                Ok(Value::Array(
+                    outcomes
+                        .iter()
+                        .map(|(m, t, o)| json!({ "mutant": mutant_entry(m, t), "outcome": o }))
+                        .collect(),
+                ))
+            }
+            "outcomes.get" => {
+                let p: MutantParams = parse_params(params)?;
+                let outcome: Option<Outcome> = self
+                    .store
+                    .get_outcome(p.mutant_id)
+                    .await
+                    .map_err(AppError::from)?;
+                to_value(outcome)
+            }
+            "status" => to_value(generate_status_report(&self.store, &self.registry).await?),
+            "mutants.test" => {
+                let p: TestParams = parse_params(params)?;
+                for id in &p.ids {
+                    if self.store.get_mutant(*id).await.is_err() {
+                        return Err(RpcError::new(
+                            INVALID_PARAMS,
+                            format!("unknown mutant id {id}"),
+                        ));
+                    }
+                }
+                let queued = self.queue.push(p.ids);
+                let (_, pending) = self.queue.snapshot();
+                Ok(json!({ "queued": queued, "pending": pending.len() }))
+            }
+            "queue.list" => {
+                let (active, pending) = self.queue.snapshot();
+                Ok(json!({ "active": active, "pending": pending }))
+            }
+            "run.cancel" => {
+                let (cancelled, interrupted) = self.queue.cancel();
+                Ok(json!({ "cancelled": cancelled, "interrupted": interrupted }))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method `{method}`"),
+            )),
+        }
+    }
+}
+
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn server() -> (tempfile::TempDir, Server) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("mewt.sqlite");
+        let store = SqlStore::new(format!("sqlite:{}?mode=rwc", db.display()))
+            .await
+            .unwrap();
+        let registry = Arc::new(LanguageRegistry::new());
+        let queue = TestQueue::new(
+            store.clone(),
+            Arc::clone(&registry),
+            Config::default(),
+            None,
+            None,
+            false,
+            Arc::new(|_: &CampaignEvent| {}),
+        );
+        let server = Server {
+            store,
+            registry,
+            queue: Arc::new(queue),
+            events: broadcast::channel(16).0,
+            token: None,
+        };
+        (dir, server)
+    }
+
+    #[test]
+    fn endpoints_are_unix_paths_or_loopback_addresses() {
+        assert_eq!(
+            Endpoint::parse("/tmp/mewt.sock").unwrap(),
+            Endpoint::Unix(PathBuf::from("/tmp/mewt.sock"))
+        );
+        assert_eq!(
+            Endpoint::parse("localhost:7070").unwrap(),
+            Endpoint::Tcp("127.0.0.1:7070".parse().unwrap())
+        );
+        assert_eq!(
+            Endpoint::parse("[::1]:7070").unwrap(),
+            Endpoint::Tcp("[::1]:7070".parse().unwrap())
+        );
+        assert!(Endpoint::parse("0.0.0.0:7070").is_err());
+    }
+
+    #[tokio::test]
+    async fn requests_follow_json_rpc() {
+        let (_dir, server) = server().await;
+
+        let response = server
+            .handle_line(r#"{"jsonrpc":"2.0","id":1,"method":"targets.list"}"#)
+            .await
+            .unwrap();
+        assert_eq!(response, json!({ "jsonrpc": "2.0", "id": 1, "result": [] }));
+
+        let response = server.handle_line("{not json").await.unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+
+        let response = server
+            .handle_line(r#"{"jsonrpc":"2.0","id":"a","method":"mutants.delete"}"#)
+            .await
+            .unwrap();
+        assert_eq!(response["id"], "a");
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let response = server
+            .handle_line(
+                r#"{"jsonrpc":"2.0","id":2,"method":"mutants.test","params":{"ids":[42]}}"#,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        // Notifications get no response, batches get one per request
+        assert_eq!(
+            server
+                .handle_line(r#"{"jsonrpc":"2.0","method":"run.cancel"}"#)
+                .await,
+            None
+        );
+        let response = server
+            .handle_line(
+                r#"[{"jsonrpc":"2.0","id":3,"method":"queue.list"},
+                    {"jsonrpc":"2.0","method":"queue.list"}]"#,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            json!([{ "jsonrpc": "2.0", "id": 3, "result": { "active": null, "pending": [] } }])
+        );
+    }
+
+    /// Send `line` and read one response line
+    async fn exchange(
+        lines: &mut tokio::io::Lines<BufReader<tokio::io::ReadHalf<tokio::io::DuplexStream>>>,
+        writer: &mut tokio::io::WriteHalf<tokio::io::DuplexStream>,
+        line: &str,
+    ) -> Option<Value> {
+        writer
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap();
+        let response = lines.next_line().await.unwrap()?;
+        Some(serde_json::from_str(&response).unwrap())
+    }
+
+    #[tokio::test]
+    async fn tcp_clients_authenticate_first() {
+        let (_dir, mut server) = server().await;
+        server.token = Some("secret".to_string());
+        let server = Arc::new(server);
+
+        // A request before `auth` is refused and the connection closed
+        let (client, connection) = tokio::io::duplex(4096);
+        tokio::spawn(Arc::clone(&server).handle_connection(connection));
+        let (reader, mut writer) = tokio::io::split(client);
+        let mut lines = BufReader::new(reader).lines();
+        let list = r#"{"jsonrpc":"2.0","id":1,"method":"targets.list"}"#;
+        let response = exchange(&mut lines, &mut writer, list).await.unwrap();
+        assert_eq!(response["error"]["code"], UNAUTHORIZED);
+        assert_eq!(lines.next_line().await.unwrap(), None);
+
+        // So is a wrong token
+        let (client, connection) = tokio::io::duplex(4096);
+        tokio::spawn(Arc::clone(&server).handle_connection(connection));
+        let (reader, mut writer) = tokio::io::split(client);
+        let mut lines = BufReader::new(reader).lines();
+        let auth = r#"{"jsonrpc":"2.0","id":0,"method":"auth","params":{"token":"guess"}}"#;
+        let response = exchange(&mut lines, &mut writer, auth).await.unwrap();
+        assert_eq!(response["error"]["code"], UNAUTHORIZED);
+
+        let (client, connection) = tokio::io::duplex(4096);
+        tokio::spawn(Arc::clone(&server).handle_connection(connection));
+        let (reader, mut writer) = tokio::io::split(client);
+        let mut lines = BufReader::new(reader).lines();
+        let auth = r#"{"jsonrpc":"2.0","id":0,"method":"auth","params":{"token":"secret"}}"#;
+        let response = exchange(&mut lines, &mut writer, auth).await.unwrap();
+        assert_eq!(response["result"], true);
+        let response = exchange(&mut lines, &mut writer, list).await.unwrap();
+        assert_eq!(response["result"], json!([]));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_sockets_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (dir, server) = server().await;
+        let path = dir.path().join("mewt.sock");
+        let running = Arc::new(AtomicBool::new(true));
+        let serving = {
+            let (path, running) = (path.clone(), Arc::clone(&running));
+            tokio::spawn(async move { serve_unix(&path, &Arc::new(server), &running).await })
+        };
+        while !path.exists() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        // The staging directory is gone
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+
+        running.store(false, Ordering::SeqCst);
+        serving.await.unwrap().unwrap();
+        assert!(!path.exists());
+    }
+}
@@ -18,6 +18,7 @@ struct TargetStats {
     uncaught: usize,
     timeout: usize,
     skipped: usize,
+    equivalent: usize,
     high_catch_rate: Option<f64>,
     medium_catch_rate: Option<f64>,
     low_catch_rate: Option<f64>,
@@ -33,6 +34,7 @@ struct CampaignStats {
     uncaught: usize,
     timeout: usize,
     skipped: usize,
+    equivalent: usize,
     high_catch_rate: Option<f64>,
     medium_catch_rate: Option<f64>,
     low_catch_rate: Option<f64>,
@@ -40,7 +42,7 @@ struct CampaignStats {
 }
 
 #[derive(Debug, Serialize)]
-struct StatusReport {
+pub(crate) struct StatusReport {
     targets: Vec<TargetStats>,
     campaign: CampaignStats,
 }
@@ -65,7 +67,7 @@ pub async fn execute_status(
     Ok(())
 }
 
-async fn generate_status_report(
+pub(crate) async fn generate_status_report(
     store: &SqlStore,
     registry: &LanguageRegistry,
 ) -> AppResult<StatusReport> {
@@ -83,6 +85,7 @@ async fn generate_status_report(
                 uncaught: 0,
                 timeout: 0,
                 skipped: 0,
+                equivalent: 0,
                 high_catch_rate: None,
                 medium_catch_rate: None,
                 low_catch_rate: None,
@@ -101,6 +104,7 @@ async fn generate_status_report(
         uncaught: 0,
         timeout: 0,
         skipped: 0,
+        equivalent: 0,
         high_catch_rate: None,
         medium_catch_rate: None,
         low_catch_rate: None,
@@ -122,6 +126,7 @@ async fn generate_status_report(
         campaign_totals.uncaught += stats.uncaught;
         campaign_totals.timeout += stats.timeout;
         campaign_totals.skipped += stats.skipped;
+        campaign_totals.equivalent += stats.equivalent;
 
         target_stats.push(TargetStats {
             path: target.path.to_string_lossy().to_string(),
@@ -132,6 +137,7 @@ async fn generate_status_report(
             uncaught: stats.uncaught,
             timeout: stats.timeout,
             skipped: stats.skipped,
+            equivalent: stats.equivalent,
             high_catch_rate: high_rate,
             medium_catch_rate: medium_rate,
             low_catch_rate: low_rate,
@@ -184,8 +190,9 @@ async fn generate_status_report(
     campaign_totals.medium_catch_rate = medium_rate;
     campaign_totals.low_catch_rate = low_rate;
 
-    // Calculate progress (exclude skipped mutants from denominator)
-    let testable_mutants = campaign_totals.total_mutants - campaign_totals.skipped;
+    // Calculate progress (exclude skipped and equivalent mutants from denominator)
+    let testable_mutants =
+        campaign_totals.total_mutants - campaign_totals.skipped - campaign_totals.equivalent;
     if testable_mutants > 0 {
         campaign_totals.progress_percent =
             (campaign_totals.tested as f64 / testable_mutants as f64) * 100.0;
@@ -255,8 +262,8 @@ fn print_table_format(report: &StatusReport) {
             target.total_mutants, target.tested, target.untested
         );
         info!(
-            "  Outcomes: {} caught, {} uncaught, {} timeout, {} skipped",
-            target.caught, target.uncaught, target.timeout, target.skipped
+            "  Outcomes: {} caught, {} uncaught, {} timeout, {} skipped, {} equivalent",
+            target.caught, target.uncaught, target.timeout, target.skipped, target.equivalent
         );
 
         // Catch rates by severity
@@ -279,8 +286,8 @@ fn print_table_format(report: &StatusReport) {
         c.total_mutants, c.tested, c.progress_percent, c.untested
     );
     info!(
-        "Outcomes: {} caught, {} uncaught, {} timeout, {} skipped",
-        c.caught, c.uncaught, c.timeout, c.skipped
+        "Outcomes: {} caught, {} uncaught, {} timeout, {} skipped, {} equivalent",
+        c.caught, c.uncaught, c.timeout, c.skipped, c.equivalent
     );
 
     let high_rate = format_rate(c.high_catch_rate);
@@ -10,7 +10,7 @@ use crate::SqlStore;
 use crate::core::cli::TestArgs;
 use crate::core::runner::TestRunner;
 use crate::types::AppResult;
-use crate::types::config::{config, resolve_test_for_path};
+use crate::types::config::Config;
 
 /// Read mutant IDs from --ids-file (file or stdin) or --ids (CLI arg).
 /// --ids-file takes precedence over --ids.
@@ -54,6 +54,7 @@ fn read_mutant_ids(args: &TestArgs) -> io::Result<Vec<i64>> {
 
 pub async fn execute_test(
     args: TestArgs,
+    config: Config,
     store: SqlStore,
     running: Arc<AtomicBool>,
     registry: Arc<LanguageRegistry>,
@@ -77,8 +78,11 @@ pub async fn execute_test(
         match store.get_mutant(id).await {
             Ok(mutant) => match store.get_target(mutant.target_id).await {
                 Ok(target) => {
-                    let (maybe_cmd, timeout) =
-                        resolve_test_for_path(&target.path, test_cmd.as_deref(), test_timeout);
+                    let (maybe_cmd, timeout) = config.resolve_test_for_path(
+                        &target.path,
+                        test_cmd.as_deref(),
+                        test_timeout,
+                    );
                     if let Some(cmd) = maybe_cmd {
                         groups.entry((cmd, timeout)).or_default().push(id);
                     } else {
@@ -100,12 +104,14 @@ pub async fn execute_test(
 
         let mut runner = match TestRunner::new_with_baseline(
             cmd,
-            timeout.or(config().test().timeout()),
+            timeout,
             Arc::clone(&running),
             store.clone(),
             false, // No need for comprehensive mode during targeted re-tests
             args.verbose,
             Arc::clone(&registry),
+            config.clone(),
+            None,
         )
         .await
         {
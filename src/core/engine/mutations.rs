@@ -11,6 +11,11 @@ pub const COMMON_MUTATIONS: &[Mutation] = &[
         description: "Comment Replacement: Replace statement with an in-line comment",
         severity: MutationSeverity::Medium,
     },
+    Mutation {
+        slug: "RV",
+        description: "Return Value Replacement: Replace a returned value with a default for its type",
+        severity: MutationSeverity::Medium,
+    },
     Mutation {
         slug: "IF",
         description: "If False: Hardcode an if condition to false",
@@ -41,6 +46,38 @@ pub const COMMON_MUTATIONS: &[Mutation] = &[
         description: "Boolean Literal Flip: true <-> false",
         severity: MutationSeverity::Low,
     },
+    // Literal value mutations
+    Mutation {
+        slug: "NLR",
+        description: "Numeric Literal Replacement: Replace an integer n with 0, 1, n+1 or n-1",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "SLR",
+        description: "String Literal Replacement: Replace a string literal with an empty string",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "HLZ",
+        description: "Hex Literal Zeroing: Replace every digit of a hex or address literal with 0",
+        severity: MutationSeverity::Low,
+    },
+    // Unary operator mutations
+    Mutation {
+        slug: "UOD",
+        description: "Unary Operator Deletion: Remove a unary !, -, ~ or not operator",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "UOR",
+        description: "Unary Operator Replacement: Swap ++ and --",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "UOI",
+        description: "Unary Operator Insertion: Negate an if condition",
+        severity: MutationSeverity::Low,
+    },
     // Shared operator shuffle mutations
     Mutation {
         slug: "AOS",
@@ -1,299 +1,860 @@
-use crate::types::PartialMutant;
-use crate::utils::{calculate_line_offset, is_in_comment, node_text, visit_nodes_with_cursor};
+use std::collections::HashMap;
+use std::panic::RefUnwindSafe;
+
+use crate::types::config::is_slug_enabled;
+use crate::types::{Mutant, PartialMutant, Target};
+use crate::utils::{LineIndex, node_text};
 use tree_sitter::Node;
 
-/// Wrap entire nodes of the provided kinds with arbitrary prefix/suffix around the old text
-pub fn wrap(
-    root: Node,
-    source: &str,
-    node_kinds: &[&str],
-    prefix: &str,
-    suffix: &str,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let kinds: Vec<&str> = node_kinds.to_vec();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if kinds.contains(&node.kind())
-            && !is_in_comment(&node)
-            && !has_ancestor_with_kind(&node, &kinds)
-        {
-            let old = node_text(&node, source);
-            let replacement = format!("{prefix}{old}{suffix}");
-            mutants.push(PartialMutant {
-                byte_offset: node.start_byte() as u32,
-                line_offset: calculate_line_offset(source, node.start_byte()),
-                old_text: old.to_string(),
-                new_text: replacement,
+/// Per-target state shared by every pattern during a traversal
+pub struct PatternContext<'a> {
+    pub source: &'a str,
+    pub lines: &'a LineIndex,
+}
+
+impl PatternContext<'_> {
+    /// Build a mutant replacing `old_text` at the given byte position
+    pub fn mutant(&self, byte_offset: usize, old_text: &str, new_text: String) -> PartialMutant {
+        PartialMutant {
+            byte_offset: byte_offset as u32,
+            line_offset: self.lines.line_offset(byte_offset),
+            old_text: old_text.to_string(),
+            new_text,
+        }
+    }
+}
+
+/// A mutation operator that is dispatched the nodes it is interested in during a
+/// single traversal of the syntax tree
+pub trait Pattern: Send + Sync + RefUnwindSafe {
+    /// Node kinds this pattern wants to visit
+    fn kinds(&self) -> &[&'static str];
+
+    /// Emit mutants for a node of one of `kinds()`.
+    /// `ancestors` holds the enclosing nodes, outermost first.
+    fn visit(
+        &self,
+        node: Node,
+        ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    );
+}
+
+/// The set of patterns an engine applies, keyed by mutation slug.
+/// Applying the set walks the tree once and dispatches each node to every
+/// enabled pattern registered for its kind.
+#[derive(Default)]
+pub struct PatternSet {
+    patterns: Vec<(&'static str, Box<dyn Pattern>)>,
+    by_kind: HashMap<&'static str, Vec<usize>>,
+}
+
+impl PatternSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a pattern under a mutation slug
+    pub fn add(&mut self, slug: &'static str, pattern: impl Pattern + 'static) {
+        let idx = self.patterns.len();
+        for kind in pattern.kinds() {
+            let entry = self.by_kind.entry(*kind).or_default();
+            if !entry.contains(&idx) {
+                entry.push(idx);
+            }
+        }
+        self.patterns.push((slug, Box::new(pattern)));
+    }
+
+    /// Apply all enabled patterns (None = all) to a parsed target.
+    /// Mutants are grouped by pattern in registration order, each group in tree order.
+    pub fn apply(&self, root: Node, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let active: Vec<bool> = self
+            .patterns
+            .iter()
+            .map(|(slug, _)| is_slug_enabled(slug, enabled))
+            .collect();
+        let source = target.text.as_str();
+        let lines = LineIndex::new(source);
+        let ctx = PatternContext {
+            source,
+            lines: &lines,
+        };
+
+        let mut buckets: Vec<Vec<PartialMutant>> = vec![Vec::new(); self.patterns.len()];
+        if active.iter().any(|&a| a) {
+            walk(root, &mut |node, ancestors| {
+                if let Some(indices) = self.by_kind.get(node.kind()) {
+                    for &idx in indices {
+                        if active[idx] {
+                            self.patterns[idx]
+                                .1
+                                .visit(node, ancestors, &ctx, &mut buckets[idx]);
+                        }
+                    }
+                }
             });
         }
-    });
-    mutants
+
+        self.patterns
+            .iter()
+            .zip(buckets)
+            .flat_map(|((slug, _), partials)| {
+                partials
+                    .into_iter()
+                    .map(|p| Mutant::from_partial(p, target, slug))
+            })
+            .collect()
+    }
 }
 
+/// Pre-order traversal that skips comment subtrees and tracks the ancestor chain
+fn walk<F>(root: Node, callback: &mut F)
+where
+    F: FnMut(Node, &[Node]),
+{
+    let mut cursor = root.walk();
+    let mut ancestors: Vec<Node> = Vec::new();
+    loop {
+        let node = cursor.node();
+        let in_comment = node.kind() == "comment";
+        if !in_comment {
+            callback(node, &ancestors);
+            if cursor.goto_first_child() {
+                ancestors.push(node);
+                continue;
+            }
+        }
+        loop {
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                return;
+            }
+            ancestors.pop();
+        }
+    }
+}
+
+////////////////////////////////////////
+// Patterns
+
+/// Wrap entire nodes of the provided kinds with arbitrary prefix/suffix around the old text
+pub struct Wrap {
+    kinds: Vec<&'static str>,
+    prefix: String,
+    suffix: String,
+}
+
+impl Wrap {
+    pub fn new(node_kinds: &[&'static str], prefix: &str, suffix: &str) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+        }
+    }
+}
+
+impl Pattern for Wrap {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        if has_ancestor_with_kind(ancestors, &self.kinds) {
+            return;
+        }
+        let old = node_text(&node, ctx.source);
+        let replacement = format!("{}{old}{}", self.prefix, self.suffix);
+        out.push(ctx.mutant(node.start_byte(), old, replacement));
+    }
+}
+
+type NodeFilter = Box<dyn Fn(&Node, &str) -> bool + Send + Sync + RefUnwindSafe>;
+
 /// Replace entire nodes of the provided kinds with a fixed replacement text
 /// controlled by a filter predicate
-pub fn replace(
-    root: Node,
-    source: &str,
-    node_kinds: &[&str],
-    replacement_text: &str,
-    should_replace: &dyn Fn(&Node, &str) -> bool,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let kinds: Vec<&str> = node_kinds.to_vec();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if kinds.contains(&node.kind())
-            && !is_in_comment(&node)
-            && !has_ancestor_with_kind(&node, &kinds)
-            && should_replace(&node, source)
+pub struct Replace {
+    kinds: Vec<&'static str>,
+    replacement: String,
+    should_replace: NodeFilter,
+}
+
+impl Replace {
+    pub fn new(
+        node_kinds: &[&'static str],
+        replacement_text: &str,
+        should_replace: impl Fn(&Node, &str) -> bool + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            replacement: replacement_text.to_string(),
+            should_replace: Box::new(should_replace),
+        }
+    }
+}
+
+impl Pattern for Replace {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        if has_ancestor_with_kind(ancestors, &self.kinds)
+            || !(self.should_replace)(&node, ctx.source)
         {
-            mutants.push(PartialMutant {
-                byte_offset: node.start_byte() as u32,
-                line_offset: calculate_line_offset(source, node.start_byte()),
-                old_text: node_text(&node, source).to_string(),
-                new_text: replacement_text.to_string(),
-            });
+            return;
         }
-    });
-    mutants
+        out.push(ctx.mutant(
+            node.start_byte(),
+            node_text(&node, ctx.source),
+            self.replacement.clone(),
+        ));
+    }
 }
 
-/// Replace a condition for nodes of a specific kind using field-first, positional-fallback
-pub fn replace_condition(
-    root: Node,
-    source: &str,
-    node_kind: &str,
-    condition_field_name: &str,
-    keyword_kinds: &[&str],
-    replacement: &str,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if node.kind() == node_kind && !is_in_comment(&node) {
-            if let Some(field_node) = node.child_by_field_name(condition_field_name) {
-                let old_text = node_text(&field_node, source);
-                let trimmed_start = old_text.trim_start();
-                let trimmed_end = old_text.trim_end();
-                let needs_parens = trimmed_start.starts_with('(') && trimmed_end.ends_with(')');
-                let new_text = if needs_parens {
-                    format!("({replacement})")
-                } else {
-                    replacement.to_string()
-                };
-                mutants.push(PartialMutant {
-                    byte_offset: field_node.start_byte() as u32,
-                    line_offset: calculate_line_offset(source, field_node.start_byte()),
-                    old_text: old_text.to_string(),
-                    new_text,
-                });
-            } else if let Some(cond) = first_named_child_after_keyword(&node, keyword_kinds) {
-                if cond.kind() != ";" && cond.kind() != "{" {
-                    let old_text = node_text(&cond, source);
-                    let trimmed_start = old_text.trim_start();
-                    let trimmed_end = old_text.trim_end();
-                    let needs_parens = trimmed_start.starts_with('(') && trimmed_end.ends_with(')');
-                    let new_text = if needs_parens {
-                        format!("({replacement})")
-                    } else {
-                        replacement.to_string()
-                    };
-                    mutants.push(PartialMutant {
-                        byte_offset: cond.start_byte() as u32,
-                        line_offset: calculate_line_offset(source, cond.start_byte()),
-                        old_text: old_text.to_string(),
-                        new_text,
-                    });
-                }
+type NodeRewrite = Box<dyn Fn(&Node, &str) -> Option<String> + Send + Sync + RefUnwindSafe>;
+
+/// Replace entire nodes of the provided kinds with text computed from the node itself,
+/// skipping nodes for which the rewrite returns None
+pub struct ReplaceWith {
+    kinds: Vec<&'static str>,
+    rewrite: NodeRewrite,
+}
+
+impl ReplaceWith {
+    pub fn new(
+        node_kinds: &[&'static str],
+        rewrite: impl Fn(&Node, &str) -> Option<String> + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            rewrite: Box::new(rewrite),
+        }
+    }
+}
+
+impl Pattern for ReplaceWith {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let old_text = node_text(&node, ctx.source);
+        if let Some(new_text) = (self.rewrite)(&node, ctx.source) {
+            if new_text != old_text {
+                out.push(ctx.mutant(node.start_byte(), old_text, new_text));
             }
         }
-    });
-    mutants
+    }
 }
 
-/// Replace the first argument for calls whose callee matches a predicate
-pub fn replace_first_arg(
-    root: Node,
-    source: &str,
-    call_node_kinds: &[&str],
-    args_field_name: &str,
-    alt_args_kinds: &[&str],
-    callee_matches: &dyn Fn(&str) -> bool,
-    replacement: &str,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let call_kinds: Vec<&str> = call_node_kinds.to_vec();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if call_kinds.contains(&node.kind()) && !is_in_comment(&node) {
-            let callee_text = if let Some(callee_node) = node.child(0) {
-                node_text(&callee_node, source)
-            } else {
+type NodeRewrites = Box<dyn Fn(&Node, &str) -> Vec<String> + Send + Sync + RefUnwindSafe>;
+
+/// Like [`ReplaceWith`], but the rewrite may produce several alternatives per node,
+/// each of which becomes its own mutant
+pub struct ReplaceWithEach {
+    kinds: Vec<&'static str>,
+    rewrite: NodeRewrites,
+}
+
+impl ReplaceWithEach {
+    pub fn new(
+        node_kinds: &[&'static str],
+        rewrite: impl Fn(&Node, &str) -> Vec<String> + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            rewrite: Box::new(rewrite),
+        }
+    }
+}
+
+impl Pattern for ReplaceWithEach {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let old_text = node_text(&node, ctx.source);
+        for new_text in (self.rewrite)(&node, ctx.source) {
+            if new_text != old_text {
+                out.push(ctx.mutant(node.start_byte(), old_text, new_text));
+            }
+        }
+    }
+}
+
+/// Replace a condition for nodes of a specific kind using field-first, positional-fallback
+pub struct ReplaceCondition {
+    kinds: [&'static str; 1],
+    condition_field_name: &'static str,
+    keyword_kinds: Vec<&'static str>,
+    replacement: String,
+}
+
+impl ReplaceCondition {
+    pub fn new(
+        node_kind: &'static str,
+        condition_field_name: &'static str,
+        keyword_kinds: &[&'static str],
+        replacement: &str,
+    ) -> Self {
+        Self {
+            kinds: [node_kind],
+            condition_field_name,
+            keyword_kinds: keyword_kinds.to_vec(),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    fn replacement_for(&self, old_text: &str) -> String {
+        let needs_parens =
+            old_text.trim_start().starts_with('(') && old_text.trim_end().ends_with(')');
+        if needs_parens {
+            format!("({})", self.replacement)
+        } else {
+            self.replacement.clone()
+        }
+    }
+}
+
+impl Pattern for ReplaceCondition {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        if let Some(cond) = condition_node(&node, self.condition_field_name, &self.keyword_kinds) {
+            let old_text = node_text(&cond, ctx.source);
+            out.push(ctx.mutant(cond.start_byte(), old_text, self.replacement_for(old_text)));
+        }
+    }
+}
+
+/// Negate a condition for nodes of a specific kind, located like [`ReplaceCondition`]:
+/// `if (c)` becomes `if (!(c))` and `if c:` becomes `if not (c):`
+pub struct NegateCondition {
+    kinds: [&'static str; 1],
+    condition_field_name: &'static str,
+    keyword_kinds: Vec<&'static str>,
+    negation: &'static str,
+}
+
+impl NegateCondition {
+    pub fn new(
+        node_kind: &'static str,
+        condition_field_name: &'static str,
+        keyword_kinds: &[&'static str],
+        negation: &'static str,
+    ) -> Self {
+        Self {
+            kinds: [node_kind],
+            condition_field_name,
+            keyword_kinds: keyword_kinds.to_vec(),
+            negation,
+        }
+    }
+}
+
+impl Pattern for NegateCondition {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        if let Some(cond) = condition_node(&node, self.condition_field_name, &self.keyword_kinds) {
+            // Negating `!c` would only duplicate deleting its operator, and `if let`
+            // bindings cannot be negated at all
+            if is_negation(&cond, ctx.source, self.negation)
+                || matches!(cond.kind(), "let_condition" | "let_chain")
+            {
                 return;
+            }
+            let old_text = node_text(&cond, ctx.source);
+            // A parenthesized condition keeps its outer parentheses
+            let new_text = if old_text.starts_with('(') && old_text.ends_with(')') {
+                format!("({}{old_text})", self.negation)
+            } else {
+                format!("{}({old_text})", self.negation)
             };
-            if !callee_matches(callee_text) {
-                return;
+            out.push(ctx.mutant(cond.start_byte(), old_text, new_text));
+        }
+    }
+}
+
+/// Rewrite the operator token of unary and update expressions, leaving the operand alone.
+/// An empty replacement deletes the operator (`!x` -> `x`, `not x` -> `x`), anything
+/// else swaps it (`x++` -> `x--`).
+pub struct UnaryOperator {
+    kinds: Vec<&'static str>,
+    rewrites: Vec<(&'static str, &'static str)>,
+}
+
+impl UnaryOperator {
+    pub fn new(node_kinds: &[&'static str], rewrites: &[(&'static str, &'static str)]) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            rewrites: rewrites.to_vec(),
+        }
+    }
+}
+
+impl Pattern for UnaryOperator {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.is_named() {
+                continue;
             }
-            let args_node_opt = node.child_by_field_name(args_field_name).or_else(|| {
-                let mut c = node.walk();
-                for child in node.children(&mut c) {
-                    let k = child.kind();
-                    if alt_args_kinds.contains(&k) || k == args_field_name {
-                        return Some(child);
-                    }
+            for (op, replacement) in &self.rewrites {
+                if child.kind() != *op {
+                    continue;
                 }
-                None
-            });
-            if let Some(args_node) = args_node_opt {
-                let mut ac = args_node.walk();
-                for child in args_node.children(&mut ac) {
-                    let k = child.kind();
-                    if is_punctuation_kind(k) {
-                        continue;
-                    }
-                    mutants.push(PartialMutant {
-                        byte_offset: child.start_byte() as u32,
-                        line_offset: calculate_line_offset(source, child.start_byte()),
-                        old_text: node_text(&child, source).to_string(),
-                        new_text: replacement.to_string(),
-                    });
-                    break;
+                if replacement.is_empty() {
+                    // Delete the operator together with any whitespace before the operand
+                    let end = child
+                        .next_sibling()
+                        .map_or(child.end_byte(), |n| n.start_byte());
+                    let old_text = &ctx.source[child.start_byte()..end];
+                    out.push(ctx.mutant(child.start_byte(), old_text, String::new()));
+                } else {
+                    let old_text = node_text(&child, ctx.source);
+                    out.push(ctx.mutant(child.start_byte(), old_text, replacement.to_string()));
                 }
             }
         }
-    });
-    mutants
+    }
+}
+
+type CalleeFilter = Box<dyn Fn(&str) -> bool + Send + Sync + RefUnwindSafe>;
+
+/// Replace the first argument for calls whose callee matches a predicate
+pub struct ReplaceFirstArg {
+    kinds: Vec<&'static str>,
+    args_field_name: &'static str,
+    alt_args_kinds: Vec<&'static str>,
+    callee_matches: CalleeFilter,
+    replacement: String,
+}
+
+impl ReplaceFirstArg {
+    pub fn new(
+        call_node_kinds: &[&'static str],
+        args_field_name: &'static str,
+        alt_args_kinds: &[&'static str],
+        callee_matches: impl Fn(&str) -> bool + Send + Sync + RefUnwindSafe + 'static,
+        replacement: &str,
+    ) -> Self {
+        Self {
+            kinds: call_node_kinds.to_vec(),
+            args_field_name,
+            alt_args_kinds: alt_args_kinds.to_vec(),
+            callee_matches: Box::new(callee_matches),
+            replacement: replacement.to_string(),
+        }
+    }
+}
+
+impl Pattern for ReplaceFirstArg {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let callee_text = match node.child(0) {
+            Some(callee_node) => node_text(&callee_node, ctx.source),
+            None => return,
+        };
+        if !(self.callee_matches)(callee_text) {
+            return;
+        }
+        let args_node_opt = node.child_by_field_name(self.args_field_name).or_else(|| {
+            let mut c = node.walk();
+            node.children(&mut c).find(|child| {
+                let k = child.kind();
+                self.alt_args_kinds.contains(&k) || k == self.args_field_name
+            })
+        });
+        if let Some(args_node) = args_node_opt {
+            let mut ac = args_node.walk();
+            if let Some(child) = args_node
+                .children(&mut ac)
+                .find(|child| !is_punctuation_kind(child.kind()))
+            {
+                out.push(ctx.mutant(
+                    child.start_byte(),
+                    node_text(&child, ctx.source),
+                    self.replacement.clone(),
+                ));
+            }
+        }
+    }
 }
 
 /// Shuffle operator tokens inside expressions of specified kinds by replacing any occurrence
 /// of the provided operators with any other in the set (excluding identity)
-pub fn shuffle_operators(
-    root: Node,
-    source: &str,
-    expr_node_kinds: &[&str],
-    operators: &[&str],
-) -> Vec<PartialMutant> {
-    shuffle_impl(root, source, expr_node_kinds, operators, false)
+pub struct ShuffleOperators {
+    kinds: Vec<&'static str>,
+    operators: Vec<&'static str>,
 }
 
-/// Shuffle by replacing entire node text with alternatives from the operators set
-pub fn shuffle_nodes(
-    root: Node,
-    source: &str,
-    node_kinds: &[&str],
-    alternatives: &[&str],
-) -> Vec<PartialMutant> {
-    shuffle_impl(root, source, node_kinds, alternatives, true)
-}
-
-fn shuffle_impl(
-    root: Node,
-    source: &str,
-    node_kinds: &[&str],
-    options: &[&str],
-    full_node_mode: bool,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let kinds: Vec<&str> = node_kinds.to_vec();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if kinds.contains(&node.kind()) && !is_in_comment(&node) {
-            if full_node_mode {
-                let node_text_str = node_text(&node, source);
-                for replacement in options.iter().copied() {
-                    let matches = if options.len() == 2 {
-                        node_text_str.contains(options[0]) || node_text_str.contains(options[1])
-                    } else {
-                        options.contains(&node_text_str)
-                    };
-                    if matches && replacement != node_text_str {
-                        let new_text = if options.len() == 2 {
-                            if node_text_str.contains(options[0]) {
-                                node_text_str.replace(options[0], replacement)
-                            } else {
-                                node_text_str.replace(options[1], replacement)
-                            }
-                        } else {
-                            replacement.to_string()
-                        };
-                        if new_text != node_text_str {
-                            mutants.push(PartialMutant {
-                                byte_offset: node.start_byte() as u32,
-                                line_offset: calculate_line_offset(source, node.start_byte()),
-                                old_text: node_text_str.to_string(),
-                                new_text,
-                            });
-                        }
+impl ShuffleOperators {
+    pub fn new(expr_node_kinds: &[&'static str], operators: &[&'static str]) -> Self {
+        Self {
+            kinds: expr_node_kinds.to_vec(),
+            operators: operators.to_vec(),
+        }
+    }
+}
+
+impl Pattern for ShuffleOperators {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let mut nc = node.walk();
+        for child in node.children(&mut nc) {
+            let token = node_text(&child, ctx.source);
+            if self.operators.contains(&token) {
+                for replacement in self.operators.iter().copied() {
+                    if replacement != token {
+                        out.push(ctx.mutant(child.start_byte(), token, replacement.to_string()));
                     }
                 }
+            }
+        }
+    }
+}
+
+/// Shuffle by replacing entire node text with alternatives from the operators set
+pub struct ShuffleNodes {
+    kinds: Vec<&'static str>,
+    alternatives: Vec<&'static str>,
+}
+
+impl ShuffleNodes {
+    pub fn new(node_kinds: &[&'static str], alternatives: &[&'static str]) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            alternatives: alternatives.to_vec(),
+        }
+    }
+}
+
+impl Pattern for ShuffleNodes {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let options = &self.alternatives;
+        let node_text_str = node_text(&node, ctx.source);
+        for replacement in options.iter().copied() {
+            let matches = if options.len() == 2 {
+                node_text_str.contains(options[0]) || node_text_str.contains(options[1])
             } else {
-                let mut nc = node.walk();
-                for child in node.children(&mut nc) {
-                    let token = node_text(&child, source);
-                    if options.contains(&token) {
-                        for replacement in options.iter().copied() {
-                            if replacement != token {
-                                mutants.push(PartialMutant {
-                                    byte_offset: child.start_byte() as u32,
-                                    line_offset: calculate_line_offset(source, child.start_byte()),
-                                    old_text: token.to_string(),
-                                    new_text: replacement.to_string(),
-                                });
-                            }
-                        }
+                options.contains(&node_text_str)
+            };
+            if matches && replacement != node_text_str {
+                let new_text = if options.len() == 2 {
+                    if node_text_str.contains(options[0]) {
+                        node_text_str.replace(options[0], replacement)
+                    } else {
+                        node_text_str.replace(options[1], replacement)
                     }
+                } else {
+                    replacement.to_string()
+                };
+                if new_text != node_text_str {
+                    out.push(ctx.mutant(node.start_byte(), node_text_str, new_text));
                 }
             }
         }
-    });
-    mutants
+    }
 }
 
 /// Swap adjacent arguments inside a child field (e.g., "arguments") for specified node kinds
-pub fn swap_args(
-    root: Node,
-    source: &str,
-    node_kinds: &[&str],
-    args_field_name: &str,
-) -> Vec<PartialMutant> {
-    let mut mutants = Vec::new();
-    let kinds: Vec<&str> = node_kinds.to_vec();
-    let mut cursor = root.walk();
-    visit_nodes_with_cursor(root, &mut cursor, &mut |node| {
-        if kinds.contains(&node.kind()) && !is_in_comment(&node) {
-            if let Some(args_node) = node.child_by_field_name(args_field_name) {
-                let mut args: Vec<Node> = Vec::new();
-                let mut ac = args_node.walk();
-                for child in args_node.children(&mut ac) {
-                    let k = child.kind();
-                    if k != "(" && k != ")" && k != "," {
-                        args.push(child);
-                    }
-                }
-                if args.len() >= 2 {
-                    for i in 0..args.len() - 1 {
-                        let a = args[i];
-                        let b = args[i + 1];
-                        let start = a.start_byte();
-                        let end = b.end_byte();
-                        let a_text = node_text(&a, source);
-                        let b_text = node_text(&b, source);
-                        let full_text = &source[start..end];
-                        let swapped = format!("{b_text}, {a_text}");
-                        mutants.push(PartialMutant {
-                            byte_offset: start as u32,
-                            line_offset: calculate_line_offset(source, start),
-                            old_text: full_text.to_string(),
-                            new_text: swapped,
-                        });
-                    }
-                }
+pub struct SwapArgs {
+    kinds: Vec<&'static str>,
+    args_field_name: &'static str,
+}
+
+impl SwapArgs {
+    pub fn new(node_kinds: &[&'static str], args_field_name: &'static str) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            args_field_name,
+        }
+    }
+}
+
+impl Pattern for SwapArgs {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        if let Some(args_node) = node.child_by_field_name(self.args_field_name) {
+            let mut ac = args_node.walk();
+            let args: Vec<Node> = args_node
+                .children(&mut ac)
+                .filter(|child| !is_punctuation_kind(child.kind()))
+                .collect();
+            for pair in args.windows(2) {
+                let (a, b) = (pair[0], pair[1]);
+                let full_text = &ctx.source[a.start_byte()..b.end_byte()];
+                let swapped = format!(
+                    "{}, {}",
+                    node_text(&b, ctx.source),
+                    node_text(&a, ctx.source)
+                );
+                out.push(ctx.mutant(a.start_byte(), full_text, swapped));
+            }
+        }
+    }
+}
+
+/// Default values keyed by declared type. A key matches the type exactly, or by prefix
+/// when it ends in `*` (a lone `*` matches anything). `{type}` in a default is replaced
+/// by the type itself, and an empty default marks a type that has none.
+pub type DefaultTable = &'static [(&'static str, &'static str)];
+
+/// Look up the default for a declared type in a [`DefaultTable`]
+pub fn default_for_type(table: DefaultTable, ty: &str) -> Option<String> {
+    let (_, default) = table.iter().find(|(key, _)| match key.strip_suffix('*') {
+        Some(prefix) => ty.starts_with(prefix),
+        None => ty == *key,
+    })?;
+    (!default.is_empty()).then(|| default.replace("{type}", ty))
+}
+
+/// The expression a `return` statement returns: its first named, non-comment child
+pub fn returned_expression(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .find(|child| !child.kind().contains("comment"))
+}
+
+/// The nearest ancestor of one of the given kinds, such as the function a `return` is in
+pub fn enclosing<'a>(node: Node<'a>, kinds: &[&str]) -> Option<Node<'a>> {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if kinds.contains(&n.kind()) {
+            return Some(n);
+        }
+        current = n.parent();
+    }
+    None
+}
+
+type ValueLocator = fn(Node) -> Option<Node>;
+type TypeLocator = fn(Node, &str) -> Option<String>;
+
+/// Whether a node is a literal in any of the vendored grammars
+fn is_literal(node: &Node) -> bool {
+    let kind = node.kind();
+    kind.ends_with("literal")
+        || matches!(
+            kind,
+            "number" | "integer" | "float" | "string" | "template_string" | "true" | "false"
+        )
+}
+
+/// Replace returned values with the default for the enclosing function's return type.
+/// `value` finds the returned expression of a visited node and `return_type` the
+/// declared type to look up, where None means the value cannot be replaced. Literals
+/// are left to the literal operators, which already produce the default or its equivalent.
+pub struct ReturnValue {
+    kinds: Vec<&'static str>,
+    defaults: DefaultTable,
+    value: ValueLocator,
+    return_type: TypeLocator,
+}
+
+impl ReturnValue {
+    pub fn new(
+        node_kinds: &[&'static str],
+        defaults: DefaultTable,
+        value: ValueLocator,
+        return_type: TypeLocator,
+    ) -> Self {
+        Self {
+            kinds: node_kinds.to_vec(),
+            defaults,
+            value,
+            return_type,
+        }
+    }
+}
+
+impl Pattern for ReturnValue {
+    fn kinds(&self) -> &[&'static str] {
+        &self.kinds
+    }
+
+    fn visit(
+        &self,
+        node: Node,
+        _ancestors: &[Node],
+        ctx: &PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let Some(value) = (self.value)(node).filter(|v| !is_literal(v)) else {
+            return;
+        };
+        let Some(ty) = (self.return_type)(node, ctx.source) else {
+            return;
+        };
+        if let Some(default) = default_for_type(self.defaults, &ty) {
+            let old_text = node_text(&value, ctx.source);
+            if default != old_text {
+                out.push(ctx.mutant(value.start_byte(), old_text, default));
             }
         }
-    });
-    mutants
+    }
+}
+
+////////////////////////////////////////
+// Literal rewrites
+
+/// Boundary and off-by-one alternatives for a decimal integer literal: `n` becomes
+/// `0`, `1`, `n + 1` and `n - 1`. A type suffix or unit (`10u32`, `10_u32`, `10n`,
+/// `1 ether`) is kept; floats, exponents, legacy octal and prefixed literals are skipped.
+pub fn numeric_literal_variants(text: &str) -> Vec<String> {
+    let is_digit = |c: char| c.is_ascii_digit() || c == '_' || c == '\'';
+    let run = text.find(|c: char| !is_digit(c)).unwrap_or(text.len());
+    let digits = text[..run].trim_end_matches(['_', '\'']);
+    let suffix = &text[digits.len()..];
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return Vec::new();
+    }
+    if suffix.starts_with(['.', 'e', 'E', 'x', 'X', 'o', 'O', 'b', 'B']) {
+        return Vec::new();
+    }
+    let Ok(n) = digits.replace(['_', '\''], "").parse::<u128>() else {
+        return Vec::new();
+    };
+
+    let mut variants: Vec<String> = Vec::new();
+    for value in [Some(0), Some(1), n.checked_add(1), n.checked_sub(1)]
+        .into_iter()
+        .flatten()
+    {
+        let variant = format!("{value}{suffix}");
+        if value != n && !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+/// Empty a string literal while keeping its prefix and quoting (`b"..."`, `r#"..."#`,
+/// `'''...'''`, template backticks), or None if it is already empty
+pub fn empty_string_literal(text: &str) -> Option<String> {
+    let open = text.find(['"', '\'', '`'])?;
+    let quote = text[open..].chars().next()?;
+    let run = text[open..].chars().take_while(|&c| c == quote).count();
+    let width = if run >= 3 { 3 } else { 1 };
+    let body_start = open + width;
+    let body_end = (text.rfind(quote)? + 1).saturating_sub(width);
+    if body_end <= body_start {
+        return None;
+    }
+    Some(format!("{}{}", &text[..body_start], &text[body_end..]))
+}
+
+/// Zero every digit of a hex literal (`0xdEaD` -> `0x0000`), keeping its width so
+/// address literals stay valid addresses, or None if it is already zero
+pub fn zero_hex_literal(text: &str) -> Option<String> {
+    let digits = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix("0X"))?;
+    let end = digits
+        .find(|c: char| !(c.is_ascii_hexdigit() || c == '_'))
+        .unwrap_or(digits.len());
+    if !digits[..end].contains(|c: char| c.is_ascii_hexdigit() && c != '0') {
+        return None;
+    }
+    let zeroed: String = digits[..end]
+        .chars()
+        .map(|c| if c == '_' { c } else { '0' })
+        .collect();
+    Some(format!("{}{zeroed}{}", &text[..2], &digits[end..]))
 }
 
 ////////////////////////////////////////
@@ -307,15 +868,34 @@ fn is_keyword_kind(kind: &str, keywords: &[&str]) -> bool {
     keywords.contains(&kind)
 }
 
-fn has_ancestor_with_kind(node: &Node, kinds: &[&str]) -> bool {
-    let mut current = node.parent();
-    while let Some(parent) = current {
-        if kinds.contains(&parent.kind()) {
-            return true;
+fn has_ancestor_with_kind(ancestors: &[Node], kinds: &[&str]) -> bool {
+    ancestors.iter().any(|a| kinds.contains(&a.kind()))
+}
+
+/// Locate the condition of a node using its field, falling back to the first named
+/// child after the keyword
+fn condition_node<'a>(node: &Node<'a>, field_name: &str, keywords: &[&str]) -> Option<Node<'a>> {
+    if let Some(field_node) = node.child_by_field_name(field_name) {
+        return Some(field_node);
+    }
+    first_named_child_after_keyword(node, keywords)
+        .filter(|cond| cond.kind() != ";" && cond.kind() != "{")
+}
+
+/// Whether an expression is itself a negation, looking through parentheses and
+/// single-child wrapper nodes
+fn is_negation(expr: &Node, source: &str, negation: &str) -> bool {
+    let mut node = *expr;
+    while node.named_child_count() == 1
+        && (node.kind() == "parenthesized_expression" || node.child_count() == 1)
+    {
+        match node.named_child(0) {
+            Some(child) => node = child,
+            None => break,
         }
-        current = parent.parent();
     }
-    false
+    matches!(node.kind(), "unary_expression" | "not_operator")
+        && node_text(&node, source).starts_with(negation.trim_end())
 }
 
 fn first_named_child_after_keyword<'a>(node: &Node<'a>, keywords: &[&str]) -> Option<Node<'a>> {
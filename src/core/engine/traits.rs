@@ -1,3 +1,4 @@
+use crate::types::config::is_slug_enabled;
 use crate::types::{Mutant, Mutation, MutationSeverity, Target};
 use tree_sitter::Language as TsLanguage;
 
@@ -18,6 +19,14 @@ pub trait LanguageEngine: Send + Sync {
     /// Apply all mutations to a target and return mutants
     fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant>;
 
+    /// Apply only the enabled mutations (None = all) to a target.
+    /// Engines backed by a `PatternSet` override this to skip disabled operators during traversal.
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let mut mutants = self.apply_all_mutations(target);
+        mutants.retain(|m| is_slug_enabled(&m.mutation_slug, enabled));
+        mutants
+    }
+
     /// Get all unique mutation slugs for this language
     fn get_all_slugs(&self) -> Vec<String> {
         self.get_mutations()
@@ -52,6 +52,7 @@ where
 }
 
 /// Calculate line offset for a byte position
+/// Rescans the source from the start; use a `LineIndex` for repeated lookups
 pub fn calculate_line_offset(source: &str, byte_offset: usize) -> u32 {
     source
         .bytes()
@@ -59,3 +60,49 @@ pub fn calculate_line_offset(source: &str, byte_offset: usize) -> u32 {
         .filter(|&b| b == b'\n')
         .count() as u32
 }
+
+/// Precomputed table of line start positions for fast byte-to-line lookups
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(
+            source
+                .bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'\n')
+                .map(|(i, _)| i + 1),
+        );
+        Self { line_starts }
+    }
+
+    /// Zero-based line of a byte position, equivalent to `calculate_line_offset`
+    pub fn line_offset(&self, byte_offset: usize) -> u32 {
+        (self
+            .line_starts
+            .partition_point(|&start| start <= byte_offset)
+            - 1) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_index_matches_calculate_line_offset() {
+        let source = "a\n\nbc\n  d\ne";
+        let index = LineIndex::new(source);
+        for offset in 0..=source.len() {
+            assert_eq!(
+                index.line_offset(offset),
+                calculate_line_offset(source, offset),
+                "mismatch at byte {offset}"
+            );
+        }
+    }
+}
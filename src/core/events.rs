@@ -0,0 +1,110 @@
+//! Newline-delimited JSON output of campaign events (`--events jsonl[=path]`).
+//! Each line is one [`CampaignEvent`] object tagged by its `event` field, with the time
+//! it was emitted in `time`. Lines are flushed as they are written so consumers can
+//! follow a running campaign.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use chrono::{SecondsFormat, Utc};
+use log::warn;
+use serde::Serialize;
+
+use crate::core::campaign::{CampaignEvent, EventHandler};
+
+/// Where `--events` writes to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventsOutput {
+    /// `jsonl`: standard output; logs move to standard error
+    Stdout,
+    /// `jsonl=<path>`: a file, truncated first
+    File(PathBuf),
+}
+
+impl FromStr for EventsOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, path) = match s.split_once('=') {
+            Some((format, path)) => (format, Some(path)),
+            None => (s, None),
+        };
+        if format != "jsonl" {
+            return Err(format!(
+                "unsupported events format `{format}`; expected `jsonl` or `jsonl=<path>`"
+            ));
+        }
+        match path {
+            None | Some("-") => Ok(EventsOutput::Stdout),
+            Some("") => Err("missing path after `jsonl=`".to_string()),
+            Some(path) => Ok(EventsOutput::File(PathBuf::from(path))),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    time: String,
+    #[serde(flatten)]
+    event: &'a CampaignEvent,
+}
+
+/// Event handler writing each event as a JSON line to `output`
+pub fn jsonl_handler(output: &EventsOutput) -> io::Result<EventHandler> {
+    let writer: Box<dyn Write + Send> = match output {
+        EventsOutput::Stdout => Box::new(io::stdout()),
+        EventsOutput::File(path) => Box::new(BufWriter::new(File::create(path)?)),
+    };
+    let writer = Mutex::new(writer);
+    Ok(Arc::new(move |event: &CampaignEvent| {
+        let line = Line {
+            time: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            event,
+        };
+        let mut writer = writer.lock().unwrap();
+        let written = serde_json::to_writer(&mut *writer, &line)
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(writer))
+            .and_then(|_| writer.flush());
+        if let Err(e) = written {
+            warn!("Failed to write event: {e}");
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_events_argument() {
+        assert_eq!("jsonl".parse(), Ok(EventsOutput::Stdout));
+        assert_eq!("jsonl=-".parse(), Ok(EventsOutput::Stdout));
+        assert_eq!(
+            "jsonl=out/events.jsonl".parse(),
+            Ok(EventsOutput::File(PathBuf::from("out/events.jsonl")))
+        );
+        assert!("json".parse::<EventsOutput>().is_err());
+        assert!("jsonl=".parse::<EventsOutput>().is_err());
+    }
+
+    #[test]
+    fn events_are_tagged_json_lines() {
+        let event = CampaignEvent::TargetStarted {
+            target: PathBuf::from("src/lib.rs"),
+            mutants: 3,
+        };
+        let line = serde_json::to_string(&Line {
+            time: "2026-01-01T00:00:00.000Z".to_string(),
+            event: &event,
+        })
+        .unwrap();
+        assert_eq!(
+            line,
+            r#"{"time":"2026-01-01T00:00:00.000Z","event":"target_started","target":"src/lib.rs","mutants":3}"#
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! Logging utilities that integrate with `indicatif` progress bars.
-//! - Logs go to stdout via a custom writer (`BarAwareWriter`).
+//! - Logs go to stdout via a custom writer (`BarAwareWriter`), or to stderr when stdout
+//!   carries machine-readable output.
 //! - When a bar is active, completed lines use `ProgressBar::println`, preserving the bar.
 //! - Progress bars render on stderr (indicatif default); logs stay on stdout.
 //! - Log level comes from CLI flags or config file; default is Info.
 //! - Colors are applied with `console::style`.
 //! - A `Mutex<Vec<u8>>` buffers bytes to assemble whole lines without interleaving.
+//! - `init_logging` returns the `ActiveBar` the writer prints through; bars are drawn only
+//!   by code that was handed it, so library users get events instead of terminal output.
 
 use std::io::{self, Write};
 
@@ -13,29 +16,42 @@ use fern::Dispatch;
 use indicatif::WeakProgressBar;
 use indicatif::{ProgressBar, ProgressStyle};
 use log::LevelFilter;
-use once_cell::sync::OnceCell;
-use std::sync::Mutex;
-
-use crate::types::config::config;
-
-/// Global registry of the active progress bar as a `WeakProgressBar`.
-/// Wrapped in a `Mutex` inside a `OnceCell` so logging can access it
-/// without keeping the bar alive.
-static ACTIVE_BAR: OnceCell<Mutex<Option<WeakProgressBar>>> = OnceCell::new();
-
-/// Register or clear the active progress bar. Stores a weak reference so the
-/// bar can drop naturally.
-fn set_active_progress_bar(bar: Option<&ProgressBar>) {
-    let cell = ACTIVE_BAR.get_or_init(|| Mutex::new(None));
-    let weak = bar.map(|b| b.downgrade());
-    if let Ok(mut guard) = cell.lock() {
-        *guard = weak;
+use std::sync::{Arc, Mutex};
+
+use crate::types::config::Config;
+
+/// The progress bar log lines are printed above, shared between the logger and whoever
+/// draws the bar. Holds a `WeakProgressBar` so logging doesn't keep the bar alive.
+#[derive(Clone, Default)]
+pub struct ActiveBar(Arc<Mutex<Option<WeakProgressBar>>>);
+
+impl ActiveBar {
+    /// Register or clear the active progress bar
+    fn set(&self, bar: Option<&ProgressBar>) {
+        let weak = bar.map(|b| b.downgrade());
+        if let Ok(mut guard) = self.0.lock() {
+            *guard = weak;
+        }
+    }
+
+    /// Print `line` above the active bar; false if no bar is active
+    fn println(&self, line: &str) -> bool {
+        let Ok(guard) = self.0.lock() else {
+            return false;
+        };
+        match guard.as_ref().and_then(WeakProgressBar::upgrade) {
+            Some(bar) => {
+                bar.println(line);
+                true
+            }
+            None => false,
+        }
     }
 }
 
 /// Create a sized progress bar with the default style, set its message, and
 /// register it as the active bar.
-pub fn new_progress_bar(len: u64, message: impl Into<String>) -> ProgressBar {
+pub fn new_progress_bar(active: &ActiveBar, len: u64, message: impl Into<String>) -> ProgressBar {
     let bar = ProgressBar::new(len);
     bar.set_style(
         ProgressStyle::with_template(
@@ -45,22 +61,34 @@ pub fn new_progress_bar(len: u64, message: impl Into<String>) -> ProgressBar {
         .progress_chars("#>-"),
     );
     bar.set_message(message.into());
-    set_active_progress_bar(Some(&bar));
+    active.set(Some(&bar));
     bar
 }
 
-/// Finish and clear the provided progress bar, then clear the active bar registry.
-pub fn end_progress_bar(bar: &ProgressBar) {
+/// Finish and clear the provided progress bar, then clear the active bar.
+pub fn end_progress_bar(active: &ActiveBar, bar: &ProgressBar) {
     bar.finish_and_clear();
-    set_active_progress_bar(None);
+    active.set(None);
 }
 
 /// Writer that buffers bytes until a newline and then emits whole lines.
 /// If a progress bar is active, lines are printed via `bar.println(...)`;
-/// otherwise they are written to stdout. The internal `Mutex<Vec<u8>>`
+/// otherwise they are written to stdout (or stderr). The internal `Mutex<Vec<u8>>`
 /// ensures that line assembly is synchronized and not interleaved.
 struct BarAwareWriter {
     buffer: Mutex<Vec<u8>>, // accumulates bytes until a '\n' is seen
+    active: ActiveBar,
+    stderr: bool,
+}
+
+impl BarAwareWriter {
+    fn output(&self) -> Box<dyn Write> {
+        if self.stderr {
+            Box::new(io::stderr())
+        } else {
+            Box::new(io::stdout())
+        }
+    }
 }
 
 impl Write for BarAwareWriter {
@@ -85,19 +113,8 @@ impl Write for BarAwareWriter {
                         line_bytes.pop();
                     }
                     let line = String::from_utf8_lossy(&line_bytes);
-                    let mut printed_via_bar = false;
-                    if let Some(cell) = ACTIVE_BAR.get() {
-                        if let Ok(guard) = cell.lock() {
-                            if let Some(ref weak) = *guard {
-                                if let Some(bar) = weak.upgrade() {
-                                    bar.println(&line);
-                                    printed_via_bar = true;
-                                }
-                            }
-                        }
-                    }
-                    if !printed_via_bar {
-                        let _ = writeln!(io::stdout(), "{}", line);
+                    if !self.active.println(&line) {
+                        let _ = writeln!(self.output(), "{}", line);
                     }
                 }
                 None => break,
@@ -111,31 +128,22 @@ impl Write for BarAwareWriter {
         if let Ok(mut buf_guard) = self.buffer.lock() {
             if !buf_guard.is_empty() {
                 let line = String::from_utf8_lossy(&buf_guard);
-                let mut printed_via_bar = false;
-                if let Some(cell) = ACTIVE_BAR.get() {
-                    if let Ok(guard) = cell.lock() {
-                        if let Some(ref weak) = *guard {
-                            if let Some(bar) = weak.upgrade() {
-                                bar.println(&line);
-                                printed_via_bar = true;
-                            }
-                        }
-                    }
-                }
-                if !printed_via_bar {
-                    let _ = writeln!(io::stdout(), "{}", line);
+                if !self.active.println(&line) {
+                    let _ = writeln!(self.output(), "{}", line);
                 }
                 buf_guard.clear();
             }
         }
-        io::stdout().flush()
+        self.output().flush()
     }
 }
 
 /// Initialize logging with `fern`, using `BarAwareWriter` to coexist with active
-/// progress bars. Level comes from config, default Info.
-pub fn init_logging() {
-    let level = match config().log().level().to_lowercase().as_str() {
+/// progress bars. Level comes from config, default Info. Logs are written to stderr
+/// if `to_stderr` is set. Returns the bar slot the logger prints through.
+pub fn init_logging(config: &Config, to_stderr: bool) -> ActiveBar {
+    let active = ActiveBar::default();
+    let level = match config.log().level().to_lowercase().as_str() {
         "trace" => LevelFilter::Trace,
         "debug" => LevelFilter::Debug,
         "info" => LevelFilter::Info,
@@ -159,6 +167,9 @@ pub fn init_logging() {
         })
         .chain(Box::new(BarAwareWriter {
             buffer: Mutex::new(Vec::new()),
+            active: active.clone(),
+            stderr: to_stderr,
         }) as Box<dyn Write + Send>)
         .apply();
+    active
 }
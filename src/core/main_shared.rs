@@ -1,27 +1,25 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use clap::{CommandFactory, FromArgMatches};
-use log::{debug, warn};
+use log::{debug, error, warn};
 
 use crate::LanguageRegistry;
-use crate::core::cli::{Args, Commands, PrintArgs};
+use crate::core::cli::{Args, Commands, ConfigArgs, PrintArgs};
 use crate::core::cmds;
+use crate::core::events::EventsOutput;
 use crate::core::logging::init_logging;
 use crate::core::store::SqlStore;
-use crate::types::AppResult;
-use crate::types::config::{CliOverrides, config, init_with_overrides, set_namespace};
+use crate::types::config::{CliOverrides, load_with_overrides};
+use crate::types::{AppError, AppResult};
 
 pub async fn run_main(
     registry: Arc<LanguageRegistry>,
     namespace: &str,
     description: &str,
 ) -> AppResult<()> {
-    // Set namespace at start (derives config/db filenames)
-    set_namespace(namespace);
-
     // Override CLI help text with namespace and description
     // Leak strings to get 'static lifetime for clap
     let namespace_static: &'static str = Box::leak(namespace.to_string().into_boxed_str());
@@ -47,30 +45,50 @@ pub async fn run_main(
         db: args.db.clone(),
         log_level: args.log_level.clone(),
         log_color: args.log_color.clone(),
+        profile: args.profile.clone(),
     };
 
-    // Initialize configuration (files, env, then CLI overrides)
-    init_with_overrides(&cli_overrides);
-
-    // Initialize logging after config so level/color are applied
-    init_logging();
+    // Load configuration (files, env, then CLI overrides); the namespace derives the
+    // config, database and ignore file names
+    let (config, config_issues) =
+        load_with_overrides(namespace, &cli_overrides, &registry.mutation_slugs());
+    if let Some(force) = config.log().color() {
+        console::set_colors_enabled(force);
+        console::set_colors_enabled_stderr(force);
+    }
 
-    // Initialize the database
-    let db_path = config().db();
-    let db_file = PathBuf::from(&db_path);
+    // Initialize logging after config so level/color are applied. Events streamed to
+    // stdout and the language server protocol keep it machine-readable, so logs move to
+    // stderr.
+    let stdout_reserved = match &args.command {
+        Commands::Run(run_args) => run_args.events == Some(EventsOutput::Stdout),
+        Commands::Lsp(_) => true,
+        _ => false,
+    };
+    let active_bar = init_logging(&config, stdout_reserved);
 
-    if !db_file.exists() {
-        debug!(
-            "Database file doesn't exist. Creating it at: {}",
-            db_file.display()
-        );
-        let file = std::fs::File::create(&db_file)?;
-        drop(file);
+    // Config checks report problems themselves and don't need the database
+    if let Commands::Config {
+        command: ConfigArgs::Check,
+    } = args.command
+    {
+        if cmds::execute_config_check(namespace, &registry, args.profile.as_deref()).await > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    if !config_issues.is_empty() {
+        for issue in &config_issues {
+            error!("{issue}");
+        }
+        // Init may replace a broken config file, so it runs regardless
+        if !matches!(args.command, Commands::Init(_)) {
+            return Err(AppError::Custom(format!(
+                "Invalid config file ({} problem(s)); run `{namespace} config check` for details",
+                config_issues.len()
+            )));
+        }
     }
-
-    let db_connection_string = format!("sqlite:{db_path}");
-    debug!("Using database: {db_connection_string}");
-    let store = SqlStore::new(db_connection_string).await?;
 
     // Setup running flag to handle signals from ctrl-c
     let running = Arc::new(AtomicBool::new(true));
@@ -82,56 +100,57 @@ pub async fn run_main(
     })
     .expect("Error creating a Ctrl-C handler");
 
+    // The language server opens the database once the editor names the workspace root
+    if let Commands::Lsp(lsp_args) = args.command {
+        cmds::execute_lsp(lsp_args, config, running, Arc::clone(&registry)).await?;
+        return Ok(());
+    }
+
+    // Initialize the database
+    let store = open_store(Path::new(&config.db())).await?;
+
     // Dispatch to appropriate command
     let exit_code = match args.command {
         Commands::Run(run_args) => {
             // Resolve command-specific options
             let resolved_targets = if !run_args.targets.is_empty()
                 || run_args.ignore_targets.is_some()
+                || run_args.no_ignore
             {
-                Some(
-                    config()
-                        .resolve_targets(&run_args.targets, run_args.ignore_targets.as_deref())?,
-                )
+                Some(config.resolve_targets(
+                    &run_args.targets,
+                    run_args.ignore_targets.as_deref(),
+                    run_args.no_ignore,
+                )?)
             } else {
                 None
             };
-            let mutations = config().resolve_mutations(run_args.mutations.as_deref());
-            let test_cmd = config().resolve_test_cmd(run_args.test_cmd.as_deref());
-            let test_timeout = config().resolve_test_timeout(run_args.test_timeout);
-
-            let summary = cmds::execute_run(
+            let result = cmds::execute_run(
                 run_args,
+                config.clone(),
                 store,
                 Arc::clone(&running),
                 Arc::clone(&registry),
                 resolved_targets,
-                mutations,
-                test_cmd,
-                test_timeout,
+                active_bar,
             )
             .await?;
 
-            // Determine exit code based on campaign results
-            match summary {
-                Some(_summary) if !running.load(Ordering::SeqCst) => {
-                    // Campaign was interrupted
-                    2
-                }
-                _ => {
-                    // Successful completion (regardless of uncaught mutants)
-                    0
-                }
-            }
+            // Interrupted campaigns exit with 2; uncaught mutants are not a failure
+            if result.interrupted { 2 } else { 0 }
         }
         Commands::Mutate(mutate_args) => {
             // Resolve command-specific options
-            let resolved_targets = config()
-                .resolve_targets(&mutate_args.targets, mutate_args.ignore_targets.as_deref())?;
-            let mutations = config().resolve_mutations(None);
+            let resolved_targets = config.resolve_targets(
+                &mutate_args.targets,
+                mutate_args.ignore_targets.as_deref(),
+                mutate_args.no_ignore,
+            )?;
+            let mutations = config.resolve_mutations(None);
 
             cmds::execute_mutate(
                 mutate_args,
+                config.clone(),
                 store,
                 Arc::clone(&registry),
                 resolved_targets,
@@ -145,12 +164,13 @@ pub async fn run_main(
             0
         }
         Commands::Test(test_args) => {
-            // Resolve command-specific options
-            let test_cmd = config().resolve_test_cmd(test_args.test_cmd.as_deref());
-            let test_timeout = config().resolve_test_timeout(test_args.test_timeout);
+            // Per-target test settings are resolved for each mutant's target
+            let test_cmd = test_args.test_cmd.clone();
+            let test_timeout = test_args.test_timeout;
 
             cmds::execute_test(
                 test_args,
+                config.clone(),
                 store,
                 running,
                 Arc::clone(&registry),
@@ -160,6 +180,17 @@ pub async fn run_main(
             .await?;
             0
         }
+        Commands::Serve(serve_args) => {
+            cmds::execute_serve(
+                serve_args,
+                config.clone(),
+                store,
+                running,
+                Arc::clone(&registry),
+            )
+            .await?;
+            0
+        }
         Commands::Purge(purge_args) => {
             cmds::execute_purge(purge_args, store).await?;
             0
@@ -235,7 +266,7 @@ pub async fn run_main(
                 }
                 PrintArgs::Config(args) => {
                     cmds::execute_print(
-                        cmds::print::PrintCommand::Config(args.format),
+                        cmds::print::PrintCommand::Config(Box::new(config), args.format),
                         None,
                         Arc::clone(&registry),
                     )
@@ -244,10 +275,12 @@ pub async fn run_main(
             }
             0
         }
-        Commands::Init => {
-            cmds::execute_init().await?;
+        Commands::Init(init_args) => {
+            cmds::execute_init(init_args, &config).await?;
             0
         }
+        Commands::Config { .. } => unreachable!("config commands are handled before dispatch"),
+        Commands::Lsp(_) => unreachable!("the language server is started before dispatch"),
     };
 
     // Exit with appropriate code
@@ -257,3 +290,19 @@ pub async fn run_main(
 
     Ok(())
 }
+
+/// Open the database at `path`, creating the file if it doesn't exist
+pub(crate) async fn open_store(path: &Path) -> AppResult<SqlStore> {
+    if !path.exists() {
+        debug!(
+            "Database file doesn't exist. Creating it at: {}",
+            path.display()
+        );
+        let file = std::fs::File::create(path)?;
+        drop(file);
+    }
+
+    let db_connection_string = format!("sqlite:{}", path.display());
+    debug!("Using database: {db_connection_string}");
+    Ok(SqlStore::new(db_connection_string).await?)
+}
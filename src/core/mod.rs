@@ -1,8 +1,11 @@
+pub mod campaign;
 pub mod cli;
 pub mod cmds;
 pub mod engine;
+pub mod events;
 pub mod logging;
 pub mod main_shared;
+pub mod queue;
 pub mod registry;
 pub mod runner;
 pub mod store;
@@ -0,0 +1,206 @@
+//! A queue of mutants tested one at a time by a single worker, shared by the long-running
+//! front ends (`serve`, `lsp`) so that requests from any client never race on a target file.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use log::{error, warn};
+use tokio::sync::Notify;
+
+use crate::LanguageRegistry;
+use crate::SqlStore;
+use crate::core::campaign::EventHandler;
+use crate::core::runner::TestRunner;
+use crate::types::config::Config;
+use crate::types::{AppResult, Mutant, Target};
+
+/// Mutants waiting for the worker and the one being tested
+#[derive(Debug, Default)]
+struct Queue {
+    pending: VecDeque<i64>,
+    active: Option<i64>,
+}
+
+pub struct TestQueue {
+    store: SqlStore,
+    registry: Arc<LanguageRegistry>,
+    config: Config,
+    test_cmd: Option<String>,
+    test_timeout: Option<u32>,
+    verbose: bool,
+    queue: Mutex<Queue>,
+    /// Wakes the worker when mutants are queued or the queue stops
+    wake: Notify,
+    /// Cleared to interrupt the worker's current test run
+    testing: Arc<AtomicBool>,
+    stopping: AtomicBool,
+    events: EventHandler,
+}
+
+impl TestQueue {
+    /// `test_cmd` and `test_timeout` override the config as they do for `test`
+    pub fn new(
+        store: SqlStore,
+        registry: Arc<LanguageRegistry>,
+        config: Config,
+        test_cmd: Option<String>,
+        test_timeout: Option<u32>,
+        verbose: bool,
+        events: EventHandler,
+    ) -> Self {
+        Self {
+            store,
+            registry,
+            config,
+            test_cmd,
+            test_timeout,
+            verbose,
+            queue: Mutex::new(Queue::default()),
+            wake: Notify::new(),
+            testing: Arc::new(AtomicBool::new(true)),
+            stopping: AtomicBool::new(false),
+            events,
+        }
+    }
+
+    /// Queue mutants that aren't already waiting or being tested; returns how many were added
+    pub fn push(&self, ids: impl IntoIterator<Item = i64>) -> usize {
+        let mut queue = self.queue.lock().unwrap();
+        let mut queued = 0;
+        for id in ids {
+            if queue.active != Some(id) && !queue.pending.contains(&id) {
+                queue.pending.push_back(id);
+                queued += 1;
+            }
+        }
+        self.wake.notify_one();
+        queued
+    }
+
+    /// The mutant being tested and the ones waiting, in order
+    pub fn snapshot(&self) -> (Option<i64>, Vec<i64>) {
+        let queue = self.queue.lock().unwrap();
+        (queue.active, queue.pending.iter().copied().collect())
+    }
+
+    /// Drop the waiting mutants and interrupt the running test. Returns the number of
+    /// mutants dropped and the one interrupted.
+    pub fn cancel(&self) -> (usize, Option<i64>) {
+        let mut queue = self.queue.lock().unwrap();
+        let cancelled = queue.pending.len();
+        queue.pending.clear();
+        // Stops a baseline or mutant test in progress; the worker restores the target and
+        // re-arms the flag for the next batch
+        self.testing.store(false, Ordering::SeqCst);
+        (cancelled, queue.active)
+    }
+
+    /// Interrupt the current test (its target is restored) and let the worker return
+    pub fn stop(&self) {
+        self.stopping.store(true, Ordering::SeqCst);
+        self.testing.store(false, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    /// Test queued mutants until stopped. Each batch is grouped by test command so the
+    /// baseline runs once per command.
+    pub async fn work(self: Arc<Self>) {
+        loop {
+            // Mutants stay pending (and cancellable) until their test starts
+            let batch: Vec<i64> = self.queue.lock().unwrap().pending.iter().copied().collect();
+            if self.stopping.load(Ordering::SeqCst) {
+                break;
+            }
+            if batch.is_empty() {
+                self.wake.notified().await;
+                continue;
+            }
+            self.testing.store(true, Ordering::SeqCst);
+            let mut started = HashSet::new();
+            if let Err(e) = self.test_batch(&batch, &mut started).await {
+                error!("Failed to test queued mutants: {e}");
+            }
+
+            // Drop the mutants that couldn't be tested (no test command, failed baseline)
+            let mut queue = self.queue.lock().unwrap();
+            queue.active = None;
+            queue
+                .pending
+                .retain(|id| started.contains(id) || !batch.contains(id));
+        }
+    }
+
+    /// Take `id` off the queue as its test starts, unless it was cancelled
+    fn start_test(&self, id: i64, started: &mut HashSet<i64>) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        let Some(position) = queue.pending.iter().position(|&p| p == id) else {
+            return false;
+        };
+        queue.pending.remove(position);
+        queue.active = Some(id);
+        started.insert(id);
+        true
+    }
+
+    async fn test_batch(&self, ids: &[i64], started: &mut HashSet<i64>) -> AppResult<()> {
+        // Mutants grouped by resolved (test_cmd, timeout)
+        type Groups = HashMap<(String, Option<u32>), Vec<(Mutant, Target)>>;
+        let mut groups = Groups::new();
+        for &id in ids {
+            let mutant = self.store.get_mutant(id).await?;
+            let target = self.store.get_target(mutant.target_id).await?;
+            let (cmd, timeout) = self.config.resolve_test_for_path(
+                &target.path,
+                self.test_cmd.as_deref(),
+                self.test_timeout,
+            );
+            match cmd {
+                Some(cmd) => groups
+                    .entry((cmd, timeout))
+                    .or_default()
+                    .push((mutant, target)),
+                None => warn!("No test command provided for target {}", target.display()),
+            }
+        }
+
+        for ((cmd, timeout), mutants) in groups {
+            if !self.testing.load(Ordering::SeqCst) {
+                break;
+            }
+            let mut runner = match TestRunner::new_with_baseline(
+                cmd,
+                timeout,
+                Arc::clone(&self.testing),
+                self.store.clone(),
+                false,
+                self.verbose,
+                Arc::clone(&self.registry),
+                self.config.clone(),
+                Some(Arc::clone(&self.events)),
+            )
+            .await
+            {
+                Ok(runner) => runner,
+                Err(e) => {
+                    error!("Skipping {} queued mutant(s): {e}", mutants.len());
+                    continue;
+                }
+            };
+            for (mutant, target) in mutants {
+                if !self.testing.load(Ordering::SeqCst) {
+                    break;
+                }
+                let id = mutant.id;
+                if !self.start_test(id, started) {
+                    continue;
+                }
+                let mut duration_ms = 0;
+                if let Err(e) = runner.test_mutant(target, mutant, &mut duration_ms).await {
+                    error!("Failed to test mutant {id}: {e}");
+                }
+            }
+        }
+        Ok(())
+    }
+}
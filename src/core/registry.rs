@@ -48,6 +48,19 @@ impl LanguageRegistry {
         self.engines.iter().map(|engine| engine.name()).collect()
     }
 
+    /// Get every mutation slug offered by any registered language, without duplicates
+    pub fn mutation_slugs(&self) -> Vec<&str> {
+        let mut slugs: Vec<&str> = Vec::new();
+        for engine in &self.engines {
+            for mutation in engine.get_mutations() {
+                if !slugs.contains(&mutation.slug) {
+                    slugs.push(mutation.slug);
+                }
+            }
+        }
+        slugs
+    }
+
     /// Parse source code with the appropriate language
     pub fn parse(&self, language_name: &str, source: &str) -> Option<Tree> {
         let engine = self.get_engine(language_name)?;
@@ -7,11 +7,13 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
-use crate::core::logging::{end_progress_bar, new_progress_bar};
+use crate::core::logging::{ActiveBar, end_progress_bar, new_progress_bar};
 use indicatif::{HumanDuration, ProgressBar};
 
 use crate::LanguageRegistry;
 use crate::SqlStore;
+use crate::core::campaign::{CampaignEvent, EventHandler, MutantResult, SkipReason};
+use crate::types::config::{Config, PerTargetRunRule};
 use crate::types::{CampaignSummary, Mutant, MutationSeverity, Outcome, Status, Target};
 
 pub struct TestRunner {
@@ -22,6 +24,10 @@ pub struct TestRunner {
     running: Arc<AtomicBool>,
     store: SqlStore,
     registry: Arc<LanguageRegistry>,
+    // Per-target run rules come from here
+    config: Config,
+    // Receives progress events, if anyone listens
+    events: Option<EventHandler>,
     // Track if we've applied mutations that need to be cleaned up
     has_active_mutation: bool,
     // Hold the current target for cleanup
@@ -32,9 +38,14 @@ pub struct TestRunner {
     uncaught_med_sev_lines: std::collections::HashSet<u32>,
     // Campaign-wide progress bar to track all mutants across all targets
     campaign_bar: Option<ProgressBar>,
+    // Where the campaign bar is registered for logging; no bar is drawn without it
+    active_bar: Option<ActiveBar>,
+    // Whether each target seen so far changed on disk since its mutants were generated
+    stale_targets: std::collections::HashMap<i64, bool>,
 }
 
 impl TestRunner {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         test_cmd: String,
         timeout_secs: Option<u32>,
@@ -43,6 +54,8 @@ impl TestRunner {
         running: Arc<AtomicBool>,
         store: SqlStore,
         registry: Arc<LanguageRegistry>,
+        config: Config,
+        events: Option<EventHandler>,
     ) -> Self {
         Self {
             test_cmd,
@@ -52,16 +65,55 @@ impl TestRunner {
             running,
             store,
             registry,
+            config,
+            events,
             has_active_mutation: false,
             current_target: None,
             uncaught_high_sev_lines: std::collections::HashSet::new(),
             uncaught_med_sev_lines: std::collections::HashSet::new(),
             campaign_bar: None,
+            active_bar: None,
+            stale_targets: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Draw a campaign-wide progress bar, printing log lines above it through `active`
+    pub fn show_progress(&mut self, active: ActiveBar) {
+        self.active_bar = Some(active);
+    }
+
+    /// Whether `target` changed on disk since generation, read and hashed once per target
+    fn is_stale(&mut self, target: &Target) -> bool {
+        *self
+            .stale_targets
+            .entry(target.id)
+            .or_insert_with(|| target.is_stale())
+    }
+
+    /// Severity of a mutation slug, taking the target's [[run.per_target]] override into account
+    fn severity(
+        &self,
+        language: &str,
+        rule: Option<&PerTargetRunRule>,
+        slug: &str,
+    ) -> Option<MutationSeverity> {
+        rule.and_then(|rule| rule.severity(slug)).or_else(|| {
+            self.registry
+                .get_engine(language)
+                .unwrap()
+                .get_severity_by_slug(slug)
+        })
+    }
+
+    fn emit(&self, event: CampaignEvent) {
+        if let Some(events) = &self.events {
+            events(&event);
         }
     }
 
     /// Creates a TestRunner, runs baseline tests, and configures timeout settings
     /// Returns a properly configured TestRunner ready for mutation testing
+    #[allow(clippy::too_many_arguments)]
     pub async fn new_with_baseline(
         test_cmd: String,
         user_timeout: Option<u32>,
@@ -70,6 +122,8 @@ impl TestRunner {
         comprehensive: bool,
         verbose: bool,
         registry: Arc<LanguageRegistry>,
+        config: Config,
+        events: Option<EventHandler>,
     ) -> Result<Self, io::Error> {
         // Create initial runner for baseline tests (no timeout)
         let mut runner = Self::new(
@@ -80,9 +134,14 @@ impl TestRunner {
             Arc::clone(&running),
             store.clone(),
             Arc::clone(&registry),
+            config.clone(),
+            events.clone(),
         );
 
         // Run baseline tests
+        runner.emit(CampaignEvent::BaselineStarted {
+            test_cmd: test_cmd.clone(),
+        });
         let baseline_duration_ms = runner.run_baseline_test().await?;
         let baseline_duration_secs = baseline_duration_ms.div_ceil(1000);
         let recommended_timeout = baseline_duration_secs * 2;
@@ -119,6 +178,12 @@ impl TestRunner {
             }
         };
 
+        runner.emit(CampaignEvent::BaselinePassed {
+            test_cmd: test_cmd.clone(),
+            duration_ms: baseline_duration_ms,
+            timeout_secs: actual_timeout,
+        });
+
         // Create a new TestRunner with the determined timeout for mutation tests
         Ok(Self::new(
             test_cmd,
@@ -128,6 +193,8 @@ impl TestRunner {
             running,
             store,
             registry,
+            config,
+            events,
         ))
     }
 
@@ -144,6 +211,10 @@ impl TestRunner {
         let duration_ms = start.elapsed().as_millis() as u32;
 
         if status != Status::Uncaught {
+            self.emit(CampaignEvent::BaselineFailed {
+                test_cmd: self.test_cmd.clone(),
+                duration_ms,
+            });
             error!("Baseline test failed! Fix your tests before running mutation testing.");
             if !self.verbose {
                 error!("Test output:\n{output}");
@@ -219,13 +290,23 @@ impl TestRunner {
             "Estimated maximum completion time: {}",
             HumanDuration(estimated_total_duration)
         );
+        self.emit(CampaignEvent::CampaignStarted {
+            test_cmd: self.test_cmd.clone(),
+            targets: targets.len(),
+            untested: campaign_untested_count,
+            retest: campaign_retest_count,
+            timeout_secs: timeout_secs as u32,
+        });
 
         // Create a single campaign-wide progress bar that tracks all mutants
-        if total_untested_mutants > 1 {
-            self.campaign_bar = Some(new_progress_bar(
-                total_untested_mutants as u64,
-                "Preparing mutation campaign...",
-            ));
+        if let Some(active) = &self.active_bar {
+            if total_untested_mutants > 1 {
+                self.campaign_bar = Some(new_progress_bar(
+                    active,
+                    total_untested_mutants as u64,
+                    "Preparing mutation campaign...",
+                ));
+            }
         }
 
         let campaign_start = Instant::now();
@@ -241,8 +322,8 @@ impl TestRunner {
         }
 
         // Finish and clear the campaign progress bar if it exists
-        if let Some(bar) = &self.campaign_bar {
-            end_progress_bar(bar);
+        if let (Some(active), Some(bar)) = (&self.active_bar, &self.campaign_bar) {
+            end_progress_bar(active, bar);
         }
         self.campaign_bar = None;
 
@@ -316,6 +397,13 @@ impl TestRunner {
     ) -> io::Result<()> {
         info!("");
         info!("Processing target: {}", target.display());
+        let stale = self.is_stale(&target);
+        if stale {
+            warn!(
+                "Skipping {}: it changed since its mutants were generated (run `clean` to drop stale targets)",
+                target.display()
+            );
+        }
         self.current_target = Some(target.clone());
         // Clear any tracked uncaught lines from previous targets
         self.uncaught_high_sev_lines.clear();
@@ -331,24 +419,13 @@ impl TestRunner {
         };
 
         let language = &target.language;
+        let rule = self.config.run_rule_for_path(&target.path);
 
         // Sort mutants by severity (High, Medium, Low)
-        mutants.sort_by(|a, b| {
-            let a_sev = self
-                .registry
-                .get_engine(language)
-                .unwrap()
-                .get_severity_by_slug(&a.mutation_slug)
-                .map(|s| s.to_numeric())
-                .unwrap_or(2); // Default to Low severity if not found
-            let b_sev = self
-                .registry
-                .get_engine(language)
-                .unwrap()
-                .get_severity_by_slug(&b.mutation_slug)
+        mutants.sort_by_cached_key(|m| {
+            self.severity(language, rule.as_ref(), &m.mutation_slug)
                 .map(|s| s.to_numeric())
-                .unwrap_or(2); // Default to Low severity if not found
-            a_sev.cmp(&b_sev)
+                .unwrap_or(2) // Default to Low severity if not found
         });
 
         let mut count = 1;
@@ -400,6 +477,10 @@ impl TestRunner {
             );
         }
 
+        self.emit(CampaignEvent::TargetStarted {
+            target: target.path.clone(),
+            mutants: total_untested,
+        });
         let target_start = Instant::now();
         let mut target_duration_ms = 0;
 
@@ -425,14 +506,27 @@ impl TestRunner {
                 }
             }
 
+            // Writing a mutant of stale text would clobber the newer file when it's restored
+            if stale {
+                self.emit(CampaignEvent::MutantSkipped {
+                    target: target.path.clone(),
+                    mutant_id: mutant.id,
+                    mutation_slug: mutant.mutation_slug.clone(),
+                    line: mutant.get_lines().0,
+                    reason: SkipReason::TargetChanged,
+                });
+                skipped += 1;
+                if let Some(bar) = &self.campaign_bar {
+                    bar.inc(1);
+                }
+                continue;
+            }
+
             // Skip less severe mutations if more severe ones on the same line were uncaught
             // and comprehensive mode is not enabled
             if !self.comprehensive {
                 let severity = self
-                    .registry
-                    .get_engine(language)
-                    .unwrap()
-                    .get_severity_by_slug(&mutant.mutation_slug)
+                    .severity(language, rule.as_ref(), &mutant.mutation_slug)
                     .ok_or_else(|| {
                         io::Error::new(
                             io::ErrorKind::NotFound,
@@ -480,6 +574,13 @@ impl TestRunner {
                             mutant.id, e
                         );
                     }
+                    self.emit(CampaignEvent::MutantSkipped {
+                        target: target.path.clone(),
+                        mutant_id: mutant.id,
+                        mutation_slug: mutant.mutation_slug.clone(),
+                        line: line_start,
+                        reason: SkipReason::UncaughtHigherSeverity,
+                    });
 
                     skipped += 1;
                     if let Some(bar) = &self.campaign_bar {
@@ -495,6 +596,13 @@ impl TestRunner {
                     // Check if the mutant's slug is in our allowed list
                     if !slugs.iter().any(|s| s == &mutant.mutation_slug) {
                         // Skip this mutant as its slug is not in our allowed list
+                        self.emit(CampaignEvent::MutantSkipped {
+                            target: target.path.clone(),
+                            mutant_id: mutant.id,
+                            mutation_slug: mutant.mutation_slug.clone(),
+                            line: mutant.get_lines().0,
+                            reason: SkipReason::MutationFiltered,
+                        });
                         skipped += 1;
                         if let Some(bar) = &self.campaign_bar {
                             bar.inc(1);
@@ -519,6 +627,12 @@ impl TestRunner {
                 total_untested - skipped,
                 mutant.display(&target)
             );
+            self.emit(CampaignEvent::MutantStarted {
+                target: target.path.clone(),
+                mutant_id: mutant.id,
+                mutation_slug: mutant.mutation_slug.clone(),
+                line: mutant.get_lines().0,
+            });
             self.test_mutant(target.clone(), mutant, &mut target_duration_ms)
                 .await?;
             count += 1;
@@ -537,6 +651,10 @@ impl TestRunner {
             "Finished testing target in {}",
             HumanDuration(target_elapsed),
         );
+        self.emit(CampaignEvent::TargetFinished {
+            target: target.path.clone(),
+            duration_ms: target_elapsed.as_millis() as u32,
+        });
         info!("");
 
         // Clear current target after processing
@@ -551,6 +669,23 @@ impl TestRunner {
         mutant: Mutant,
         target_duration_ms: &mut u32,
     ) -> io::Result<()> {
+        // Writing a mutant of stale text would clobber the newer file when it's restored
+        if self.is_stale(&target) {
+            warn!(
+                "Skipping mutant {}: {} changed since its mutants were generated (run `clean` to drop stale targets)",
+                mutant.id,
+                target.display()
+            );
+            self.emit(CampaignEvent::MutantSkipped {
+                target: target.path.clone(),
+                mutant_id: mutant.id,
+                mutation_slug: mutant.mutation_slug.clone(),
+                line: mutant.get_lines().0,
+                reason: SkipReason::TargetChanged,
+            });
+            return Ok(());
+        }
+
         // Apply the mutation
         let mutated_target = target.mutate(&mutant)?;
         self.has_active_mutation = true;
@@ -580,11 +715,9 @@ impl TestRunner {
         // track the affected lines so we can skip lower severity mutants on those lines
         if status == Status::Uncaught {
             let language = &target.language;
+            let rule = self.config.run_rule_for_path(&target.path);
             let severity = self
-                .registry
-                .get_engine(language)
-                .unwrap()
-                .get_severity_by_slug(&mutant.mutation_slug)
+                .severity(language, rule.as_ref(), &mutant.mutation_slug)
                 .ok_or_else(|| {
                     io::Error::new(
                         io::ErrorKind::NotFound,
@@ -611,6 +744,15 @@ impl TestRunner {
             }
         }
 
+        let result = MutantResult {
+            target: target.path.clone(),
+            mutant_id: mutant.id,
+            mutation_slug: mutant.mutation_slug.clone(),
+            line: mutant.get_lines().0,
+            status: status.clone(),
+            duration_ms,
+        };
+
         // Create outcome
         let outcome = Outcome {
             mutant_id: mutant.id,
@@ -631,6 +773,7 @@ impl TestRunner {
         // Restore original file
         target.restore()?;
         self.has_active_mutation = false;
+        self.emit(CampaignEvent::MutantTested(result));
 
         Ok(())
     }
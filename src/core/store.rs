@@ -1,14 +1,26 @@
 use chrono::{DateTime, Utc};
-use sqlx::sqlite::{Sqlite, SqlitePool};
+use sqlx::sqlite::{Sqlite, SqliteConnection, SqlitePool};
 use sqlx::{QueryBuilder, Row};
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
+use crate::types::config::{path_glob, relative_path};
 use crate::types::{
     CampaignSeverityStats, CampaignSummary, Hash, Mutant, Outcome, Status, StoreError, StoreResult,
     Target, TargetStats,
 };
 
+/// Number of mutants written per transaction by `add_mutants`
+const MUTANT_BATCH_SIZE: usize = 500;
+
+/// `path` canonicalized, or joined onto `cwd` when it no longer exists on disk
+fn absolute_path(cwd: Option<&Path>, path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| match cwd {
+        Some(cwd) if path.is_relative() => cwd.join(path),
+        _ => path.to_path_buf(),
+    })
+}
+
 #[derive(Clone, Debug)]
 pub struct SqlStore {
     pool: SqlitePool,
@@ -22,6 +34,21 @@ impl SqlStore {
     }
 
     pub async fn add_target(&self, target: Target) -> StoreResult<i64> {
+        let mut conn = self.pool.acquire().await?;
+        Self::upsert_target(&mut conn, &target).await
+    }
+
+    /// Store many targets in a single transaction, filling in each target's id
+    pub async fn add_targets(&self, targets: &mut [Target]) -> StoreResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for target in targets.iter_mut() {
+            target.id = Self::upsert_target(&mut tx, target).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn upsert_target(conn: &mut SqliteConnection, target: &Target) -> StoreResult<i64> {
         // Get language string
         let language_str = &target.language;
 
@@ -35,7 +62,7 @@ impl SqlStore {
         "#,
             file_hash_hex
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await?;
         match existing {
             // got an exact match
@@ -51,7 +78,7 @@ impl SqlStore {
                     path_str,
                     record.id
                 )
-                .execute(&self.pool)
+                .execute(&mut *conn)
                 .await?;
                 Ok(record.id)
             }
@@ -67,7 +94,7 @@ impl SqlStore {
                     target.text,
                     language_str
                 )
-                .execute(&self.pool)
+                .execute(&mut *conn)
                 .await?;
                 Ok(result.last_insert_rowid())
             }
@@ -77,6 +104,28 @@ impl SqlStore {
     // returns None if noop bc mutant already exists
     // otherwise returns the newly added mutant id
     pub async fn add_mutant(&self, mutant: Mutant) -> StoreResult<Option<i64>> {
+        let mut conn = self.pool.acquire().await?;
+        Self::insert_mutant(&mut conn, &mutant).await
+    }
+
+    /// Store many mutants in batched transactions.
+    /// Returns one entry per input mutant, None where it already existed.
+    pub async fn add_mutants(&self, mutants: &[Mutant]) -> StoreResult<Vec<Option<i64>>> {
+        let mut ids = Vec::with_capacity(mutants.len());
+        for batch in mutants.chunks(MUTANT_BATCH_SIZE) {
+            let mut tx = self.pool.begin().await?;
+            for mutant in batch {
+                ids.push(Self::insert_mutant(&mut tx, mutant).await?);
+            }
+            tx.commit().await?;
+        }
+        Ok(ids)
+    }
+
+    async fn insert_mutant(
+        conn: &mut SqliteConnection,
+        mutant: &Mutant,
+    ) -> StoreResult<Option<i64>> {
         let existing = sqlx::query!(
             r#"
             SELECT id
@@ -89,7 +138,7 @@ impl SqlStore {
             mutant.new_text,
             mutant.mutation_slug,
         )
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *conn)
         .await?;
         match existing {
             Some(_) => Ok(None),
@@ -106,7 +155,7 @@ impl SqlStore {
                     mutant.new_text,
                     mutant.mutation_slug,
                 )
-                .execute(&self.pool)
+                .execute(&mut *conn)
                 .await?;
                 Ok(Some(result.last_insert_rowid()))
             }
@@ -216,48 +265,44 @@ impl SqlStore {
     }
 
     /// Get target IDs that match a glob pattern (file, dir, or glob) against database targets.
+    /// Globs are matched against target paths both as stored and relative to the current
+    /// directory, with `*` stopping at `/`.
     /// Returns None if no pattern provided (match all targets).
     /// Returns Some(vec![...]) with matching target IDs if pattern provided.
     pub async fn match_target_ids(&self, pattern: Option<String>) -> StoreResult<Option<Vec<i64>>> {
-        match pattern {
-            None => Ok(None), // No filter
-            Some(pattern) => {
-                let all_targets = self.get_all_targets().await?;
-                let path = PathBuf::from(&pattern);
-
-                let matching_ids: Vec<i64> = if path.exists() && path.is_file() {
-                    // Direct file match
-                    all_targets
-                        .iter()
-                        .filter(|t| t.path == path)
-                        .map(|t| t.id)
-                        .collect()
-                } else if path.exists() && path.is_dir() {
-                    // Directory match - all targets under this dir
+        let Some(pattern) = pattern else {
+            return Ok(None); // No filter
+        };
+        let all_targets = self.get_all_targets().await?;
+        let cwd = std::env::current_dir().ok();
+
+        let matching_ids: Vec<i64> = if let Ok(path) = Path::new(&pattern).canonicalize() {
+            // Target paths are stored as given, often relative to the working directory,
+            // so canonicalize both sides. A file matches itself and a directory all
+            // targets under it.
+            all_targets
+                .iter()
+                .filter(|t| absolute_path(cwd.as_deref(), &t.path).starts_with(&path))
+                .map(|t| t.id)
+                .collect()
+        } else {
+            match path_glob(pattern.trim_start_matches("./")) {
+                Ok(glob) => {
+                    let glob = glob.compile_matcher();
                     all_targets
                         .iter()
-                        .filter(|t| t.path.starts_with(&path))
+                        .filter(|t| {
+                            glob.is_match(&t.path)
+                                || glob.is_match(relative_path(cwd.as_deref(), &t.path))
+                        })
                         .map(|t| t.id)
                         .collect()
-                } else {
-                    // Try as glob pattern
-                    match glob::glob(&pattern) {
-                        Ok(paths) => {
-                            let glob_paths: HashSet<PathBuf> =
-                                paths.filter_map(Result::ok).collect();
-                            all_targets
-                                .iter()
-                                .filter(|t| glob_paths.contains(&t.path))
-                                .map(|t| t.id)
-                                .collect()
-                        }
-                        Err(_) => vec![], // Invalid glob, no matches
-                    }
-                };
-
-                Ok(Some(matching_ids))
+                }
+                Err(_) => vec![], // Invalid glob, no matches
             }
-        }
+        };
+
+        Ok(Some(matching_ids))
     }
 
     pub async fn get_mutant(&self, id: i64) -> StoreResult<Mutant> {
@@ -738,6 +783,7 @@ impl SqlStore {
         let mut uncaught = 0;
         let mut timeout = 0;
         let mut skipped = 0;
+        let mut equivalent = 0;
 
         // Track severity stats: (eligible, caught) per severity
         let mut severity_stats: HashMap<String, (usize, usize)> = HashMap::new();
@@ -754,10 +800,11 @@ impl SqlStore {
                 }
                 Status::Timeout => timeout += 1,
                 Status::Skipped => skipped += 1,
+                Status::Equivalent => equivalent += 1,
             }
         }
 
-        let untested = total_mutants - tested - timeout - skipped;
+        let untested = total_mutants - tested - timeout - skipped - equivalent;
 
         // For severity stats, we need to join with mutants to get mutation_slug
         // This will be computed from the database in a separate query
@@ -789,6 +836,7 @@ impl SqlStore {
             uncaught,
             timeout,
             skipped,
+            equivalent,
             severity_stats,
         })
     }
@@ -819,3 +867,86 @@ impl SqlStore {
         Ok(CampaignSeverityStats { severity_stats })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn targets_match_files_directories_and_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let store = SqlStore::new(format!("sqlite:{}?mode=rwc", root.join("db").display()))
+            .await
+            .unwrap();
+        let mut targets: Vec<Target> = ["src/lib.rs", "src/core/mod.rs"]
+            .iter()
+            .map(|name| {
+                let path = root.join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                // Targets are keyed by hash, so each needs its own contents
+                std::fs::write(&path, name).unwrap();
+                Target {
+                    id: 0,
+                    path,
+                    file_hash: Hash::digest(name.to_string()),
+                    text: name.to_string(),
+                    language: "Rust".to_string(),
+                }
+            })
+            .collect();
+        store.add_targets(&mut targets).await.unwrap();
+        let (lib, core) = (targets[0].id, targets[1].id);
+
+        let matches = |pattern: std::path::PathBuf| {
+            let store = store.clone();
+            async move {
+                store
+                    .match_target_ids(Some(pattern.display().to_string()))
+                    .await
+                    .unwrap()
+                    .unwrap()
+            }
+        };
+        assert_eq!(matches(root.join("src/lib.rs")).await, vec![lib]);
+        assert_eq!(matches(root.join("src")).await, vec![lib, core]);
+        // `*` stays within one directory
+        assert_eq!(matches(root.join("src/*.rs")).await, vec![lib]);
+        assert_eq!(matches(root.join("src/**/*.rs")).await, vec![lib, core]);
+        assert_eq!(matches(root.join("*.py")).await, Vec::<i64>::new());
+
+        // `mewt mutate src` stores paths as given, relative to the working directory
+        let cwd_dir = tempfile::tempdir_in(".").unwrap();
+        let rel = PathBuf::from(cwd_dir.path().file_name().unwrap());
+        let mut targets: Vec<Target> = ["app/main.rs", "app/util/mod.rs"]
+            .iter()
+            .map(|name| {
+                let path = rel.join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, name).unwrap();
+                Target {
+                    id: 0,
+                    path,
+                    file_hash: Hash::digest(format!("relative {name}")),
+                    text: name.to_string(),
+                    language: "Rust".to_string(),
+                }
+            })
+            .collect();
+        store.add_targets(&mut targets).await.unwrap();
+        let (main, util) = (targets[0].id, targets[1].id);
+
+        assert_eq!(matches(rel.join("app/main.rs")).await, vec![main]);
+        assert_eq!(matches(rel.join("app")).await, vec![main, util]);
+        assert_eq!(
+            matches(PathBuf::from(".").join(&rel).join("app")).await,
+            vec![main, util]
+        );
+        assert_eq!(
+            matches(cwd_dir.path().canonicalize().unwrap().join("app/main.rs")).await,
+            vec![main]
+        );
+        assert_eq!(matches(rel.join("app/*.rs")).await, vec![main]);
+        assert_eq!(matches(rel.join("app/**/*.rs")).await, vec![main, util]);
+    }
+}
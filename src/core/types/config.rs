@@ -1,8 +1,12 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
-use once_cell::sync::OnceCell;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::types::MutationSeverity;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct LogConfig {
@@ -71,14 +75,66 @@ impl TestConfig {
 pub struct TargetsConfig {
     /// Glob patterns for target inclusion (e.g., "src/**/*.rs")
     pub include: Option<Vec<String>>,
-    /// Substrings for path exclusion (e.g., "node_modules")
+    /// Glob patterns for path exclusion (e.g., "node_modules", "src/**/*_generated.rs")
     pub ignore: Option<Vec<String>>,
+    /// Skip files listed in .gitignore, .ignore and .mewtignore files (None = true)
+    pub respect_ignore_files: Option<bool>,
+}
+
+impl TargetsConfig {
+    pub fn respect_ignore_files(&self) -> bool {
+        self.respect_ignore_files.unwrap_or(true)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ResolvedTargets {
     pub include: Vec<String>,
     pub ignore: Vec<String>,
+    pub respect_ignore_files: bool,
+    /// Tool-specific ignore file honoured next to .gitignore, e.g. `.mewtignore`
+    pub ignore_filename: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct PerTargetRunRule {
+    pub glob: String,
+    /// Whitelist of mutation slugs for matching targets (None = all enabled)
+    pub mutations: Option<Vec<String>>,
+    /// Mutation slugs never generated for matching targets
+    pub exclude_mutations: Option<Vec<String>>,
+    /// Severity overrides by slug, e.g. `{ AOS = "High" }`
+    pub severity: Option<BTreeMap<String, MutationSeverity>>,
+}
+
+impl PerTargetRunRule {
+    /// Narrow the enabled slugs (None = all of `all_slugs`) by this rule's whitelist and blacklist
+    pub fn select_mutations(
+        &self,
+        enabled: Option<&[String]>,
+        all_slugs: &[String],
+    ) -> Option<Vec<String>> {
+        if self.mutations.is_none() && self.exclude_mutations.is_none() {
+            return enabled.map(<[String]>::to_vec);
+        }
+        let selected = enabled
+            .unwrap_or(all_slugs)
+            .iter()
+            .filter(|slug| is_slug_enabled(slug, self.mutations.as_deref()))
+            .filter(|slug| {
+                !self
+                    .exclude_mutations
+                    .as_ref()
+                    .is_some_and(|excluded| excluded.contains(slug))
+            })
+            .cloned()
+            .collect();
+        Some(selected)
+    }
+
+    pub fn severity(&self, slug: &str) -> Option<MutationSeverity> {
+        self.severity.as_ref()?.get(slug).cloned()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -86,6 +142,21 @@ pub struct RunConfig {
     /// Whitelist specific mutation types by slug (None = all enabled)
     pub mutations: Option<Vec<String>>,
     pub comprehensive: Option<bool>,
+    pub per_target: Option<Vec<PerTargetRunRule>>, // ordered, first match wins
+}
+
+impl RunConfig {
+    pub fn per_target(&self) -> &[PerTargetRunRule] {
+        self.per_target.as_deref().unwrap_or(&[])
+    }
+}
+
+/// A named `[profile.<name>]` section, overlaid on the base config when selected
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct ProfileConfig {
+    pub test: Option<TestConfig>,
+    pub targets: Option<TargetsConfig>,
+    pub run: Option<RunConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -98,13 +169,93 @@ pub struct Config {
     pub test: Option<TestConfig>,
     pub targets: Option<TargetsConfig>,
     pub run: Option<RunConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<BTreeMap<String, ProfileConfig>>,
+
+    /// Name of the profile applied by `--profile`, if any
+    #[serde(skip_deserializing)]
+    pub active_profile: Option<String>,
+
+    /// Where each key's value came from; keys not listed come from the defaults
+    #[serde(skip_deserializing, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<&'static str, ValueSource>,
+
+    /// Tool name that config, database and ignore file names derive from (None = `mewt`)
+    #[serde(skip)]
+    pub namespace: Option<String>,
+}
+
+/// Dotted names of the config keys whose source is tracked
+pub const CONFIG_KEYS: &[&str] = &[
+    "db",
+    "log.level",
+    "log.color",
+    "targets.include",
+    "targets.ignore",
+    "targets.respect_ignore_files",
+    "run.mutations",
+    "run.comprehensive",
+    "run.per_target",
+    "test.cmd",
+    "test.timeout",
+    "test.per_target",
+];
+
+/// The configuration layer an effective value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueSource {
+    Default,
+    File(PathBuf),
+    Profile(String),
+    Env(String),
+    Cli(String),
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueSource::Default => write!(f, "default"),
+            ValueSource::File(path) => write!(f, "file {}", path.display()),
+            ValueSource::Profile(name) => write!(f, "profile {name}"),
+            ValueSource::Env(var) => write!(f, "env {var}"),
+            ValueSource::Cli(flag) => write!(f, "cli {flag}"),
+        }
+    }
+}
+
+impl Serialize for ValueSource {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
 impl Config {
+    /// An empty config for the tool called `namespace`
+    pub fn for_namespace(namespace: &str) -> Self {
+        Self {
+            namespace: Some(namespace.to_string()),
+            ..Self::default()
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        self.namespace.as_deref().unwrap_or(DEFAULT_NAMESPACE)
+    }
+
+    /// Name of the config file, e.g. `mewt.toml`
+    pub fn config_filename(&self) -> String {
+        format!("{}.toml", self.namespace())
+    }
+
+    /// Name of the tool-specific ignore file, e.g. `.mewtignore`
+    pub fn ignore_filename(&self) -> String {
+        format!(".{}ignore", self.namespace())
+    }
+
     pub fn db(&self) -> String {
         self.db
             .clone()
-            .unwrap_or_else(|| format!("{}.sqlite", get_namespace()))
+            .unwrap_or_else(|| format!("{}.sqlite", self.namespace()))
     }
 
     pub fn log(&self) -> LogConfig {
@@ -123,11 +274,55 @@ impl Config {
         self.run.as_ref()
     }
 
-    /// Resolve target configuration with CLI overrides (complete replacement)
+    /// Where the effective value of a key (one of [`CONFIG_KEYS`]) came from
+    pub fn source(&self, key: &str) -> ValueSource {
+        self.sources
+            .get(key)
+            .cloned()
+            .unwrap_or(ValueSource::Default)
+    }
+
+    /// Keys of [`CONFIG_KEYS`] that this config sets explicitly
+    fn keys_set(&self) -> Vec<&'static str> {
+        let log = self.log.as_ref();
+        let targets = self.targets.as_ref();
+        let run = self.run.as_ref();
+        let test = self.test.as_ref();
+        let set = [
+            self.db.is_some(),
+            log.is_some_and(|l| l.level.is_some()),
+            log.is_some_and(|l| l.color.is_some()),
+            targets.is_some_and(|t| t.include.is_some()),
+            targets.is_some_and(|t| t.ignore.is_some()),
+            targets.is_some_and(|t| t.respect_ignore_files.is_some()),
+            run.is_some_and(|r| r.mutations.is_some()),
+            run.is_some_and(|r| r.comprehensive.is_some()),
+            run.is_some_and(|r| r.per_target.is_some()),
+            test.is_some_and(|t| t.cmd.is_some()),
+            test.is_some_and(|t| t.timeout.is_some()),
+            test.is_some_and(|t| t.per_target.is_some()),
+        ];
+        CONFIG_KEYS
+            .iter()
+            .zip(set)
+            .filter_map(|(key, set)| set.then_some(*key))
+            .collect()
+    }
+
+    /// Record `source` for every key that `layer` sets
+    fn record_sources(&mut self, layer: &Config, source: impl Fn(&str) -> ValueSource) {
+        for key in layer.keys_set() {
+            self.sources.insert(key, source(key));
+        }
+    }
+
+    /// Resolve target configuration with CLI overrides (complete replacement).
+    /// `cli_no_ignore` disables ignore files regardless of config.
     pub fn resolve_targets(
         &self,
         cli_targets: &[String],
         cli_ignore: Option<&str>,
+        cli_no_ignore: bool,
     ) -> std::io::Result<ResolvedTargets> {
         // CLI completely replaces config
         let include = if !cli_targets.is_empty() {
@@ -153,7 +348,15 @@ impl Config {
                 .unwrap_or_default()
         };
 
-        Ok(ResolvedTargets { include, ignore })
+        let respect_ignore_files =
+            !cli_no_ignore && self.targets().is_none_or(|t| t.respect_ignore_files());
+
+        Ok(ResolvedTargets {
+            include,
+            ignore,
+            respect_ignore_files,
+            ignore_filename: Some(self.ignore_filename()),
+        })
     }
 
     /// Resolve mutations with CLI override (complete replacement)
@@ -180,6 +383,48 @@ impl Config {
         cli_timeout.or_else(|| self.test().timeout())
     }
 
+    /// The first `[[run.per_target]]` rule whose glob matches `path`
+    pub fn run_rule_for_path(&self, path: &Path) -> Option<PerTargetRunRule> {
+        let run = self.run()?;
+        run.per_target()
+            .iter()
+            .find(|rule| glob_matches(&rule.glob, path))
+            .cloned()
+    }
+
+    /// Test command and timeout for `path`. An explicit `cmd_override` wins, then the first
+    /// matching `[[test.per_target]]` rule with a command, then `[test]`.
+    pub fn resolve_test_for_path(
+        &self,
+        path: &Path,
+        cmd_override: Option<&str>,
+        timeout_override: Option<u32>,
+    ) -> (Option<String>, Option<u32>) {
+        let test = self.test();
+
+        if let Some(cmd) = cmd_override {
+            if !cmd.trim().is_empty() {
+                return (Some(cmd.to_string()), timeout_override.or(test.timeout()));
+            }
+        }
+
+        // Per-target rules: first match wins
+        for rule in test.per_target() {
+            if glob_matches(&rule.glob, path) {
+                if let Some(cmd) = &rule.cmd {
+                    let timeout = timeout_override.or(rule.timeout).or(test.timeout());
+                    return (Some(cmd.clone()), timeout);
+                }
+            }
+        }
+
+        // Fallback to global
+        (
+            test.cmd().map(|s| s.to_string()),
+            timeout_override.or(test.timeout()),
+        )
+    }
+
     pub fn to_effective(&self) -> Self {
         Self {
             db: Some(self.db().to_string()),
@@ -187,6 +432,13 @@ impl Config {
             test: Some(self.test().to_effective()),
             targets: self.targets.clone(),
             run: self.run.clone(),
+            profile: None,
+            active_profile: self.active_profile.clone(),
+            sources: CONFIG_KEYS
+                .iter()
+                .map(|key| (*key, self.source(key)))
+                .collect(),
+            namespace: self.namespace.clone(),
         }
     }
 }
@@ -196,69 +448,496 @@ pub struct CliOverrides {
     pub db: Option<String>,
     pub log_level: Option<String>,
     pub log_color: Option<String>, // "on" | "off"
+    pub profile: Option<String>,
+}
+
+/// Namespace used when the binary doesn't set one, e.g. when mewt is used as a library
+pub const DEFAULT_NAMESPACE: &str = "mewt";
+
+/// Build the config for `namespace` from the nearest config file (plus the selected profile),
+/// environment variables and CLI overrides, in increasing order of precedence.
+/// The config file and environment are checked strictly against `known_slugs`; layers with
+/// problems are not applied and their problems are returned next to the config, so callers
+/// can report them and stop.
+pub fn load_with_overrides(
+    namespace: &str,
+    overrides: &CliOverrides,
+    known_slugs: &[&str],
+) -> (Config, Vec<ConfigIssue>) {
+    let mut cfg = Config::for_namespace(namespace);
+    let mut issues = Vec::new();
+
+    // 1) Config file: walk up from cwd and use the first config file found,
+    //    overlaid with the selected profile
+    let path = find_nearest_config_file(&cfg.config_filename());
+    match path.as_deref() {
+        Some(path) => match read_config_file(path, Some(known_slugs)) {
+            Ok(file_cfg) => {
+                apply_file_config(&mut cfg, &file_cfg, path);
+                if let Some(name) = &overrides.profile {
+                    match select_profile(&file_cfg, name) {
+                        Ok(profile) => apply_profile(&mut cfg, name, profile),
+                        Err(message) => issues.push(profile_issue(path, message)),
+                    }
+                }
+            }
+            Err(file_issues) => issues = file_issues,
+        },
+        None => {
+            if let Some(name) = &overrides.profile {
+                let message = format!("profile `{name}` selected but no config file was found");
+                issues.push(profile_issue(Path::new(&cfg.config_filename()), message));
+            }
+        }
+    }
+
+    // 2) Environment variables
+    let (env_cfg, env_issues) =
+        env_layer(namespace, |var| std::env::var(var).ok(), Some(known_slugs));
+    if env_issues.is_empty() {
+        apply_overlay(&mut cfg, &env_cfg);
+        cfg.record_sources(&env_cfg, |key| {
+            ValueSource::Env(env_var_name(namespace, key))
+        });
+    }
+    issues.extend(env_issues);
+
+    // 3) CLI arguments (highest priority). Only override if user specified.
+    apply_cli_overrides(&mut cfg, overrides);
+
+    (cfg, issues)
 }
 
-static NAMESPACE: OnceCell<String> = OnceCell::new();
-static CONFIG_FILENAME: OnceCell<String> = OnceCell::new();
-static CONFIG: OnceCell<Config> = OnceCell::new();
+/// Check the nearest config file for `namespace` and the environment, returning the file's
+/// path (if any) and every problem found. If `profile` is given, it must be defined in the file.
+pub fn check_nearest_config(
+    namespace: &str,
+    known_slugs: &[&str],
+    profile: Option<&str>,
+) -> (Option<PathBuf>, Vec<ConfigIssue>) {
+    let (_, env_issues) = env_layer(namespace, |var| std::env::var(var).ok(), Some(known_slugs));
+    let config_filename = Config::for_namespace(namespace).config_filename();
+    let Some(path) = find_nearest_config_file(&config_filename) else {
+        let mut issues: Vec<ConfigIssue> = profile
+            .map(|name| {
+                let message = format!("profile `{name}` selected but no config file was found");
+                profile_issue(Path::new(&config_filename), message)
+            })
+            .into_iter()
+            .collect();
+        issues.extend(env_issues);
+        return (None, issues);
+    };
+    let mut issues = match read_config_file(&path, Some(known_slugs)) {
+        Ok(file_cfg) => profile
+            .and_then(|name| select_profile(&file_cfg, name).err())
+            .map(|message| profile_issue(&path, message))
+            .into_iter()
+            .collect(),
+        Err(issues) => issues,
+    };
+    issues.extend(env_issues);
+    (Some(path), issues)
+}
+
+/// Name of the environment variable for a config key, e.g. `MEWT_TEST_CMD` for `test.cmd`
+pub fn env_var_name(namespace: &str, key: &str) -> String {
+    format!(
+        "{}_{}",
+        namespace.to_uppercase().replace('-', "_"),
+        key.replace('.', "_").to_uppercase()
+    )
+}
+
+/// Build a config layer from environment variables looked up with `var`.
+/// Empty variables are ignored; lists are comma-separated.
+fn env_layer(
+    namespace: &str,
+    var: impl Fn(&str) -> Option<String>,
+    known_slugs: Option<&[&str]>,
+) -> (Config, Vec<ConfigIssue>) {
+    let mut cfg = Config::default();
+    let mut issues = Vec::new();
+
+    for key in CONFIG_KEYS {
+        // Per-target rules have no flat representation
+        if key.ends_with(".per_target") {
+            continue;
+        }
+        let name = env_var_name(namespace, key);
+        let Some(value) = var(&name).filter(|v| !v.trim().is_empty()) else {
+            continue;
+        };
+        let value = value.trim();
+        let mut issue = |message: String| {
+            issues.push(ConfigIssue {
+                path: PathBuf::from(&name),
+                location: None,
+                message,
+            })
+        };
+        let list = || -> Vec<String> {
+            value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        };
+
+        match *key {
+            "db" => cfg.db = Some(value.to_string()),
+            "log.level" => cfg.log.get_or_insert_default().level = Some(value.to_string()),
+            "log.color" => match parse_bool(value) {
+                Some(color) => cfg.log.get_or_insert_default().color = Some(color),
+                None if value.eq_ignore_ascii_case("auto") => {}
+                None => issue(format!("expected on, off or auto, got `{value}`")),
+            },
+            "targets.include" => cfg.targets.get_or_insert_default().include = Some(list()),
+            "targets.ignore" => cfg.targets.get_or_insert_default().ignore = Some(list()),
+            "targets.respect_ignore_files" => match parse_bool(value) {
+                Some(respect) => {
+                    cfg.targets.get_or_insert_default().respect_ignore_files = Some(respect)
+                }
+                None => issue(format!("expected true or false, got `{value}`")),
+            },
+            "run.mutations" => {
+                let mutations = list();
+                for slug in &mutations {
+                    if known_slugs.is_some_and(|known| !known.contains(&slug.as_str())) {
+                        issue(format!("unknown mutation slug `{slug}`"));
+                    }
+                }
+                cfg.run.get_or_insert_default().mutations = Some(mutations);
+            }
+            "run.comprehensive" => match parse_bool(value) {
+                Some(comprehensive) => {
+                    cfg.run.get_or_insert_default().comprehensive = Some(comprehensive)
+                }
+                None => issue(format!("expected true or false, got `{value}`")),
+            },
+            "test.cmd" => cfg.test.get_or_insert_default().cmd = Some(value.to_string()),
+            "test.timeout" => match value.parse() {
+                Ok(timeout) => cfg.test.get_or_insert_default().timeout = Some(timeout),
+                Err(_) => issue(format!("expected a timeout in seconds, got `{value}`")),
+            },
+            _ => unreachable!("config key `{key}` has no environment variable"),
+        }
+    }
+
+    (cfg, issues)
+}
 
-pub fn set_namespace(namespace: &str) {
-    let _ = NAMESPACE.set(namespace.to_string());
-    // Also set config filename based on namespace if not already set
-    if CONFIG_FILENAME.get().is_none() {
-        let _ = CONFIG_FILENAME.set(format!("{}.toml", namespace));
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "1" | "true" | "on" | "yes" => Some(true),
+        "0" | "false" | "off" | "no" => Some(false),
+        _ => None,
     }
 }
 
-pub fn get_namespace() -> &'static str {
-    NAMESPACE.get().map(|s| s.as_str()).unwrap()
+fn select_profile<'a>(file: &'a Config, name: &str) -> Result<&'a ProfileConfig, String> {
+    let profiles = file.profile.as_ref();
+    profiles.and_then(|p| p.get(name)).ok_or_else(|| {
+        let available: Vec<&str> = profiles
+            .map(|p| p.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        if available.is_empty() {
+            format!("unknown profile `{name}`; no profiles are defined")
+        } else {
+            format!(
+                "unknown profile `{name}`; available profiles: {}",
+                available.join(", ")
+            )
+        }
+    })
 }
 
-pub fn set_config_filename(filename: &str) {
-    let _ = CONFIG_FILENAME.set(filename.to_string());
+fn profile_issue(path: &Path, message: String) -> ConfigIssue {
+    ConfigIssue {
+        path: path.to_path_buf(),
+        location: None,
+        message,
+    }
 }
 
-pub fn get_config_filename() -> &'static str {
-    CONFIG_FILENAME.get().map(|s| s.as_str()).unwrap()
+/// A problem found in a config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub path: PathBuf,
+    /// 1-based line and column, when the problem can be located in the file
+    pub location: Option<(usize, usize)>,
+    pub message: String,
 }
 
-pub fn config() -> &'static Config {
-    CONFIG.get_or_init(|| {
-        let mut cfg = Config::default();
-        // Apply nearest config file found by walking up from cwd
-        if let Some(path) = find_nearest_config_file() {
-            if let Some(file_cfg) = read_config_file(&path) {
-                apply_file_config(&mut cfg, &file_cfg);
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some((line, column)) => {
+                write!(
+                    f,
+                    "{}:{line}:{column}: {}",
+                    self.path.display(),
+                    self.message
+                )
             }
+            None => write!(f, "{}: {}", self.path.display(), self.message),
         }
-        cfg
+    }
+}
+
+fn read_config_file(path: &Path, known_slugs: Option<&[&str]>) -> Result<Config, Vec<ConfigIssue>> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        vec![ConfigIssue {
+            path: path.to_path_buf(),
+            location: None,
+            message: format!("cannot read config file: {e}"),
+        }]
+    })?;
+    parse_config(path, &contents, known_slugs)
+}
+
+/// Parse config file contents strictly. Syntax errors, mistyped values and unknown keys
+/// are reported, as are mutation slugs missing from `known_slugs` (when given) and
+/// invalid `per_target` globs.
+pub fn parse_config(
+    path: &Path,
+    contents: &str,
+    known_slugs: Option<&[&str]>,
+) -> Result<Config, Vec<ConfigIssue>> {
+    let issue = |span: Option<Range<usize>>, message: String| ConfigIssue {
+        path: path.to_path_buf(),
+        location: span.map(|span| line_column(contents, span.start)),
+        message,
+    };
+
+    let mut unknown: Vec<Vec<KeySegment>> = Vec::new();
+    let deserializer = toml::Deserializer::new(contents);
+    let cfg: Config = serde_ignored::deserialize(deserializer, |key| {
+        unknown.push(key_segments(&key));
     })
+    .map_err(|e| vec![issue(e.span(), e.message().to_string())])?;
+
+    let mut problems: Vec<(Vec<KeySegment>, String)> = unknown
+        .into_iter()
+        .map(|key| {
+            let message = format!("unknown key `{}`", display_key(&key));
+            (key, message)
+        })
+        .collect();
+
+    check_sections(
+        &[],
+        cfg.targets.as_ref(),
+        cfg.run.as_ref(),
+        cfg.test.as_ref(),
+        known_slugs,
+        &mut problems,
+    );
+    for (name, profile) in cfg.profile.iter().flatten() {
+        let prefix = [
+            KeySegment::Key("profile".into()),
+            KeySegment::Key(name.clone()),
+        ];
+        check_sections(
+            &prefix,
+            profile.targets.as_ref(),
+            profile.run.as_ref(),
+            profile.test.as_ref(),
+            known_slugs,
+            &mut problems,
+        );
+    }
+
+    if problems.is_empty() {
+        return Ok(cfg);
+    }
+    // The file already parsed, so this only fails if toml_edit disagrees with toml
+    let document = toml_edit::ImDocument::parse(contents).ok();
+    Err(problems
+        .into_iter()
+        .map(|(key, message)| {
+            let span = document
+                .as_ref()
+                .and_then(|doc| locate_key(doc.as_item(), &key));
+            issue(span, message)
+        })
+        .collect())
 }
 
-pub fn init_with_overrides(overrides: &CliOverrides) {
-    let mut cfg = Config::default();
+/// Check ignore globs, mutation slugs and `per_target` globs of the sections found under `prefix`
+fn check_sections(
+    prefix: &[KeySegment],
+    targets: Option<&TargetsConfig>,
+    run: Option<&RunConfig>,
+    test: Option<&TestConfig>,
+    known_slugs: Option<&[&str]>,
+    problems: &mut Vec<(Vec<KeySegment>, String)>,
+) {
+    let key = |segments: &[KeySegment]| [prefix, segments].concat();
 
-    // 1) Config file: walk up from cwd and use the first config file found
-    if let Some(path) = find_nearest_config_file() {
-        if let Some(file_cfg) = read_config_file(&path) {
-            apply_file_config(&mut cfg, &file_cfg);
+    let ignore = targets.and_then(|t| t.ignore.as_deref()).unwrap_or(&[]);
+    for (i, pattern) in ignore.iter().enumerate() {
+        if let Err(e) = IgnorePatterns::new(std::slice::from_ref(pattern)) {
+            problems.push((
+                key(&[
+                    KeySegment::Key("targets".into()),
+                    KeySegment::Key("ignore".into()),
+                    KeySegment::Index(i),
+                ]),
+                format!("invalid glob `{pattern}`: {}", e.kind()),
+            ));
         }
     }
 
-    // 2) CLI arguments (highest priority). Only override if user specified.
-    apply_cli_overrides(&mut cfg, overrides);
+    // Every mutation slug mentioned, with the key it is found at
+    let mut slugs: Vec<(Vec<KeySegment>, &String)> = Vec::new();
+    fn add_slugs<'a>(
+        slugs: &mut Vec<(Vec<KeySegment>, &'a String)>,
+        path: Vec<KeySegment>,
+        list: Option<&'a Vec<String>>,
+    ) {
+        for (i, slug) in list.into_iter().flatten().enumerate() {
+            slugs.push(([path.as_slice(), &[KeySegment::Index(i)]].concat(), slug));
+        }
+    }
+    add_slugs(
+        &mut slugs,
+        vec![
+            KeySegment::Key("run".into()),
+            KeySegment::Key("mutations".into()),
+        ],
+        run.and_then(|r| r.mutations.as_ref()),
+    );
+    let run_per_target = run.map(|r| r.per_target()).unwrap_or(&[]);
+    for (i, rule) in run_per_target.iter().enumerate() {
+        let rule_key = |field: &str| {
+            vec![
+                KeySegment::Key("run".into()),
+                KeySegment::Key("per_target".into()),
+                KeySegment::Index(i),
+                KeySegment::Key(field.into()),
+            ]
+        };
+        add_slugs(&mut slugs, rule_key("mutations"), rule.mutations.as_ref());
+        add_slugs(
+            &mut slugs,
+            rule_key("exclude_mutations"),
+            rule.exclude_mutations.as_ref(),
+        );
+        for slug in rule.severity.iter().flat_map(|s| s.keys()) {
+            let path = [rule_key("severity"), vec![KeySegment::Key(slug.clone())]].concat();
+            slugs.push((path, slug));
+        }
+        if let Err(e) = path_glob(&anchored_glob(rule.glob.trim())) {
+            problems.push((
+                key(&rule_key("glob")),
+                format!("invalid glob `{}`: {}", rule.glob, e.kind()),
+            ));
+        }
+    }
+    if let Some(known_slugs) = known_slugs {
+        for (path, slug) in slugs {
+            if !known_slugs.contains(&slug.as_str()) {
+                problems.push((key(&path), format!("unknown mutation slug `{slug}`")));
+            }
+        }
+    }
+
+    let per_target = test.map(|t| t.per_target()).unwrap_or(&[]);
+    for (i, rule) in per_target.iter().enumerate() {
+        if let Err(e) = path_glob(&anchored_glob(rule.glob.trim())) {
+            problems.push((
+                key(&[
+                    KeySegment::Key("test".into()),
+                    KeySegment::Key("per_target".into()),
+                    KeySegment::Index(i),
+                    KeySegment::Key("glob".into()),
+                ]),
+                format!("invalid glob `{}`: {}", rule.glob, e.kind()),
+            ));
+        }
+    }
+}
+
+/// One step of a key path through a config document
+#[derive(Debug, Clone)]
+enum KeySegment {
+    Key(String),
+    Index(usize),
+}
 
-    let _ = CONFIG.set(cfg);
+fn key_segments(path: &serde_ignored::Path) -> Vec<KeySegment> {
+    use serde_ignored::Path;
+    match path {
+        Path::Root => Vec::new(),
+        Path::Seq { parent, index } => {
+            let mut segments = key_segments(parent);
+            segments.push(KeySegment::Index(*index));
+            segments
+        }
+        Path::Map { parent, key } => {
+            let mut segments = key_segments(parent);
+            segments.push(KeySegment::Key(key.clone()));
+            segments
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => key_segments(parent),
+    }
 }
 
-fn read_config_file(path: &Path) -> Option<Config> {
-    match fs::read_to_string(path) {
-        Ok(contents) => toml::from_str::<Config>(&contents).ok(),
-        Err(_) => None,
+fn display_key(segments: &[KeySegment]) -> String {
+    let mut out = String::new();
+    for segment in segments {
+        match segment {
+            KeySegment::Key(key) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(key);
+            }
+            KeySegment::Index(i) => out.push_str(&format!("[{i}]")),
+        }
     }
+    out
 }
 
-fn apply_file_config(cfg: &mut Config, file: &Config) {
+/// Byte span of the key (or array element) a path points at
+fn locate_key(item: &toml_edit::Item, segments: &[KeySegment]) -> Option<Range<usize>> {
+    use toml_edit::{Item, Value};
+    let Some((first, rest)) = segments.split_first() else {
+        return item.span();
+    };
+    match (item, first) {
+        (Item::Table(table), KeySegment::Key(key)) => match rest.is_empty() {
+            true => table.key(key)?.span(),
+            false => locate_key(table.get(key)?, rest),
+        },
+        (Item::Value(Value::InlineTable(table)), KeySegment::Key(key)) => match rest.is_empty() {
+            true => table.key(key)?.span(),
+            false => locate_key(&Item::Value(table.get(key)?.clone()), rest),
+        },
+        (Item::ArrayOfTables(tables), KeySegment::Index(i)) => {
+            locate_key(&Item::Table(tables.get(*i)?.clone()), rest)
+        }
+        (Item::Value(Value::Array(array)), KeySegment::Index(i)) => {
+            locate_key(&Item::Value(array.get(*i)?.clone()), rest)
+        }
+        _ => None,
+    }
+}
+
+/// 1-based line and column of a byte offset
+fn line_column(contents: &str, offset: usize) -> (usize, usize) {
+    let before = &contents[..offset.min(contents.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+fn apply_file_config(cfg: &mut Config, file: &Config, path: &Path) {
+    cfg.record_sources(file, |_| ValueSource::File(path.to_path_buf()));
+
     // Merge top-level fields
     if file.db.is_some() {
         cfg.db = file.db.clone();
@@ -308,10 +987,93 @@ fn apply_file_config(cfg: &mut Config, file: &Config) {
     }
 }
 
+/// Overlay the selected profile on the merged config
+fn apply_profile(cfg: &mut Config, name: &str, profile: &ProfileConfig) {
+    let layer = Config {
+        test: profile.test.clone(),
+        targets: profile.targets.clone(),
+        run: profile.run.clone(),
+        ..Default::default()
+    };
+    apply_overlay(cfg, &layer);
+    cfg.record_sources(&layer, |_| ValueSource::Profile(name.to_string()));
+    cfg.active_profile = Some(name.to_string());
+}
+
+/// Overlay a layer on the merged config. Fields set in the layer replace the
+/// corresponding values; its `per_target` rules are tried before the existing rules.
+fn apply_overlay(cfg: &mut Config, layer: &Config) {
+    if layer.db.is_some() {
+        cfg.db = layer.db.clone();
+    }
+
+    if let Some(layer_log) = &layer.log {
+        let mut log = cfg.log.clone().unwrap_or_default();
+        if layer_log.level.is_some() {
+            log.level = layer_log.level.clone();
+        }
+        if layer_log.color.is_some() {
+            log.color = layer_log.color;
+        }
+        cfg.log = Some(log);
+    }
+
+    if let Some(layer_test) = &layer.test {
+        let mut test = cfg.test.clone().unwrap_or_default();
+        if layer_test.cmd.is_some() {
+            test.cmd = layer_test.cmd.clone();
+        }
+        if layer_test.timeout.is_some() {
+            test.timeout = layer_test.timeout;
+        }
+        if let Some(layer_per_target) = &layer_test.per_target {
+            let mut rules: Vec<PerTargetTestRule> = layer_per_target
+                .iter()
+                .filter(|rule| rule.cmd.as_ref().is_some_and(|c| !c.trim().is_empty()))
+                .cloned()
+                .collect();
+            rules.extend_from_slice(test.per_target());
+            test.per_target = Some(rules);
+        }
+        cfg.test = Some(test);
+    }
+
+    if let Some(layer_targets) = &layer.targets {
+        let mut targets = cfg.targets.clone().unwrap_or_default();
+        if layer_targets.include.is_some() {
+            targets.include = layer_targets.include.clone();
+        }
+        if layer_targets.ignore.is_some() {
+            targets.ignore = layer_targets.ignore.clone();
+        }
+        if layer_targets.respect_ignore_files.is_some() {
+            targets.respect_ignore_files = layer_targets.respect_ignore_files;
+        }
+        cfg.targets = Some(targets);
+    }
+
+    if let Some(layer_run) = &layer.run {
+        let mut run = cfg.run.clone().unwrap_or_default();
+        if layer_run.mutations.is_some() {
+            run.mutations = layer_run.mutations.clone();
+        }
+        if layer_run.comprehensive.is_some() {
+            run.comprehensive = layer_run.comprehensive;
+        }
+        if let Some(layer_per_target) = &layer_run.per_target {
+            let mut rules = layer_per_target.clone();
+            rules.extend_from_slice(run.per_target());
+            run.per_target = Some(rules);
+        }
+        cfg.run = Some(run);
+    }
+}
+
 fn apply_cli_overrides(cfg: &mut Config, overrides: &CliOverrides) {
     // Top-level overrides
     if overrides.db.is_some() {
         cfg.db = overrides.db.clone();
+        cfg.sources.insert("db", ValueSource::Cli("--db".into()));
     }
 
     // Log overrides
@@ -319,13 +1081,20 @@ fn apply_cli_overrides(cfg: &mut Config, overrides: &CliOverrides) {
     if let Some(level) = &overrides.log_level {
         if !level.trim().is_empty() {
             log.level = Some(level.trim().to_string());
+            cfg.sources
+                .insert("log.level", ValueSource::Cli("--log.level".into()));
         }
     }
     if let Some(color_str) = &overrides.log_color {
-        match color_str.to_lowercase().as_str() {
-            "on" => log.color = Some(true),
-            "off" => log.color = Some(false),
-            _ => {}
+        let color = match color_str.to_lowercase().as_str() {
+            "on" => Some(true),
+            "off" => Some(false),
+            _ => None,
+        };
+        if color.is_some() {
+            log.color = color;
+            cfg.sources
+                .insert("log.color", ValueSource::Cli("--log.color".into()));
         }
     }
     if overrides.log_level.is_some() || overrides.log_color.is_some() {
@@ -333,9 +1102,8 @@ fn apply_cli_overrides(cfg: &mut Config, overrides: &CliOverrides) {
     }
 }
 
-fn find_nearest_config_file() -> Option<PathBuf> {
+fn find_nearest_config_file(config_filename: &str) -> Option<PathBuf> {
     let cwd = std::env::current_dir().ok()?;
-    let config_filename = get_config_filename();
     for dir in cwd.ancestors() {
         let candidate = dir.join(config_filename);
         if candidate.exists() {
@@ -345,13 +1113,6 @@ fn find_nearest_config_file() -> Option<PathBuf> {
     None
 }
 
-pub fn colors_enabled() -> bool {
-    match config().log().color() {
-        Some(force) => force,
-        None => console::colors_enabled(),
-    }
-}
-
 pub fn is_slug_enabled(slug: &str, mutations: Option<&[String]>) -> bool {
     if let Some(list) = mutations {
         return list.iter().any(|s| s == slug);
@@ -359,53 +1120,445 @@ pub fn is_slug_enabled(slug: &str, mutations: Option<&[String]>) -> bool {
     true
 }
 
-pub fn is_path_excluded(path: &Path, ignore_patterns: &[String]) -> bool {
-    if ignore_patterns.is_empty() {
-        return false;
-    }
-    let path_str = path.to_string_lossy();
-    ignore_patterns
-        .iter()
-        .filter(|p| !p.is_empty())
-        .any(|pat| path_str.contains(pat))
+/// Target ignore patterns with gitignore-like semantics.
+/// A pattern without a `/` matches a file or directory name at any depth, a pattern
+/// containing a `/` is matched against the whole path relative to the current directory,
+/// and a trailing `/` only matches directories. Everything inside a matched directory
+/// is excluded too.
+#[derive(Debug, Clone)]
+pub struct IgnorePatterns {
+    /// Matches excluded files and directories
+    paths: globset::GlobSet,
+    /// Matches excluded directories only
+    dirs: globset::GlobSet,
+    /// Directory that anchored patterns are relative to
+    cwd: Option<PathBuf>,
 }
 
-pub fn resolve_test_for_path(
-    path: &Path,
-    resolved_cmd: Option<&str>,
-    resolved_timeout: Option<u32>,
-) -> (Option<String>, Option<u32>) {
-    let test = config().test();
-
-    // If we have a resolved command from CLI, use it
-    if let Some(cmd) = resolved_cmd {
-        if !cmd.trim().is_empty() {
-            return (Some(cmd.to_string()), resolved_timeout);
+impl IgnorePatterns {
+    pub fn new(patterns: &[String]) -> Result<Self, globset::Error> {
+        let mut paths = globset::GlobSetBuilder::new();
+        let mut dirs = globset::GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = pattern.trim();
+            let dir_only = pattern.ends_with('/');
+            let pattern = pattern.trim_end_matches('/');
+            if pattern.is_empty() {
+                continue;
+            }
+            let base = anchored_glob(pattern);
+            if dir_only {
+                dirs.add(path_glob(&base)?);
+            } else {
+                paths.add(path_glob(&base)?);
+            }
+            paths.add(path_glob(&format!("{base}/**"))?);
         }
+        Ok(Self {
+            paths: paths.build()?,
+            dirs: dirs.build()?,
+            cwd: std::env::current_dir().ok(),
+        })
     }
 
-    // Per-target rules: first match wins
-    let path_buf = PathBuf::from(path);
-    for rule in test.per_target() {
-        if glob_matches(&rule.glob, &path_buf) {
-            if let Some(cmd) = &rule.cmd {
-                let timeout = resolved_timeout.or(rule.timeout).or(test.timeout());
-                return (Some(cmd.clone()), timeout);
-            }
-        }
+    /// Whether `path` (a directory if `is_dir`) is excluded
+    pub fn is_excluded(&self, path: &Path, is_dir: bool) -> bool {
+        let path = relative_path(self.cwd.as_deref(), path);
+        self.paths.is_match(&path) || (is_dir && self.dirs.is_match(&path))
     }
+}
 
-    // Fallback to global
-    (
-        test.cmd().map(|s| s.to_string()),
-        resolved_timeout.or(test.timeout()),
-    )
+/// Resolve a gitignore-style pattern against the current directory: a leading `/` or `./`
+/// anchors it there, and a pattern without `/` matches at any depth
+fn anchored_glob(pattern: &str) -> String {
+    match pattern.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if pattern.contains('/') => pattern.trim_start_matches("./").to_string(),
+        None => format!("**/{pattern}"),
+    }
 }
 
+/// A glob whose `*` and `?` stop at path separators
+pub(crate) fn path_glob(pattern: &str) -> Result<globset::Glob, globset::Error> {
+    globset::GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+}
+
+/// `path` relative to `cwd` when inside it, without `.` components, for matching globs
+pub(crate) fn relative_path(cwd: Option<&Path>, path: &Path) -> PathBuf {
+    cwd.and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path)
+        .components()
+        .filter(|c| !matches!(c, std::path::Component::CurDir))
+        .collect()
+}
+
+/// Whether a per-target glob matches `path`, resolved like ignore patterns
 fn glob_matches(pattern: &str, path: &Path) -> bool {
-    if let Ok(glob) = globset::Glob::new(pattern) {
-        let matcher = glob.compile_matcher();
-        return matcher.is_match(path);
+    match path_glob(&anchored_glob(pattern.trim())) {
+        Ok(glob) => {
+            let cwd = std::env::current_dir().ok();
+            glob.compile_matcher()
+                .is_match(relative_path(cwd.as_deref(), path))
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SLUGS: &[&str] = &["ER", "CR"];
+
+    fn issues(contents: &str) -> Vec<String> {
+        parse_config(Path::new("mewt.toml"), contents, Some(SLUGS))
+            .err()
+            .unwrap_or_default()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn example_config_is_valid() {
+        let example = include_str!("../../example.toml");
+        assert_eq!(issues(example), Vec::<String>::new());
+    }
+
+    #[test]
+    fn syntax_and_type_errors_are_located() {
+        assert_eq!(
+            issues("[test]\ncmd = \"cargo test\"\ntimeout = \"soon\"\n"),
+            vec!["mewt.toml:3:11: invalid type: string \"soon\", expected u32"]
+        );
+        let syntax = issues("[log\nlevel = \"info\"\n");
+        assert_eq!(syntax.len(), 1);
+        assert!(syntax[0].starts_with("mewt.toml:1:5: "), "{syntax:?}");
+    }
+
+    #[test]
+    fn unknown_keys_are_all_reported() {
+        assert_eq!(
+            issues(
+                "[general]\ndb = \"x.sqlite\"\n\n[test]\ncmd = \"make\"\ntimout = 3\n\n\
+                 [[test.per_target]]\nglob = \"*.rs\"\ncommand = \"cargo test\"\n"
+            ),
+            vec![
+                "mewt.toml:1:2: unknown key `general`",
+                "mewt.toml:6:1: unknown key `test.timout`",
+                "mewt.toml:10:1: unknown key `test.per_target[0].command`",
+            ]
+        );
+    }
+
+    #[test]
+    fn slugs_and_globs_are_checked() {
+        assert_eq!(
+            issues(
+                "[run]\nmutations = [\"ER\", \"XX\"]\n\n\
+                 [[test.per_target]]\nglob = \"src/[a\"\n"
+            ),
+            vec![
+                "mewt.toml:2:20: unknown mutation slug `XX`",
+                "mewt.toml:5:1: invalid glob `src/[a`: unclosed character class; missing ']'",
+            ]
+        );
+    }
+
+    #[test]
+    fn profiles_are_validated() {
+        assert_eq!(
+            issues(
+                "[profile.quick.run]\nmutations = [\"XX\"]\n\n\
+                 [profile.quick.test]\ntimout = 5\n"
+            ),
+            vec![
+                "mewt.toml:5:1: unknown key `profile.quick.test.timout`",
+                "mewt.toml:2:14: unknown mutation slug `XX`",
+            ]
+        );
+    }
+
+    #[test]
+    fn profile_overlays_base_config() {
+        let file = parse_config(
+            Path::new("mewt.toml"),
+            r#"
+[targets]
+include = ["src"]
+ignore = ["vendor"]
+
+[run]
+comprehensive = false
+
+[test]
+cmd = "cargo test"
+timeout = 600
+
+[[test.per_target]]
+glob = "*.rs"
+cmd = "cargo test --lib"
+
+[profile.quick.run]
+mutations = ["ER"]
+
+[profile.quick.test]
+timeout = 30
+
+[[profile.quick.test.per_target]]
+glob = "src/core/*.rs"
+cmd = "cargo test core"
+
+[profile.nightly.run]
+comprehensive = true
+"#,
+            Some(SLUGS),
+        )
+        .unwrap();
+
+        let mut cfg = Config::default();
+        apply_file_config(&mut cfg, &file, Path::new("mewt.toml"));
+        apply_profile(&mut cfg, "quick", select_profile(&file, "quick").unwrap());
+
+        assert_eq!(cfg.resolve_mutations(None), Some(vec!["ER".to_string()]));
+        assert_eq!(cfg.run().unwrap().comprehensive, Some(false));
+        assert_eq!(cfg.test().cmd(), Some("cargo test"));
+        assert_eq!(cfg.test().timeout(), Some(30));
+        let globs: Vec<String> = cfg
+            .test()
+            .per_target()
+            .iter()
+            .map(|r| r.glob.clone())
+            .collect();
+        assert_eq!(globs, vec!["src/core/*.rs", "*.rs"]);
+        assert_eq!(
+            cfg.targets().unwrap().ignore,
+            Some(vec!["vendor".to_string()])
+        );
+
+        assert_eq!(
+            select_profile(&file, "weekly").unwrap_err(),
+            "unknown profile `weekly`; available profiles: nightly, quick"
+        );
+    }
+
+    #[test]
+    fn env_layer_parses_and_validates_variables() {
+        let vars = |var: &str| match var {
+            "MEWT_DB" => Some("ci.sqlite".to_string()),
+            "MEWT_TEST_CMD" => Some("make test".to_string()),
+            "MEWT_TEST_TIMEOUT" => Some(" 45 ".to_string()),
+            "MEWT_RUN_MUTATIONS" => Some("ER, CR,".to_string()),
+            "MEWT_LOG_LEVEL" => Some(String::new()),
+            _ => None,
+        };
+        let (env, issues) = env_layer("mewt", vars, Some(SLUGS));
+        assert!(issues.is_empty(), "{issues:?}");
+        assert_eq!(
+            env.keys_set(),
+            vec!["db", "run.mutations", "test.cmd", "test.timeout"]
+        );
+        assert_eq!(env.db.as_deref(), Some("ci.sqlite"));
+        assert_eq!(env.test().timeout(), Some(45));
+        assert_eq!(
+            env.resolve_mutations(None),
+            Some(vec!["ER".to_string(), "CR".to_string()])
+        );
+
+        let vars = |var: &str| match var {
+            "MEWT_TEST_TIMEOUT" => Some("soon".to_string()),
+            "MEWT_RUN_MUTATIONS" => Some("ER,XX".to_string()),
+            _ => None,
+        };
+        let messages: Vec<String> = env_layer("mewt", vars, Some(SLUGS))
+            .1
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "MEWT_RUN_MUTATIONS: unknown mutation slug `XX`",
+                "MEWT_TEST_TIMEOUT: expected a timeout in seconds, got `soon`",
+            ]
+        );
+    }
+
+    #[test]
+    fn sources_follow_precedence() {
+        let file = parse_config(
+            Path::new("mewt.toml"),
+            "[test]\ncmd = \"cargo test\"\ntimeout = 60\n\n[profile.ci.test]\ntimeout = 120\n",
+            Some(SLUGS),
+        )
+        .unwrap();
+        let mut cfg = Config::default();
+        apply_file_config(&mut cfg, &file, Path::new("mewt.toml"));
+        apply_profile(&mut cfg, "ci", select_profile(&file, "ci").unwrap());
+        let (env, _) = env_layer(
+            "mewt",
+            |var| (var == "MEWT_TEST_CMD").then(|| "make test".to_string()),
+            Some(SLUGS),
+        );
+        apply_overlay(&mut cfg, &env);
+        cfg.record_sources(&env, |key| ValueSource::Env(env_var_name("mewt", key)));
+        apply_cli_overrides(
+            &mut cfg,
+            &CliOverrides {
+                db: Some("cli.sqlite".into()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(cfg.test().cmd(), Some("make test"));
+        assert_eq!(cfg.test().timeout(), Some(120));
+        assert_eq!(cfg.source("db"), ValueSource::Cli("--db".into()));
+        assert_eq!(
+            cfg.source("test.cmd"),
+            ValueSource::Env("MEWT_TEST_CMD".into())
+        );
+        assert_eq!(
+            cfg.source("test.timeout"),
+            ValueSource::Profile("ci".into())
+        );
+        assert_eq!(cfg.source("log.level"), ValueSource::Default);
+    }
+
+    #[test]
+    fn ignore_patterns_are_globs() {
+        let patterns: Vec<String> = ["lib", "build/", "src/**/*_generated.rs", "*.min.js"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let ignore = IgnorePatterns::new(&patterns).unwrap();
+        let excluded = |path: &str, is_dir: bool| ignore.is_excluded(Path::new(path), is_dir);
+
+        // Names match at any depth, and everything inside a matched directory is excluded
+        assert!(excluded("lib", true));
+        assert!(excluded("./crates/core/lib/mod.rs", false));
+        assert!(!excluded("src/library.rs", false));
+        // A trailing slash only matches directories
+        assert!(excluded("out/build", true));
+        assert!(excluded("out/build/main.rs", false));
+        assert!(!excluded("scripts/build", false));
+        // Patterns with a slash are matched against the whole path
+        assert!(excluded("src/api/schema_generated.rs", false));
+        assert!(!excluded("tests/schema_generated.rs", false));
+        assert!(excluded("web/app.min.js", false));
+    }
+
+    #[test]
+    fn invalid_ignore_globs_are_reported() {
+        assert_eq!(
+            issues("[targets]\nignore = [\"vendor\", \"src/{a\"]\n"),
+            vec![
+                "mewt.toml:2:21: invalid glob `src/{a`: unclosed alternate group; missing '}' (maybe escape '{' with '[{]'?)"
+            ]
+        );
+    }
+
+    #[test]
+    fn run_rules_select_mutations_and_severity() {
+        let file = parse_config(
+            Path::new("mewt.toml"),
+            r#"
+[[run.per_target]]
+glob = "src/bindings/**"
+mutations = ["ER", "CR"]
+exclude_mutations = ["CR"]
+
+[[run.per_target]]
+glob = "src/math/**"
+severity = { CR = "High" }
+"#,
+            Some(SLUGS),
+        )
+        .unwrap();
+        let rules = file.run().unwrap().per_target();
+        let all: Vec<String> = ["ER", "CR", "AOS"].map(String::from).to_vec();
+
+        // Whitelists narrow the enabled set, blacklists remove from it
+        assert_eq!(
+            rules[0].select_mutations(None, &all),
+            Some(vec!["ER".to_string()])
+        );
+        assert_eq!(
+            rules[0].select_mutations(Some(&["CR".to_string(), "AOS".to_string()]), &all),
+            Some(vec![])
+        );
+        // Rules without a selection leave it alone
+        assert_eq!(rules[1].select_mutations(None, &all), None);
+        assert_eq!(rules[1].severity("CR"), Some(MutationSeverity::High));
+        assert_eq!(rules[1].severity("ER"), None);
+    }
+
+    #[test]
+    fn test_commands_resolve_per_target() {
+        let cfg = parse_config(
+            Path::new("mewt.toml"),
+            "[test]\ncmd = \"make test\"\ntimeout = 60\n\n\
+             [[test.per_target]]\nglob = \"contracts/**\"\ncmd = \"forge test\"\ntimeout = 300\n",
+            Some(SLUGS),
+        )
+        .unwrap();
+        let contract = Path::new("contracts/Token.sol");
+
+        assert_eq!(
+            cfg.resolve_test_for_path(contract, None, None),
+            (Some("forge test".to_string()), Some(300))
+        );
+        assert_eq!(
+            cfg.resolve_test_for_path(Path::new("src/lib.rs"), None, None),
+            (Some("make test".to_string()), Some(60))
+        );
+        // Explicit overrides apply to every target
+        assert_eq!(
+            cfg.resolve_test_for_path(contract, Some("just test"), None),
+            (Some("just test".to_string()), Some(60))
+        );
+        assert_eq!(
+            cfg.resolve_test_for_path(contract, None, Some(10)),
+            (Some("forge test".to_string()), Some(10))
+        );
+    }
+
+    #[test]
+    fn per_target_globs_match_like_ignore_patterns() {
+        let cwd = std::env::current_dir().unwrap();
+        // Paths are matched relative to the current directory, with or without `./`
+        assert!(glob_matches("src/*.rs", &cwd.join("src/lib.rs")));
+        assert!(glob_matches("./src/*.rs", Path::new("./src/lib.rs")));
+        assert!(glob_matches("/src/**", &cwd.join("src/core/mod.rs")));
+        // `*` stops at `/`, and patterns without one match at any depth
+        assert!(!glob_matches("src/*.rs", &cwd.join("src/core/mod.rs")));
+        assert!(glob_matches("*.sol", &cwd.join("contracts/Token.sol")));
+        assert!(!glob_matches(
+            "contracts/**",
+            Path::new("/elsewhere/contracts/Token.sol")
+        ));
+    }
+
+    #[test]
+    fn run_rules_are_validated() {
+        assert_eq!(
+            issues(
+                "[[run.per_target]]\nglob = \"gen/**\"\nexclude_mutations = [\"XX\"]\n\
+                 severity = { YY = \"High\" }\n\n\
+                 [[run.per_target]]\nglob = \"lib/**\"\nseverity = { ER = \"Huge\" }\n"
+            ),
+            vec!["mewt.toml:8:19: unknown variant `Huge`, expected one of `High`, `Medium`, `Low`"]
+        );
+        assert_eq!(
+            issues(
+                "[[run.per_target]]\nglob = \"gen/**\"\nexclude_mutations = [\"XX\"]\n\
+                 severity = { YY = \"High\" }\n"
+            ),
+            vec![
+                "mewt.toml:3:22: unknown mutation slug `XX`",
+                "mewt.toml:4:14: unknown mutation slug `YY`",
+            ]
+        );
     }
-    false
 }
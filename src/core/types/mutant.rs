@@ -3,7 +3,6 @@ use serde::Serialize;
 use similar::{ChangeTag, TextDiff};
 
 use crate::types::Target;
-use crate::types::config::colors_enabled;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PartialMutant {
@@ -150,7 +149,7 @@ impl Mutant {
             .diff_unicode_words(&formatted_original, &formatted_mutated);
 
         // Format the diff; optionally disable colors
-        let colors_enabled = colors_enabled();
+        let colors_enabled = console::colors_enabled();
         let mut original_highlighted = String::new();
         let mut mutated_highlighted = String::new();
 
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
 #[derive(Debug, Clone, Serialize)]
@@ -8,7 +8,7 @@ pub struct Mutation {
     pub severity: MutationSeverity,
 }
 
-#[derive(Clone, Debug, Display, EnumString, PartialEq, Eq, Hash, Serialize)]
+#[derive(Clone, Debug, Display, EnumString, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MutationSeverity {
     High,   // eg revert/throw replacement
     Medium, // eg replace line with a comment
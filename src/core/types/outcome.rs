@@ -16,6 +16,9 @@ pub enum Status {
     Skipped,
     // questionable, tests timed out before passing or failing
     Timeout,
+    // fine, someone marked this mutant as behaving like the original code, so no test can
+    // catch it
+    Equivalent,
 }
 
 impl Status {
@@ -25,6 +28,7 @@ impl Status {
             Status::TestFail => style(self.to_string()).green().bold(),
             Status::Timeout => style(self.to_string()).yellow(),
             Status::Skipped => style(self.to_string()).blue(),
+            Status::Equivalent => style(self.to_string()).dim(),
         }
     }
 }
@@ -39,7 +43,7 @@ pub struct Outcome {
 }
 
 /// Summary of a mutation testing campaign
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct CampaignSummary {
     /// Total number of mutants tested (not skipped)
     pub tested: usize,
@@ -10,6 +10,8 @@ pub struct TargetStats {
     pub uncaught: usize,
     pub timeout: usize,
     pub skipped: usize,
+    /// Mutants marked as equivalent to the original code
+    pub equivalent: usize,
     /// Map from mutation_slug to (eligible_count, caught_count)
     pub severity_stats: HashMap<String, (usize, usize)>,
 }
@@ -1,14 +1,17 @@
 use std::fs;
 use std::io;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
-use log::info;
+use ignore::WalkState;
+use log::{info, warn};
+use rayon::prelude::*;
 use serde::Serialize;
 
 use crate::LanguageRegistry;
 use crate::SqlStore;
-use crate::types::config::{ResolvedTargets, is_path_excluded, is_slug_enabled};
+use crate::types::config::{Config, IgnorePatterns, PerTargetRunRule, ResolvedTargets};
 use crate::types::{Hash, Mutant};
 
 #[derive(Debug, Clone, Serialize)]
@@ -47,100 +50,162 @@ impl Target {
     pub async fn load_targets(
         resolved_targets: &ResolvedTargets,
         store: &SqlStore,
+        registry: &Arc<LanguageRegistry>,
+    ) -> io::Result<Vec<Target>> {
+        // Walking, reading and hashing are blocking and CPU-bound; keep them off the runtime
+        let resolved = resolved_targets.clone();
+        let registry = Arc::clone(registry);
+        let mut all_targets =
+            tokio::task::spawn_blocking(move || Self::read_targets(&resolved, &registry))
+                .await
+                .map_err(io::Error::other)??;
+
+        if all_targets.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No valid targets found after filtering",
+            ));
+        }
+
+        store
+            .add_targets(&mut all_targets)
+            .await
+            .map_err(|e| io::Error::other(format!("Failed to store targets: {e}")))?;
+
+        Ok(all_targets)
+    }
+
+    /// Find, read and hash the files matched by `resolved_targets`, sorted by path
+    fn read_targets(
+        resolved_targets: &ResolvedTargets,
         registry: &LanguageRegistry,
-        mutations: Option<&[String]>,
     ) -> io::Result<Vec<Target>> {
-        let mut all_targets: Vec<Target> = vec![];
+        let ignore = IgnorePatterns::new(&resolved_targets.ignore).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid ignore pattern: {e}"),
+            )
+        })?;
 
-        // Expand globs and collect all target paths
+        // Expand globs and walk directories, then sort so the load order is deterministic
+        let mut paths: Vec<PathBuf> = vec![];
         for pattern in &resolved_targets.include {
-            let path = PathBuf::from(pattern);
+            Self::collect_paths(pattern, &ignore, resolved_targets, &mut paths)?;
+        }
+        paths.sort();
+        paths.dedup();
+        // Symlinks can reach the same file through several paths; keep the first
+        let mut seen = std::collections::HashSet::new();
+        paths.retain(|path| seen.insert(path.canonicalize().unwrap_or_else(|_| path.clone())));
 
-            if path.is_file() {
-                // Direct file reference
-                if !is_path_excluded(&path, &resolved_targets.ignore) {
-                    if let Some(target) =
-                        Self::load_single_file(path, store, registry, mutations).await?
-                    {
-                        all_targets.push(target);
-                    }
-                }
-            } else if path.is_dir() {
-                // Walk directory
-                let targets_from_dir = Box::pin(Self::load_from_directory(
-                    path,
-                    store,
-                    registry,
-                    &resolved_targets.ignore,
-                    mutations,
-                ))
-                .await?;
-                all_targets.extend(targets_from_dir);
-            } else {
-                // Try as glob pattern
-                match glob::glob(pattern) {
-                    Ok(paths) => {
-                        for entry in paths {
-                            match entry {
-                                Ok(glob_path) => {
-                                    if glob_path.is_file()
-                                        && !is_path_excluded(&glob_path, &resolved_targets.ignore)
-                                    {
-                                        if let Some(target) = Self::load_single_file(
-                                            glob_path, store, registry, mutations,
-                                        )
-                                        .await?
-                                        {
-                                            all_targets.push(target);
-                                        }
-                                    } else if glob_path.is_dir() {
-                                        let targets_from_dir = Box::pin(Self::load_from_directory(
-                                            glob_path,
-                                            store,
-                                            registry,
-                                            &resolved_targets.ignore,
-                                            mutations,
-                                        ))
-                                        .await?;
-                                        all_targets.extend(targets_from_dir);
-                                    }
-                                }
-                                Err(e) => {
-                                    info!("Skipping invalid glob entry: {}", e);
-                                }
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        return Err(io::Error::new(
-                            io::ErrorKind::InvalidInput,
-                            format!("Invalid glob pattern '{}': {}", pattern, e),
-                        ));
-                    }
+        // Read, hash and classify files in parallel; collect preserves the sorted order
+        let loaded = paths
+            .into_par_iter()
+            .map(|path| Self::read_single_file(path, registry))
+            .collect::<io::Result<Vec<Option<Target>>>>()?;
+        Ok(loaded.into_iter().flatten().collect())
+    }
+
+    fn collect_paths(
+        pattern: &str,
+        ignore: &IgnorePatterns,
+        resolved_targets: &ResolvedTargets,
+        paths: &mut Vec<PathBuf>,
+    ) -> io::Result<()> {
+        let path = PathBuf::from(pattern);
+
+        if path.is_file() {
+            // Direct file reference, kept even if an ignore file lists it
+            if !ignore.is_excluded(&path, false) {
+                paths.push(path);
+            }
+        } else if path.is_dir() {
+            paths.extend(Self::walk(&path, ignore, resolved_targets));
+        } else {
+            // Try as glob pattern: walk from its literal prefix and keep files that match
+            // the glob or lie inside a directory that does
+            let glob = globset::GlobBuilder::new(pattern.trim_start_matches("./"))
+                .literal_separator(true)
+                .build()
+                .map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("Invalid glob pattern '{}': {}", pattern, e),
+                    )
+                })?
+                .compile_matcher();
+            let root = glob_root(pattern);
+            for file in Self::walk(&root, ignore, resolved_targets) {
+                let normalized: PathBuf = file
+                    .components()
+                    .filter(|c| !matches!(c, Component::CurDir))
+                    .collect();
+                if normalized.ancestors().any(|p| glob.is_match(p)) {
+                    paths.push(file);
                 }
             }
         }
+        Ok(())
+    }
 
-        if all_targets.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::NotFound,
-                "No valid targets found after filtering",
-            ));
+    /// Recursively list the files under `root` on several threads, skipping ignored paths and
+    /// (if `respect_ignore_files`) anything listed in .gitignore, .ignore or .mewtignore files.
+    /// Hidden entries are skipped either way: `--no-ignore` is about ignore files, and
+    /// walking `.git/` or editor state is never wanted. Name a hidden file to include it.
+    /// Symlinks are followed; loops are reported and skipped.
+    fn walk(
+        root: &Path,
+        ignore: &IgnorePatterns,
+        resolved_targets: &ResolvedTargets,
+    ) -> Vec<PathBuf> {
+        let respect_ignore_files = resolved_targets.respect_ignore_files;
+        if ignore.is_excluded(root, true) {
+            return vec![];
         }
 
-        Ok(all_targets)
+        let mut builder = ignore::WalkBuilder::new(root);
+        builder
+            .standard_filters(respect_ignore_files)
+            .hidden(true)
+            .require_git(false)
+            .follow_links(true);
+        if respect_ignore_files {
+            if let Some(filename) = &resolved_targets.ignore_filename {
+                builder.add_custom_ignore_filename(filename);
+            }
+        }
+        let filter = ignore.clone();
+        builder.filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            !filter.is_excluded(entry.path(), is_dir)
+        });
+
+        let files = Mutex::new(vec![]);
+        builder.build_parallel().run(|| {
+            let files = &files;
+            Box::new(move |entry| {
+                match entry {
+                    Ok(entry) => {
+                        if entry.file_type().is_some_and(|t| t.is_file()) {
+                            files.lock().unwrap().push(entry.into_path());
+                        }
+                    }
+                    Err(e) => warn!("Skipping directory entry: {}", e),
+                }
+                WalkState::Continue
+            })
+        });
+        // Threads finish in any order
+        let mut files = files.into_inner().unwrap();
+        files.sort();
+        files
     }
 
-    async fn load_single_file(
+    /// Read a file into an unsaved target (id 0), or None if no engine handles it
+    fn read_single_file(
         target_path: PathBuf,
-        store: &SqlStore,
         registry: &LanguageRegistry,
-        _mutations: Option<&[String]>,
     ) -> io::Result<Option<Target>> {
-        let mut file = fs::File::open(&target_path)?;
-        let mut text = String::new();
-        file.read_to_string(&mut text)?;
-
         // Determine language from the file extension
         let language_engine = match registry.language_from_path(&target_path) {
             Some(engine) => engine,
@@ -154,59 +219,17 @@ impl Target {
         };
         let language = language_engine.name().to_string();
 
-        let mut target = Target {
+        let mut file = fs::File::open(&target_path)?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+
+        Ok(Some(Target {
             id: 0, // dummy placeholder until we store it in the db
             path: target_path,
             file_hash: Hash::digest(text.clone()),
             text,
             language,
-        };
-
-        match store.add_target(target.clone()).await {
-            Ok(id) => {
-                target.id = id;
-                Ok(Some(target))
-            }
-            Err(e) => Err(io::Error::other(format!("Failed to store target: {e}"))),
-        }
-    }
-
-    async fn load_from_directory(
-        dir_path: PathBuf,
-        store: &SqlStore,
-        registry: &LanguageRegistry,
-        ignore_patterns: &[String],
-        mutations: Option<&[String]>,
-    ) -> io::Result<Vec<Target>> {
-        // Skip directory entirely if excluded
-        if is_path_excluded(&dir_path, ignore_patterns) {
-            return Ok(vec![]);
-        }
-
-        let mut targets = vec![];
-        for entry in fs::read_dir(dir_path)? {
-            let path = entry?.path();
-            if path.is_file() {
-                if !is_path_excluded(&path, ignore_patterns) {
-                    if let Some(target) =
-                        Self::load_single_file(path, store, registry, mutations).await?
-                    {
-                        targets.push(target);
-                    }
-                }
-            } else if path.is_dir() {
-                let targets_from_subdir = Box::pin(Self::load_from_directory(
-                    path,
-                    store,
-                    registry,
-                    ignore_patterns,
-                    mutations,
-                ))
-                .await?;
-                targets.extend(targets_from_subdir);
-            }
-        }
-        Ok(targets)
+        }))
     }
 
     pub async fn filter_by_path(
@@ -226,6 +249,7 @@ impl Target {
         &self,
         registry: &LanguageRegistry,
         mutations: Option<&[String]>,
+        rule: Option<&PerTargetRunRule>,
     ) -> Result<Vec<Mutant>, String> {
         let mut mutants: Vec<Mutant> = Vec::new();
 
@@ -234,16 +258,36 @@ impl Target {
             Some(engine) => engine,
             None => return Err(format!("No engine found for language: {}", self.language)),
         };
-        let mut new_mutants = engine.apply_all_mutations(self);
-
-        // Filter by whitelist (if present)
-        new_mutants.retain(|m| is_slug_enabled(&m.mutation_slug, mutations));
+        // Only enabled mutations (whitelist, if present) are generated, narrowed by the
+        // [[run.per_target]] rule matching this target
+        let mutations = match rule {
+            Some(rule) => rule.select_mutations(mutations, &engine.get_all_slugs()),
+            None => mutations.map(<[String]>::to_vec),
+        };
+        let mut new_mutants = engine.apply_mutations(self, mutations.as_deref());
 
         mutants.append(&mut new_mutants);
 
         Ok(mutants)
     }
 
+    /// Generate mutants for many targets in parallel, applying `config`'s per-target rules.
+    /// Results are returned in the same order as `targets`.
+    pub fn generate_all_mutants(
+        targets: &[Target],
+        registry: &LanguageRegistry,
+        mutations: Option<&[String]>,
+        config: &Config,
+    ) -> Vec<Result<Vec<Mutant>, String>> {
+        targets
+            .par_iter()
+            .map(|target| {
+                let rule = config.run_rule_for_path(&target.path);
+                target.generate_mutants(registry, mutations, rule.as_ref())
+            })
+            .collect()
+    }
+
     pub fn mutate(&self, mutant: &Mutant) -> io::Result<String> {
         if mutant.target_id != self.id && mutant.target_id != 0 {
             return Err(io::Error::new(
@@ -265,8 +309,31 @@ impl Target {
         Ok(mutated_content)
     }
 
+    /// Whether the file on disk no longer holds the text its mutants were generated from
+    pub fn is_stale(&self) -> bool {
+        std::fs::read_to_string(&self.path)
+            .map_or(true, |text| Hash::digest(text) != self.file_hash)
+    }
+
     pub fn restore(&self) -> io::Result<()> {
         std::fs::write(&self.path, &self.text)?;
         Ok(())
     }
 }
+
+/// The longest leading part of a glob pattern without glob syntax, or `.`
+fn glob_root(pattern: &str) -> PathBuf {
+    let root: PathBuf = Path::new(pattern)
+        .components()
+        .take_while(|c| {
+            !c.as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '[', '{'])
+        })
+        .collect();
+    if root.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        root
+    }
+}
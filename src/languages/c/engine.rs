@@ -0,0 +1,498 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, Target};
+use crate::utils::{node_text, parse_source};
+
+use super::mutations::C_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static C_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_c() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+/// Statements targeted by ER and CR
+const STATEMENTS: &[&str] = &[
+    nodes::EXPRESSION_STATEMENT,
+    nodes::RETURN_STATEMENT,
+    nodes::IF_STATEMENT,
+    nodes::WHILE_STATEMENT,
+    nodes::FOR_STATEMENT,
+    nodes::DO_STATEMENT,
+];
+
+/// Operators shuffled by AOS
+pub(crate) const ARITHMETIC_OPERATORS: &[&str] = &["+", "-", "*", "/", "%"];
+
+/// Functions whose calls FCR deletes
+pub(crate) const FREE_FUNCTIONS: &[&str] = &["free"];
+
+/// Functions whose calls MCR deletes; these typically scrub secrets or reset state
+pub(crate) const MEMSET_FUNCTIONS: &[&str] = &["memset", "memset_s", "explicit_bzero"];
+
+pub struct CLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for CLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(C_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+}
+
+impl LanguageEngine for CLanguageEngine {
+    fn name(&self) -> &'static str {
+        "C"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["c", "h"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        C_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_c()) })
+            .clone()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let source = &target.text;
+        let tree = match parse_source(source, &self.tree_sitter_language()) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Names declared as pointers or arrays at file scope or in the function around `node`,
+/// including its parameters. Shadowing is ignored.
+fn declared_pointers<'a>(node: &Node, src: &'a str) -> HashSet<&'a str> {
+    let mut scopes: Vec<Node> = patterns::enclosing(*node, &[nodes::FUNCTION_DEFINITION])
+        .into_iter()
+        .collect();
+    let mut root = *node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let mut cursor = root.walk();
+    scopes.extend(
+        root.named_children(&mut cursor)
+            .filter(|n| n.kind() == nodes::DECLARATION),
+    );
+
+    let mut names = HashSet::new();
+    let mut pending = scopes;
+    while let Some(n) = pending.pop() {
+        if matches!(
+            n.kind(),
+            nodes::POINTER_DECLARATOR | nodes::ARRAY_DECLARATOR
+        ) {
+            let mut declarator = n;
+            while let Some(inner) = declarator.child_by_field_name(fields::DECLARATOR) {
+                declarator = inner;
+            }
+            if declarator.kind() == nodes::IDENTIFIER {
+                names.insert(node_text(&declarator, src));
+            }
+        }
+        let mut cursor = n.walk();
+        pending.extend(n.named_children(&mut cursor));
+    }
+    names
+}
+
+/// Whether `expr` evaluates to a pointer: a declared pointer or array, an address, a string,
+/// a pointer cast, or an offset from one of those
+fn is_pointer(expr: Node, src: &str, pointers: &HashSet<&str>) -> bool {
+    match expr.kind() {
+        nodes::IDENTIFIER => pointers.contains(node_text(&expr, src)),
+        nodes::STRING_LITERAL => true,
+        nodes::PARENTHESIZED_EXPRESSION => expr
+            .named_child(0)
+            .is_some_and(|inner| is_pointer(inner, src, pointers)),
+        nodes::POINTER_EXPRESSION => expr
+            .child_by_field_name(fields::OPERATOR)
+            .is_some_and(|o| o.kind() == "&"),
+        nodes::CAST_EXPRESSION => expr
+            .child_by_field_name(fields::TYPE)
+            .is_some_and(|ty| node_text(&ty, src).contains('*')),
+        nodes::BINARY_EXPRESSION => {
+            let operand = |field| {
+                expr.child_by_field_name(field)
+                    .is_some_and(|o| is_pointer(o, src, pointers))
+            };
+            match expr.child_by_field_name(fields::OPERATOR).map(|o| o.kind()) {
+                Some(nodes::PLUS) => operand(fields::LEFT) || operand(fields::RIGHT),
+                // The difference of two pointers is an integer
+                Some(nodes::MINUS) => operand(fields::LEFT) && !operand(fields::RIGHT),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is the offset dereferenced by `*( )`, as in `*(p + n)` or `*(p + n - 1)`
+fn is_dereferenced(expr: Node) -> bool {
+    let Some(parent) = expr.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        nodes::PARENTHESIZED_EXPRESSION => is_dereferenced(parent),
+        nodes::POINTER_EXPRESSION => parent
+            .child_by_field_name(fields::OPERATOR)
+            .is_some_and(|o| o.kind() == "*"),
+        nodes::BINARY_EXPRESSION => {
+            parent.child_by_field_name(fields::LEFT) == Some(expr) && is_dereferenced(parent)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a binary expression has a pointer on either side, so AOS leaves it to PAS
+fn has_pointer_operand(expr: Node, src: &str) -> bool {
+    if is_dereferenced(expr) {
+        return true;
+    }
+    let pointers = declared_pointers(&expr, src);
+    [fields::LEFT, fields::RIGHT].iter().any(|field| {
+        expr.child_by_field_name(field)
+            .is_some_and(|o| is_pointer(o, src, &pointers))
+    })
+}
+
+/// Shuffle an arithmetic operator, except in pointer arithmetic where only PAS's `+`/`-`
+/// swap compiles
+pub(crate) fn shuffle_arithmetic(op: &Node, src: &str) -> Vec<String> {
+    let Some(expr) = op.parent().filter(|p| p.kind() == nodes::BINARY_EXPRESSION) else {
+        return Vec::new();
+    };
+    if has_pointer_operand(expr, src) {
+        return Vec::new();
+    }
+    ARITHMETIC_OPERATORS
+        .iter()
+        .filter(|o| **o != op.kind())
+        .map(|o| o.to_string())
+        .collect()
+}
+
+/// Swap `+`/`-` in arithmetic that yields a pointer, as in `p + n` or `*(p + n)`
+pub(crate) fn swap_pointer_arithmetic(op: &Node, src: &str) -> Option<String> {
+    let expr = op.parent()?;
+    if expr.kind() != nodes::BINARY_EXPRESSION {
+        return None;
+    }
+    if !is_dereferenced(expr) && !is_pointer(expr, src, &declared_pointers(&expr, src)) {
+        return None;
+    }
+    match op.kind() {
+        nodes::PLUS => Some("-".to_string()),
+        nodes::MINUS => Some("+".to_string()),
+        _ => None,
+    }
+}
+
+/// Rewrite `a[i]` to `a[i <delta>]`. C++ wraps indices in a `subscript_argument_list`.
+pub(crate) fn shift_index(node: &Node, src: &str, delta: &str) -> Option<String> {
+    let index = node
+        .child_by_field_name(fields::INDEX)
+        .or_else(|| node.child_by_field_name("indices")?.named_child(0))?;
+    let index_text = node_text(&index, src);
+    let shifted = match index.kind() {
+        nodes::IDENTIFIER | nodes::NUMBER_LITERAL | nodes::FIELD_EXPRESSION => {
+            format!("{index_text} {delta}")
+        }
+        _ => format!("({index_text}) {delta}"),
+    };
+    Some(format!(
+        "{}{}{}",
+        &src[node.start_byte()..index.start_byte()],
+        shifted,
+        &src[index.end_byte()..node.end_byte()]
+    ))
+}
+
+/// Delete an expression statement that is a bare call to one of `functions`
+/// (optionally `std::`-qualified)
+pub(crate) fn remove_call(node: &Node, src: &str, functions: &[&str]) -> Option<String> {
+    let call = node.named_child(0)?;
+    if call.kind() != nodes::CALL_EXPRESSION {
+        return None;
+    }
+    let callee = node_text(&call.child_by_field_name(fields::FUNCTION)?, src);
+    let name = callee.strip_prefix("std::").unwrap_or(callee);
+    functions.contains(&name).then(String::new)
+}
+
+/// Return value defaults by declared type; `0` also serves pointers, enums and chars
+const RETURN_DEFAULTS: patterns::DefaultTable = &[("struct*", ""), ("union*", ""), ("*", "0")];
+
+/// The declared return type of the function a `return` belongs to
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(node, &[nodes::FUNCTION_DEFINITION])?;
+    let ty = function.child_by_field_name(fields::TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "abort();", |node, src| {
+                    let text = node_text(node, src);
+                    // Do not replace statements that already abort
+                    !text.contains("abort(")
+                }),
+            ),
+            "CR" => set.add(m.slug, patterns::Wrap::new(STATEMENTS, "/* ", " */")),
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "0",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "1",
+                ),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "0",
+                ),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::TRUE, nodes::FALSE], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STRING_LITERAL], |node, src| {
+                    if node
+                        .parent()
+                        .is_some_and(|p| p.kind() == nodes::PREPROC_INCLUDE)
+                    {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("~", "")],
+                ),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UPDATE_EXPRESSION],
+                    &[("++", "--"), ("--", "++")],
+                ),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
+                ),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(ARITHMETIC_OPERATORS, shuffle_arithmetic),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_EXPRESSION],
+                    &["+=", "-=", "*=", "/=", "%="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_EXPRESSION],
+                    &["&=", "|=", "^="],
+                ),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
+                ),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::ASSIGNMENT_EXPRESSION], &["<<=", ">>="]),
+            ),
+            // C-specific mutations
+            "PAS" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::PLUS, nodes::MINUS], swap_pointer_arithmetic),
+            ),
+            "AIO" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::SUBSCRIPT_EXPRESSION], |node, src| {
+                        shift_index(node, src, "+ 1")
+                    }),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::SUBSCRIPT_EXPRESSION], |node, src| {
+                        shift_index(node, src, "- 1")
+                    }),
+                );
+            }
+            "FCR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    remove_call(node, src, FREE_FUNCTIONS)
+                }),
+            ),
+            "MCR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    remove_call(node, src, MEMSET_FUNCTIONS)
+                }),
+            ),
+            _ => {
+                panic!("Unknown mutation slug encountered in C engine: {}", m.slug);
+            }
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = CLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(
+            dups.is_empty(),
+            "Duplicate mutation slugs found in C engine: {dups:?}",
+        );
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text = "int main(void) {\n    return 0;\n}\n";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.c"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "C".to_string(),
+        };
+        let engine = CLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::types::{Mutation, MutationSeverity};
+
+/// Mutations shared by the C and C++ engines
+pub const C_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "PAS",
+        description: "Pointer Arithmetic Swap: Swap + and - in dereferenced pointer arithmetic (*(p + n))",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "AIO",
+        description: "Array Index Off-by-one: Replace an array index i with i + 1 or i - 1",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "FCR",
+        description: "Free Call Removal: Delete a call to free()",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "MCR",
+        description: "Memset Call Removal: Delete a call to memset(), memset_s() or explicit_bzero()",
+        severity: MutationSeverity::High,
+    },
+];
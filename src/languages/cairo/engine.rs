@@ -0,0 +1,353 @@
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, Target};
+use crate::utils::{node_text, parse_source};
+
+use super::mutations::CAIRO_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static CAIRO_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_cairo() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+/// Statements targeted by ER and CR
+const STATEMENTS: &[&str] = &[nodes::EXPRESSION_STATEMENT, nodes::LET_STATEMENT];
+
+/// ByteArray strings and felt252 short strings, both emptied by SLR
+const STRINGS: &[&str] = &[nodes::STRING_LITERAL, nodes::SHORT_STRING_LITERAL];
+
+pub struct CairoLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for CairoLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CairoLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(CAIRO_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+}
+
+impl LanguageEngine for CairoLanguageEngine {
+    fn name(&self) -> &'static str {
+        "Cairo"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["cairo"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        CAIRO_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_cairo()) })
+            .clone()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let tree = match parse_source(&target.text, &self.tree_sitter_language()) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Whether a statement already fails on its own (assert or panic)
+fn is_failing_statement(text: &str) -> bool {
+    text.starts_with("assert") || text.contains("panic")
+}
+
+/// Cairo only has line comments, so CR prefixes every line of the outermost statement
+fn comment_out(node: &Node, src: &str) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if STATEMENTS.contains(&parent.kind()) {
+            return None;
+        }
+        current = parent.parent();
+    }
+    let text = node_text(node, src);
+    Some(format!("// {}", text.replace('\n', "\n// ")))
+}
+
+/// Any returned type with a `Default` impl can be replaced by its default
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    ("impl *", ""),
+    ("!", ""),
+    ("()", ""),
+    ("*", "Default::default()"),
+];
+
+/// The value of a `return` or the tail expression of a function body
+fn returned_value(node: Node) -> Option<Node> {
+    if node.kind() != nodes::BLOCK {
+        return patterns::returned_expression(node);
+    }
+    let function = node.parent()?;
+    if function.kind() != nodes::FUNCTION_ITEM
+        || function.child_by_field_name(fields::BODY) != Some(node)
+    {
+        return None;
+    }
+    let tail = node.named_child(node.named_child_count().checked_sub(1)?)?;
+    let kind = tail.kind();
+    let is_statement = kind == nodes::EXPRESSION_STATEMENT
+        || kind.ends_with("_item")
+        || kind.ends_with("declaration")
+        || kind.contains("comment");
+    (!is_statement).then_some(tail)
+}
+
+/// The declared return type of the function a value is returned from; closures are skipped
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = if node.kind() == nodes::BLOCK {
+        node.parent()?
+    } else {
+        patterns::enclosing(node, &[nodes::FUNCTION_ITEM, nodes::CLOSURE_EXPRESSION])?
+    };
+    if function.kind() != nodes::FUNCTION_ITEM {
+        return None;
+    }
+    let ty = function.child_by_field_name(fields::RETURN_TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "panic!(\"mewt\");", |node, src| {
+                    // Do not replace statements that already assert or panic
+                    !is_failing_statement(node_text(node, src))
+                }),
+            ),
+            "CR" => set.add(m.slug, patterns::ReplaceWith::new(STATEMENTS, comment_out)),
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
+                ),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_EXPRESSION,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
+                ),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_EXPRESSION, nodes::CONTINUE_EXPRESSION],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::BOOLEAN], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_EXPRESSION, nodes::BLOCK],
+                    RETURN_DEFAULTS,
+                    returned_value,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::INTEGER_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(STRINGS, |node, src| {
+                    if is_structural_string(node, src) {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::INTEGER_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(&[nodes::UNARY_EXPRESSION], &[("!", ""), ("-", "")]),
+            ),
+            "UOR" => {
+                // No increment or decrement operators
+            }
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
+                ),
+            ),
+            // Shared operator shuffles; felt252 supports + - * / and integers add %
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+", "-", "*", "/", "%"],
+                ),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::COMPOUND_ASSIGNMENT],
+                    &["+=", "-=", "*=", "/=", "%="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::COMPOUND_ASSIGNMENT], &["&=", "|=", "^="]),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
+                ),
+            ),
+            // Cairo-specific mutations
+            "ASR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    node_text(node, src).starts_with("assert").then(String::new)
+                }),
+            ),
+            // Cairo has no shift operators
+            "SOS" | "SAOS" => {}
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in Cairo engine: {}",
+                    m.slug
+                );
+            }
+        }
+    }
+    set
+}
+
+/// String literals the program cannot compile without changing: attribute arguments
+/// and format strings inside macro invocations
+fn is_structural_string(node: &Node, src: &str) -> bool {
+    if node
+        .parent()
+        .is_some_and(|p| p.kind() == nodes::TOKEN_TREE && node_text(node, src).contains('{'))
+    {
+        return true;
+    }
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if n.kind() == nodes::ATTRIBUTE_ITEM {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = CairoLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(
+            dups.is_empty(),
+            "Duplicate mutation slugs found in Cairo engine: {dups:?}",
+        );
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text = "fn main() -> felt252 {\n    let x = 1 + 2;\n    x\n}\n";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.cairo"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "Cairo".to_string(),
+        };
+        let engine = CairoLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
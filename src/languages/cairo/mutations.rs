@@ -0,0 +1,7 @@
+use crate::types::{Mutation, MutationSeverity};
+
+pub const CAIRO_MUTATIONS: &[Mutation] = &[Mutation {
+    slug: "ASR",
+    description: "Assert Removal: Delete an assert statement (assert, assert!, assert_eq!, ...)",
+    severity: MutationSeverity::Medium,
+}];
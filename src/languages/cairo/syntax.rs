@@ -0,0 +1,32 @@
+//! Node kinds of the vendored Cairo grammar
+
+pub mod nodes {
+    pub const BINARY_EXPRESSION: &str = "binary_expression";
+    pub const COMPOUND_ASSIGNMENT: &str = "compound_assignment_expr";
+    pub const BOOLEAN: &str = "boolean_literal";
+    pub const CALL_EXPRESSION: &str = "call_expression";
+    pub const MACRO_INVOCATION: &str = "macro_invocation";
+    pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const LET_STATEMENT: &str = "let_declaration";
+    pub const IF_EXPRESSION: &str = "if_expression";
+    pub const WHILE_EXPRESSION: &str = "while_expression";
+    pub const BREAK_EXPRESSION: &str = "break_expression";
+    pub const CONTINUE_EXPRESSION: &str = "continue_expression";
+    pub const INTEGER_LITERAL: &str = "integer_literal";
+    pub const STRING_LITERAL: &str = "string_literal";
+    pub const SHORT_STRING_LITERAL: &str = "short_string_literal";
+    pub const ATTRIBUTE_ITEM: &str = "attribute_item";
+    pub const TOKEN_TREE: &str = "token_tree";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const FUNCTION_ITEM: &str = "function_item";
+    pub const CLOSURE_EXPRESSION: &str = "closure_expression";
+    pub const BLOCK: &str = "block";
+    pub const RETURN_EXPRESSION: &str = "return_expression";
+}
+
+pub mod fields {
+    pub const CONDITION: &str = "condition";
+    pub const ARGUMENTS: &str = "arguments";
+    pub const BODY: &str = "body";
+    pub const RETURN_TYPE: &str = "return_type";
+}
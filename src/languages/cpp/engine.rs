@@ -0,0 +1,344 @@
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, Target};
+use crate::utils::{node_text, parse_source};
+
+use crate::languages::c::engine::{
+    ARITHMETIC_OPERATORS, FREE_FUNCTIONS, MEMSET_FUNCTIONS, remove_call, shift_index,
+    shuffle_arithmetic, swap_pointer_arithmetic,
+};
+use crate::languages::c::mutations::C_MUTATIONS;
+
+use super::mutations::CPP_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static CPP_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_cpp() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+/// Statements targeted by ER and CR
+const STATEMENTS: &[&str] = &[
+    nodes::EXPRESSION_STATEMENT,
+    nodes::RETURN_STATEMENT,
+    nodes::IF_STATEMENT,
+    nodes::WHILE_STATEMENT,
+    nodes::FOR_STATEMENT,
+    nodes::FOR_RANGE_LOOP,
+    nodes::DO_STATEMENT,
+];
+
+pub struct CppLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for CppLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CppLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(C_MUTATIONS);
+        mutations.extend_from_slice(CPP_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+}
+
+impl LanguageEngine for CppLanguageEngine {
+    fn name(&self) -> &'static str {
+        "C++"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["cc", "cpp", "cxx", "hh", "hpp", "hxx"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        CPP_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_cpp()) })
+            .clone()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let source = &target.text;
+        let tree = match parse_source(source, &self.tree_sitter_language()) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Return value defaults by declared type: value-initialize anything but deduced types
+const RETURN_DEFAULTS: patterns::DefaultTable = &[("auto", ""), ("decltype*", ""), ("*", "{}")];
+
+/// The declared return type of the function a `return` belongs to; lambdas are skipped
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(
+        node,
+        &[nodes::FUNCTION_DEFINITION, nodes::LAMBDA_EXPRESSION],
+    )?;
+    if function.kind() != nodes::FUNCTION_DEFINITION {
+        return None;
+    }
+    let ty = function.child_by_field_name(fields::TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "std::abort();", |node, src| {
+                    let text = node_text(node, src);
+                    // Do not replace statements that already abort
+                    !text.contains("abort(")
+                }),
+            ),
+            "CR" => set.add(m.slug, patterns::Wrap::new(STATEMENTS, "/* ", " */")),
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
+                ),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
+                ),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::TRUE, nodes::FALSE], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STRING_LITERAL], |node, src| {
+                    if node
+                        .parent()
+                        .is_some_and(|p| p.kind() == nodes::PREPROC_INCLUDE)
+                    {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("~", "")],
+                ),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UPDATE_EXPRESSION],
+                    &[("++", "--"), ("--", "++")],
+                ),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
+                ),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(ARITHMETIC_OPERATORS, shuffle_arithmetic),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_EXPRESSION],
+                    &["+=", "-=", "*=", "/=", "%="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_EXPRESSION],
+                    &["&=", "|=", "^="],
+                ),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
+                ),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::ASSIGNMENT_EXPRESSION], &["<<=", ">>="]),
+            ),
+            // C mutations, shared with the C engine
+            "PAS" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::PLUS, nodes::MINUS], swap_pointer_arithmetic),
+            ),
+            "AIO" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::SUBSCRIPT_EXPRESSION], |node, src| {
+                        shift_index(node, src, "+ 1")
+                    }),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::SUBSCRIPT_EXPRESSION], |node, src| {
+                        shift_index(node, src, "- 1")
+                    }),
+                );
+            }
+            "FCR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    remove_call(node, src, FREE_FUNCTIONS)
+                }),
+            ),
+            "MCR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    remove_call(node, src, MEMSET_FUNCTIONS)
+                }),
+            ),
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in C++ engine: {}",
+                    m.slug
+                );
+            }
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = CppLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(
+            dups.is_empty(),
+            "Duplicate mutation slugs found in C++ engine: {dups:?}",
+        );
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text = "int main() {\n    return 0;\n}\n";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.cpp"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "C++".to_string(),
+        };
+        let engine = CppLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
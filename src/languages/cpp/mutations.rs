@@ -0,0 +1,3 @@
+use crate::types::Mutation;
+
+pub const CPP_MUTATIONS: &[Mutation] = &[];
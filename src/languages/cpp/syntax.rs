@@ -0,0 +1,32 @@
+pub mod nodes {
+    pub const BINARY_EXPRESSION: &str = "binary_expression";
+    pub const ASSIGNMENT_EXPRESSION: &str = "assignment_expression";
+    pub const SUBSCRIPT_EXPRESSION: &str = "subscript_expression";
+    pub const CALL_EXPRESSION: &str = "call_expression";
+    pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const RETURN_STATEMENT: &str = "return_statement";
+    pub const IF_STATEMENT: &str = "if_statement";
+    pub const WHILE_STATEMENT: &str = "while_statement";
+    pub const FOR_STATEMENT: &str = "for_statement";
+    pub const FOR_RANGE_LOOP: &str = "for_range_loop";
+    pub const DO_STATEMENT: &str = "do_statement";
+    pub const BREAK_STATEMENT: &str = "break_statement";
+    pub const CONTINUE_STATEMENT: &str = "continue_statement";
+    pub const TRUE: &str = "true";
+    pub const FALSE: &str = "false";
+    pub const PLUS: &str = "+";
+    pub const MINUS: &str = "-";
+    pub const NUMBER_LITERAL: &str = "number_literal";
+    pub const STRING_LITERAL: &str = "string_literal";
+    pub const PREPROC_INCLUDE: &str = "preproc_include";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const UPDATE_EXPRESSION: &str = "update_expression";
+    pub const FUNCTION_DEFINITION: &str = "function_definition";
+    pub const LAMBDA_EXPRESSION: &str = "lambda_expression";
+}
+
+pub mod fields {
+    pub const CONDITION: &str = "condition";
+    pub const ARGUMENTS: &str = "arguments";
+    pub const TYPE: &str = "type";
+}
@@ -1,9 +1,9 @@
 use std::sync::OnceLock;
-use tree_sitter::Language as TsLanguage;
+use tree_sitter::{Language as TsLanguage, Node};
 
 use crate::LanguageEngine;
 use crate::mutations::COMMON_MUTATIONS;
-use crate::patterns;
+use crate::patterns::{self, PatternSet};
 use crate::types::{Mutant, Mutation, Target};
 use crate::utils::{node_text, parse_source};
 
@@ -18,6 +18,7 @@ unsafe extern "C" {
 
 pub struct GoLanguageEngine {
     mutations: Vec<Mutation>,
+    patterns: PatternSet,
 }
 
 impl Default for GoLanguageEngine {
@@ -31,7 +32,11 @@ impl GoLanguageEngine {
         let mut mutations: Vec<Mutation> = Vec::new();
         mutations.extend_from_slice(COMMON_MUTATIONS);
         mutations.extend_from_slice(GO_MUTATIONS);
-        Self { mutations }
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
     }
 }
 
@@ -55,162 +60,397 @@ impl LanguageEngine for GoLanguageEngine {
     }
 
     fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
         let source = &target.text;
         let tree = match parse_source(source, &self.tree_sitter_language()) {
             Some(t) => t,
             None => return Vec::new(),
         };
         let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Expressions that can appear as the bare condition of a `for cond { }` loop
+const LOOP_CONDITIONS: &[&str] = &[
+    nodes::BINARY_EXPRESSION,
+    nodes::UNARY_EXPRESSION,
+    nodes::CALL_EXPRESSION,
+    nodes::IDENTIFIER,
+    nodes::SELECTOR_EXPRESSION,
+    nodes::PARENTHESIZED_EXPRESSION,
+    nodes::TRUE,
+];
+
+/// Conventional names for error values: `err`, `readErr`, ...
+fn is_error_name(name: &str) -> bool {
+    name == "err" || name.ends_with("Err")
+}
+
+/// Replace the error in the last position of a return statement with nil
+fn nil_returned_error(node: &Node, src: &str) -> Option<String> {
+    let list = node.parent()?;
+    let returns = list.kind() == nodes::EXPRESSION_LIST
+        && list.parent()?.kind() == nodes::RETURN_STATEMENT
+        && list.named_child(list.named_child_count().checked_sub(1)?) == Some(*node);
+    (returns && is_error_name(node_text(node, src))).then(|| "nil".to_string())
+}
+
+/// Flip the operator of an `err != nil` / `err == nil` check
+fn negate_nil_check(node: &Node, src: &str) -> Option<String> {
+    let check = node.parent()?;
+    if check.kind() != nodes::BINARY_EXPRESSION {
+        return None;
+    }
+    let left = check.child_by_field_name(fields::LEFT)?;
+    let right = check.child_by_field_name(fields::RIGHT)?;
+    if right.kind() != nodes::NIL || !is_error_name(node_text(&left, src)) {
+        return None;
+    }
+    match node.kind() {
+        nodes::NOT_EQ => Some("==".to_string()),
+        nodes::EQ => Some("!=".to_string()),
+        _ => None,
+    }
+}
+
+const COMPARISON_OPERATORS: &[&str] = &["==", "!=", "<", "<=", ">", ">="];
+
+/// The other comparison operators for a comparison. Nil can only be compared for
+/// equality, so a nil check just flips, and error nil checks are left to `ENN`.
+fn shuffled_comparison(node: &Node, src: &str) -> Vec<String> {
+    let Some(comparison) = node
+        .parent()
+        .filter(|p| p.kind() == nodes::BINARY_EXPRESSION)
+    else {
+        return Vec::new();
+    };
+    let token = node_text(node, src);
+    let operands = [fields::LEFT, fields::RIGHT].map(|f| comparison.child_by_field_name(f));
+    if operands.iter().flatten().any(|o| o.kind() == nodes::NIL) {
+        if operands
+            .iter()
+            .flatten()
+            .any(|o| is_error_name(node_text(o, src)))
+        {
+            return Vec::new();
+        }
+        return match token {
+            "==" => vec!["!=".to_string()],
+            "!=" => vec!["==".to_string()],
+            _ => Vec::new(),
+        };
+    }
+    COMPARISON_OPERATORS
+        .iter()
+        .filter(|op| **op != token)
+        .map(|op| op.to_string())
+        .collect()
+}
+
+/// Shift each present bound of `s[a:b]` by one in both directions. A literal 0
+/// lower bound is not decremented since Go rejects negative constant indices.
+fn shifted_slice_bounds(node: &Node, src: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    for field in [fields::START, fields::END] {
+        let Some(bound) = node.child_by_field_name(field) else {
+            continue;
+        };
+        let text = node_text(&bound, src);
+        let prefix = &src[node.start_byte()..bound.start_byte()];
+        let suffix = &src[bound.end_byte()..node.end_byte()];
+        out.push(format!("{prefix}{text} + 1{suffix}"));
+        if !(bound.kind() == nodes::INT_LITERAL && text == "0") {
+            out.push(format!("{prefix}{text} - 1{suffix}"));
+        }
+    }
+    out
+}
+
+/// Return value defaults by declared type; structs and arrays have no literal default
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    // Pointers
+    ("**", "nil"),
+    ("[]*", "nil"),
+    ("map[*", "nil"),
+    ("chan*", "nil"),
+    ("<-chan*", "nil"),
+    ("func*", "nil"),
+    ("interface*", "nil"),
+    ("error", "nil"),
+    ("any", "nil"),
+    ("bool", "false"),
+    ("string", "\"\""),
+    ("int*", "0"),
+    ("uint*", "0"),
+    ("float*", "0"),
+    ("complex*", "0"),
+    ("byte", "0"),
+    ("rune", "0"),
+];
+
+/// The single value of a `return`; multi-value returns are left to `ERN`
+fn returned_value(node: Node) -> Option<Node> {
+    let values = patterns::returned_expression(node)?;
+    if values.kind() != nodes::EXPRESSION_LIST {
+        return Some(values);
+    }
+    if values.named_child_count() == 1 {
+        values.named_child(0)
+    } else {
+        None
+    }
+}
+
+/// The declared result type of the function a `return` belongs to, if it has exactly one
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(
+        node,
+        &[
+            nodes::FUNCTION_DECLARATION,
+            nodes::METHOD_DECLARATION,
+            nodes::FUNC_LITERAL,
+        ],
+    )?;
+    let mut result = function.child_by_field_name(fields::RESULT)?;
+    if result.kind() == nodes::PARAMETER_LIST {
+        if result.named_child_count() != 1 {
+            return None;
+        }
+        result = result.named_child(0)?.child_by_field_name(fields::TYPE)?;
+    }
+    Some(node_text(&result, src).to_string())
+}
 
-        let mut all_mutants = Vec::new();
-        for m in &self.mutations {
-            match m.slug {
-                "ER" => {
-                    all_mutants.extend(
-                        patterns::replace(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                            ],
-                            "panic(\"mewt\")",
-                            &|node, src| {
-                                let text = node_text(node, src);
-                                // Do not replace statements that already contain a panic
-                                !text.contains("panic(")
-                            },
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "ER")),
-                    );
-                }
-                "CR" => {
-                    all_mutants.extend(
-                        patterns::wrap(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                            ],
-                            "/* ",
-                            " */",
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "CR")),
-                    );
-                }
-                "IF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IF")),
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => {
+                set.add(
+                    m.slug,
+                    patterns::Replace::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                        ],
+                        "panic(\"mewt\")",
+                        |node, src| {
+                            let text = node_text(node, src);
+                            // Do not replace statements that already contain a panic
+                            !text.contains("panic(")
+                        },
+                    ),
+                );
+            }
+            "CR" => {
+                set.add(
+                    m.slug,
+                    patterns::Wrap::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                        ],
+                        "/* ",
+                        " */",
+                    ),
+                );
+            }
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
                 ),
-                "IT" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "true",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IT")),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(LOOP_CONDITIONS, |node, _src| {
+                    // Only the bare condition of a while-style loop is a direct child
+                    (node.parent()?.kind() == nodes::FOR_STATEMENT).then(|| "false".to_string())
+                }),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    returned_value,
+                    return_type,
                 ),
-                "AS" => all_mutants.extend(
-                    patterns::swap_args(root, source, &[nodes::CALL_EXPRESSION], fields::ARGUMENTS)
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "AS")),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::INT_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(
+                    &[nodes::INTERPRETED_STRING_LITERAL, nodes::RAW_STRING_LITERAL],
+                    |node, src| {
+                        if node
+                            .parent()
+                            .is_some_and(|p| p.kind() == nodes::IMPORT_SPEC)
+                        {
+                            return None;
+                        }
+                        patterns::empty_string_literal(node_text(node, src))
+                    },
                 ),
-                // Shared operator shuffles
-                "AOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+", "-", "*", "/", "%"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AOS")),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::INT_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("^", "")],
                 ),
-                "BOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&", "|", "^", "&^"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BOS")),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::INC_STATEMENT, nodes::DEC_STATEMENT],
+                    &[("++", "--"), ("--", "++")],
                 ),
-                "BL" => all_mutants.extend(
-                    patterns::shuffle_nodes(root, source, &["true", "false"], &["true", "false"])
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "BL")),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
                 ),
-                "COS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["==", "!=", "<", "<=", ">", ">="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "COS")),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+", "-", "*", "/", "%"],
                 ),
-                "LOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&&", "||"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LOS")),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["&", "|", "^", "&^"],
                 ),
-                "SOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<", ">>"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SOS")),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&["true", "false"], &["true", "false"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(COMPARISON_OPERATORS, shuffled_comparison),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_STATEMENT],
+                    &["+=", "-=", "*=", "/=", "%="],
                 ),
-                "LC" => all_mutants.extend(
-                    patterns::shuffle_nodes(
-                        root,
-                        source,
-                        &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
-                        &["break", "continue"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LC")),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::ASSIGNMENT_STATEMENT],
+                    &["&=", "|=", "^=", "&^="],
                 ),
-                // Mutations not applicable to Go
-                "WF" | "RZ" | "AAOS" | "BAOS" | "SAOS" => {
-                    // Skip these mutations for Go
-                }
-                _ => {
-                    panic!("Unknown mutation slug encountered in Go engine: {}", m.slug);
-                }
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::ASSIGNMENT_STATEMENT], &["<<=", ">>="]),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
+                ),
+            ),
+            // Go-specific mutations
+            "ERN" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::IDENTIFIER], nil_returned_error),
+            ),
+            "ENN" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NOT_EQ, nodes::EQ], negate_nil_check),
+            ),
+            "DSR" => set.add(
+                m.slug,
+                patterns::Replace::new(&[nodes::DEFER_STATEMENT], "", |_, _| true),
+            ),
+            "GSC" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::GO_STATEMENT], |node, src| {
+                    node.named_child(0)
+                        .map(|call| node_text(&call, src).to_string())
+                }),
+            ),
+            "CSR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::SEND_STATEMENT], |node, src| {
+                    node.child_by_field_name(fields::CHANNEL)
+                        .map(|ch| format!("<-{}", node_text(&ch, src)))
+                }),
+            ),
+            "SBM" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::SLICE_EXPRESSION], shifted_slice_bounds),
+            ),
+            // Mutations not applicable to Go
+            "RZ" => {
+                // Skip these mutations for Go
+            }
+            _ => {
+                panic!("Unknown mutation slug encountered in Go engine: {}", m.slug);
             }
         }
-        all_mutants
     }
+    set
 }
 
 #[cfg(test)]
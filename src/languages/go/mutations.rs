@@ -1,3 +1,34 @@
-use crate::types::Mutation;
+use crate::types::{Mutation, MutationSeverity};
 
-pub const GO_MUTATIONS: &[Mutation] = &[];
+pub const GO_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "ERN",
+        description: "Error Return Nil: Replace a returned err with nil",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "ENN",
+        description: "Error Nil-check Negation: Negate err != nil / err == nil checks",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "DSR",
+        description: "Defer Statement Removal: Delete a defer statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "GSC",
+        description: "Goroutine Synchronous Call: Replace go f() with f()",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "CSR",
+        description: "Channel Send to Receive: Replace ch <- v with <-ch",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SBM",
+        description: "Slice Bounds Mutation: Shift a slice bound in s[a:b] by one",
+        severity: MutationSeverity::Medium,
+    },
+];
@@ -9,6 +9,30 @@ pub mod nodes {
     pub const BREAK_STATEMENT: &str = "break_statement";
     pub const CONTINUE_STATEMENT: &str = "continue_statement";
     pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const ASSIGNMENT_STATEMENT: &str = "assignment_statement";
+    pub const PARENTHESIZED_EXPRESSION: &str = "parenthesized_expression";
+    pub const SELECTOR_EXPRESSION: &str = "selector_expression";
+    pub const SLICE_EXPRESSION: &str = "slice_expression";
+    pub const EXPRESSION_LIST: &str = "expression_list";
+    pub const DEFER_STATEMENT: &str = "defer_statement";
+    pub const GO_STATEMENT: &str = "go_statement";
+    pub const SEND_STATEMENT: &str = "send_statement";
+    pub const IDENTIFIER: &str = "identifier";
+    pub const INT_LITERAL: &str = "int_literal";
+    pub const NIL: &str = "nil";
+    pub const TRUE: &str = "true";
+    pub const FALSE: &str = "false";
+    pub const EQ: &str = "==";
+    pub const NOT_EQ: &str = "!=";
+    pub const INTERPRETED_STRING_LITERAL: &str = "interpreted_string_literal";
+    pub const RAW_STRING_LITERAL: &str = "raw_string_literal";
+    pub const IMPORT_SPEC: &str = "import_spec";
+    pub const INC_STATEMENT: &str = "inc_statement";
+    pub const DEC_STATEMENT: &str = "dec_statement";
+    pub const FUNCTION_DECLARATION: &str = "function_declaration";
+    pub const METHOD_DECLARATION: &str = "method_declaration";
+    pub const FUNC_LITERAL: &str = "func_literal";
+    pub const PARAMETER_LIST: &str = "parameter_list";
 }
 
 pub mod fields {
@@ -17,4 +41,9 @@ pub mod fields {
     pub const LEFT: &str = "left";
     pub const RIGHT: &str = "right";
     pub const OPERATOR: &str = "operator";
+    pub const CHANNEL: &str = "channel";
+    pub const START: &str = "start";
+    pub const END: &str = "end";
+    pub const RESULT: &str = "result";
+    pub const TYPE: &str = "type";
 }
@@ -1,9 +1,9 @@
 use std::sync::OnceLock;
-use tree_sitter::Language as TsLanguage;
+use tree_sitter::{Language as TsLanguage, Node};
 
 use crate::LanguageEngine;
 use crate::mutations::COMMON_MUTATIONS;
-use crate::patterns;
+use crate::patterns::{self, PatternSet};
 use crate::types::{Mutant, Mutation, Target};
 use crate::utils::{node_text, parse_source};
 
@@ -11,17 +11,14 @@ use super::mutations::JAVASCRIPT_MUTATIONS;
 use super::syntax::{fields, nodes};
 
 static JS_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
-static TS_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
-static TSX_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
 
 unsafe extern "C" {
     fn tree_sitter_javascript() -> *const tree_sitter::ffi::TSLanguage;
-    fn tree_sitter_typescript() -> *const tree_sitter::ffi::TSLanguage;
-    fn tree_sitter_tsx() -> *const tree_sitter::ffi::TSLanguage;
 }
 
 pub struct JavaScriptLanguageEngine {
     mutations: Vec<Mutation>,
+    patterns: PatternSet,
 }
 
 impl Default for JavaScriptLanguageEngine {
@@ -35,7 +32,11 @@ impl JavaScriptLanguageEngine {
         let mut mutations: Vec<Mutation> = Vec::new();
         mutations.extend_from_slice(COMMON_MUTATIONS);
         mutations.extend_from_slice(JAVASCRIPT_MUTATIONS);
-        Self { mutations }
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
     }
 
     fn javascript_language(&self) -> TsLanguage {
@@ -43,26 +44,6 @@ impl JavaScriptLanguageEngine {
             .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_javascript()) })
             .clone()
     }
-
-    fn typescript_language(&self) -> TsLanguage {
-        TS_LANGUAGE
-            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_typescript()) })
-            .clone()
-    }
-
-    fn tsx_language(&self) -> TsLanguage {
-        TSX_LANGUAGE
-            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tsx()) })
-            .clone()
-    }
-
-    fn get_extension(target: &Target) -> Option<String> {
-        target
-            .path
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|s| s.to_string())
-    }
 }
 
 impl LanguageEngine for JavaScriptLanguageEngine {
@@ -71,11 +52,10 @@ impl LanguageEngine for JavaScriptLanguageEngine {
     }
 
     fn extensions(&self) -> &[&'static str] {
-        &["js", "ts", "jsx", "tsx"]
+        &["js", "jsx", "mjs", "cjs"]
     }
 
     fn tree_sitter_language(&self) -> TsLanguage {
-        // Default to JavaScript for compatibility
         self.javascript_language()
     }
 
@@ -84,214 +64,376 @@ impl LanguageEngine for JavaScriptLanguageEngine {
     }
 
     fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
         let source = &target.text;
-        let language = match Self::get_extension(target).as_deref() {
-            Some("ts") => self.typescript_language(),
-            Some("tsx") => self.tsx_language(),
-            Some("jsx") => self.javascript_language(), // JSX uses JS grammar
-            _ => self.javascript_language(),           // Default to JS
-        };
-        let tree = match parse_source(source, &language) {
+        // JSX is covered by the JavaScript grammar
+        let tree = match parse_source(source, &self.javascript_language()) {
             Some(t) => t,
             None => return Vec::new(),
         };
         let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
 
-        let mut all_mutants = Vec::new();
-        for m in &self.mutations {
-            match m.slug {
-                "ER" => {
-                    all_mutants.extend(
-                        patterns::replace(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::VARIABLE_DECLARATION,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                                nodes::FOR_IN_STATEMENT,
-                                nodes::DO_STATEMENT,
-                            ],
-                            "throw new Error(\"mewt\");",
-                            &|node, src| {
-                                let text = node_text(node, src);
-                                // Do not replace statements that already contain an error
-                                !text.contains("throw ")
-                            },
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "ER")),
-                    );
-                }
-                "CR" => {
-                    all_mutants.extend(
-                        patterns::wrap(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::VARIABLE_DECLARATION,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                                nodes::FOR_IN_STATEMENT,
-                                nodes::DO_STATEMENT,
-                            ],
-                            "/* ",
-                            " */",
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "CR")),
-                    );
-                }
-                "IF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IF")),
-                ),
-                "IT" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "true",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IT")),
+/// Untyped returns default to `null`; `ERV` covers `[]`, `""` and `undefined`
+const RETURN_DEFAULTS: patterns::DefaultTable = &[("*", "null")];
+
+fn return_type(_node: Node, _src: &str) -> Option<String> {
+    Some(String::new())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => {
+                set.add(
+                    m.slug,
+                    patterns::Replace::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::VARIABLE_DECLARATION,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                            nodes::FOR_IN_STATEMENT,
+                            nodes::DO_STATEMENT,
+                        ],
+                        "throw new Error(\"mewt\");",
+                        |node, src| {
+                            let text = node_text(node, src);
+                            // Do not replace statements that already contain an error
+                            !text.contains("throw ")
+                        },
+                    ),
+                );
+            }
+            "CR" => {
+                set.add(
+                    m.slug,
+                    patterns::Wrap::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::VARIABLE_DECLARATION,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                            nodes::FOR_IN_STATEMENT,
+                            nodes::DO_STATEMENT,
+                        ],
+                        "/* ",
+                        " */",
+                    ),
+                );
+            }
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
                 ),
-                "WF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::WHILE_STATEMENT,
-                        fields::CONDITION,
-                        &["while"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "WF")),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
                 ),
-                "AS" => all_mutants.extend(
-                    patterns::swap_args(root, source, &[nodes::CALL_EXPRESSION], fields::ARGUMENTS)
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "AS")),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
                 ),
-                "LC" => all_mutants.extend(
-                    patterns::shuffle_nodes(
-                        root,
-                        source,
-                        &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
-                        &["break", "continue"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LC")),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
                 ),
-                "BL" => all_mutants.extend(
-                    patterns::shuffle_nodes(root, source, &["true", "false"], &["true", "false"])
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "BL")),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&["true", "false"], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
                 ),
-                "AOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+", "-", "*", "/", "%", "**"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AOS")),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUMBER], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            // Module specifiers and directives such as "use strict" are skipped
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(
+                    &[nodes::STRING, nodes::TEMPLATE_STRING],
+                    |node, src| {
+                        if node.parent().is_some_and(|p| {
+                            [
+                                nodes::IMPORT_STATEMENT,
+                                nodes::EXPORT_STATEMENT,
+                                nodes::EXPRESSION_STATEMENT,
+                            ]
+                            .contains(&p.kind())
+                        }) {
+                            return None;
+                        }
+                        patterns::empty_string_literal(node_text(node, src))
+                    },
                 ),
-                "AAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+=", "-=", "*=", "/=", "%=", "**="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AAOS")),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUMBER], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("~", "")],
                 ),
-                "BOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&", "|", "^"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BOS")),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UPDATE_EXPRESSION],
+                    &[("++", "--"), ("--", "++")],
                 ),
-                "BAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&=", "|=", "^="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BAOS")),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
                 ),
-                "LOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&&", "||"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LOS")),
+            ),
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+", "-", "*", "/", "%", "**"],
                 ),
-                "COS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["==", "!=", "===", "!==", "<", "<=", ">", ">="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "COS")),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+=", "-=", "*=", "/=", "%=", "**="],
                 ),
-                "SOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<", ">>", ">>>"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SOS")),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&=", "|=", "^="]),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            // Equality operators are left to EQN and EQS
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["<", "<=", ">", ">="],
                 ),
-                "SAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<=", ">>=", ">>>="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SAOS")),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>", ">>>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["<<=", ">>=", ">>>="],
                 ),
-                _ => panic!("Unknown mutation slug: {}", m.slug),
-            }
+            ),
+            _ => add_javascript_pattern(&mut set, m.slug),
         }
+    }
+    set
+}
 
-        all_mutants
+/// Array methods with a plausible-but-wrong counterpart
+const ARRAY_METHOD_SWAPS: &[(&str, &str)] = &[
+    ("some", "every"),
+    ("every", "some"),
+    ("find", "filter"),
+    ("filter", "find"),
+    ("push", "unshift"),
+    ("unshift", "push"),
+];
+
+/// Register the pattern for a `JAVASCRIPT_MUTATIONS` slug; shared with the TypeScript engine
+pub(crate) fn add_javascript_pattern(set: &mut PatternSet, slug: &'static str) {
+    match slug {
+        "EQN" => set.add(
+            slug,
+            patterns::ReplaceWith::new(
+                &[
+                    nodes::EQ,
+                    nodes::NOT_EQ,
+                    nodes::STRICT_EQ,
+                    nodes::STRICT_NOT_EQ,
+                ],
+                |node, _src| {
+                    let negated = match node.kind() {
+                        nodes::EQ => nodes::NOT_EQ,
+                        nodes::NOT_EQ => nodes::EQ,
+                        nodes::STRICT_EQ => nodes::STRICT_NOT_EQ,
+                        _ => nodes::STRICT_EQ,
+                    };
+                    is_binary_operator(node).then(|| negated.to_string())
+                },
+            ),
+        ),
+        "EQS" => set.add(
+            slug,
+            patterns::ReplaceWith::new(
+                &[
+                    nodes::EQ,
+                    nodes::NOT_EQ,
+                    nodes::STRICT_EQ,
+                    nodes::STRICT_NOT_EQ,
+                ],
+                |node, _src| {
+                    let swapped = match node.kind() {
+                        nodes::EQ => nodes::STRICT_EQ,
+                        nodes::NOT_EQ => nodes::STRICT_NOT_EQ,
+                        nodes::STRICT_EQ => nodes::EQ,
+                        _ => nodes::NOT_EQ,
+                    };
+                    is_binary_operator(node).then(|| swapped.to_string())
+                },
+            ),
+        ),
+        "AR" => set.add(
+            slug,
+            patterns::ReplaceWith::new(&[nodes::AWAIT_EXPRESSION], |node, src| {
+                node.named_child(0)
+                    .map(|inner| node_text(&inner, src).to_string())
+            }),
+        ),
+        "OCR" => set.add(
+            slug,
+            patterns::ReplaceWith::new(
+                &[nodes::OPTIONAL_CHAIN, nodes::OPTIONAL_CHAIN_TOKEN],
+                |node, _src| {
+                    // a?.b -> a.b, but a?.[i] -> a[i] and f?.() -> f()
+                    match node.parent().map(|p| p.kind()) {
+                        Some(nodes::MEMBER_EXPRESSION) => Some(".".to_string()),
+                        Some(nodes::SUBSCRIPT_EXPRESSION) | Some(nodes::CALL_EXPRESSION) => {
+                            Some(String::new())
+                        }
+                        // The token inside an optional_chain node is handled via its parent
+                        _ => None,
+                    }
+                },
+            ),
+        ),
+        "NCS" => set.add(
+            slug,
+            patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["??", "||"]),
+        ),
+        "AMS" => set.add(
+            slug,
+            patterns::ReplaceWith::new(&[nodes::PROPERTY_IDENTIFIER], |node, src| {
+                // Only method calls: the member expression must be the callee
+                let member = node.parent()?;
+                let call = member.parent()?;
+                if call.kind() != nodes::CALL_EXPRESSION
+                    || call.child_by_field_name(fields::FUNCTION) != Some(member)
+                {
+                    return None;
+                }
+                let name = node_text(node, src);
+                ARRAY_METHOD_SWAPS
+                    .iter()
+                    .find(|(from, _)| *from == name)
+                    .map(|(_, to)| to.to_string())
+            }),
+        ),
+        "ERV" => set.add(
+            slug,
+            patterns::ReplaceWithEach::new(&[nodes::RETURN_STATEMENT], |node, src| {
+                let Some(value) = node.named_child(0) else {
+                    return Vec::new();
+                };
+                let head = &src[node.start_byte()..value.start_byte()];
+                let tail = &src[value.end_byte()..node.end_byte()];
+                let current = node_text(&value, src);
+                ["[]", "\"\"", "undefined"]
+                    .iter()
+                    .filter(|empty| **empty != current)
+                    .map(|empty| format!("{head}{empty}{tail}"))
+                    .collect()
+            }),
+        ),
+        "PRS" => set.add(
+            slug,
+            patterns::ReplaceWith::new(&[nodes::MEMBER_EXPRESSION], |node, src| {
+                let object = node.child_by_field_name(fields::OBJECT)?;
+                if node_text(&object, src) != "Promise" {
+                    return None;
+                }
+                let text = node_text(node, src);
+                if text.ends_with(".resolve") {
+                    Some(text.replace(".resolve", ".reject"))
+                } else if text.ends_with(".reject") {
+                    Some(text.replace(".reject", ".resolve"))
+                } else {
+                    None
+                }
+            }),
+        ),
+        _ => panic!("Unknown mutation slug: {slug}"),
     }
 }
 
+/// Whether a comparison token is the operator of a binary expression
+fn is_binary_operator(node: &tree_sitter::Node) -> bool {
+    node.parent()
+        .is_some_and(|p| p.kind() == nodes::BINARY_EXPRESSION)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
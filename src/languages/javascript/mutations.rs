@@ -1,3 +1,45 @@
-use crate::types::Mutation;
+use crate::types::{Mutation, MutationSeverity};
 
-pub const JAVASCRIPT_MUTATIONS: &[Mutation] = &[];
+/// Mutations shared by the JavaScript and TypeScript engines
+pub const JAVASCRIPT_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "EQN",
+        description: "Equality Negation: Swap === with !== and == with !=",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "EQS",
+        description: "Equality Strictness Swap: Swap == with === and != with !==",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "AR",
+        description: "Await Removal: Remove the await keyword from an expression",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "OCR",
+        description: "Optional Chaining Removal: Replace ?. with plain member access",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "NCS",
+        description: "Nullish Coalescing Swap: Swap ?? and ||",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "AMS",
+        description: "Array Method Swap: Swap some/every, find/filter and push/unshift",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "ERV",
+        description: "Empty Return Value: Return [], \"\" or undefined instead of the computed value",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "PRS",
+        description: "Promise Resolve Swap: Swap Promise.resolve and Promise.reject",
+        severity: MutationSeverity::Medium,
+    },
+];
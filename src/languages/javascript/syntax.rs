@@ -11,12 +11,29 @@ pub mod nodes {
     pub const VARIABLE_DECLARATION: &str = "variable_declaration";
     pub const BREAK_STATEMENT: &str = "break_statement";
     pub const CONTINUE_STATEMENT: &str = "continue_statement";
-    // TypeScript-specific node types
-    pub const TYPE_ARGUMENTS: &str = "type_arguments";
-    pub const TYPE_PARAMETERS: &str = "type_parameters";
+    pub const AWAIT_EXPRESSION: &str = "await_expression";
+    pub const MEMBER_EXPRESSION: &str = "member_expression";
+    pub const SUBSCRIPT_EXPRESSION: &str = "subscript_expression";
+    pub const PROPERTY_IDENTIFIER: &str = "property_identifier";
+    pub const OPTIONAL_CHAIN: &str = "optional_chain";
+    // Bare `?.` token, used directly by optional calls like `f?.()`
+    pub const OPTIONAL_CHAIN_TOKEN: &str = "?.";
+    pub const EQ: &str = "==";
+    pub const NOT_EQ: &str = "!=";
+    pub const STRICT_EQ: &str = "===";
+    pub const STRICT_NOT_EQ: &str = "!==";
+    pub const NUMBER: &str = "number";
+    pub const STRING: &str = "string";
+    pub const TEMPLATE_STRING: &str = "template_string";
+    pub const IMPORT_STATEMENT: &str = "import_statement";
+    pub const EXPORT_STATEMENT: &str = "export_statement";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const UPDATE_EXPRESSION: &str = "update_expression";
 }
 
 pub mod fields {
     pub const CONDITION: &str = "condition";
     pub const ARGUMENTS: &str = "arguments";
+    pub const FUNCTION: &str = "function";
+    pub const OBJECT: &str = "object";
 }
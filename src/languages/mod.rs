@@ -1,4 +1,11 @@
+pub mod c;
+pub mod cairo;
+pub mod cpp;
 pub mod go;
 pub mod javascript;
+pub mod r#move;
+pub mod python;
 pub mod rust;
 pub mod solidity;
+pub mod typescript;
+pub mod vyper;
@@ -0,0 +1,643 @@
+use std::ops::Range;
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, PartialMutant, Target};
+use crate::utils::{node_text, parse_source};
+
+use super::mutations::MOVE_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static MOVE_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_move() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+/// Statements targeted by ER and CR
+const STATEMENTS: &[&str] = &[nodes::EXPRESSION_STATEMENT, nodes::LET_STATEMENT];
+
+/// Byte strings and hex strings, both emptied by SLR
+const STRINGS: &[&str] = &[nodes::BYTE_STRING_LITERAL, nodes::HEX_STRING_LITERAL];
+
+/// Attributes marking items that only exist for `move test`
+const TEST_ATTRIBUTES: &[&str] = &["test", "test_only"];
+
+pub struct MoveLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for MoveLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(MOVE_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+}
+
+impl LanguageEngine for MoveLanguageEngine {
+    fn name(&self) -> &'static str {
+        "Move"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["move"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        MOVE_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_move()) })
+            .clone()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let source = &target.text;
+        let tree = match parse_source(source, &self.tree_sitter_language()) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        let mut mutants = self.patterns.apply(root, target, enabled);
+
+        // Unit tests live next to the code they test; mutating them proves nothing
+        let mut tests = Vec::new();
+        test_only_ranges(root, source, &mut tests);
+        mutants.retain(|m| {
+            !tests
+                .iter()
+                .any(|range| range.contains(&(m.byte_offset as usize)))
+        });
+        mutants
+    }
+}
+
+/// Byte ranges of `#[test]` and `#[test_only]` items, including whole test-only modules
+fn test_only_ranges(node: Node, src: &str, out: &mut Vec<Range<usize>>) {
+    let mut cursor = node.walk();
+    for item in node.named_children(&mut cursor) {
+        if is_test_only(&item, src) {
+            out.push(item.byte_range());
+        } else if item.named_child_count() > 0
+            && !matches!(item.kind(), nodes::FUNCTION_DEFINITION | nodes::BLOCK)
+        {
+            test_only_ranges(item, src, out);
+        }
+    }
+}
+
+fn is_test_only(item: &Node, src: &str) -> bool {
+    let mut cursor = item.walk();
+    item.children(&mut cursor)
+        .filter(|child| child.kind() == nodes::ATTRIBUTES)
+        .any(|attributes| {
+            let mut cursor = attributes.walk();
+            attributes
+                .named_children(&mut cursor)
+                .filter(|attribute| attribute.kind() == nodes::ATTRIBUTE)
+                .filter_map(|attribute| attribute.child_by_field_name(fields::NAME))
+                .any(|name| TEST_ATTRIBUTES.contains(&node_text(&name, src)))
+        })
+}
+
+/// Whether a statement already fails on its own (assert or abort)
+fn is_failing_statement(text: &str) -> bool {
+    text.starts_with("assert") || text.starts_with("abort")
+}
+
+/// CR prefixes every line of the outermost statement with a line comment
+fn comment_out(node: &Node, src: &str) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if STATEMENTS.contains(&parent.kind()) {
+            return None;
+        }
+        current = parent.parent();
+    }
+    let text = node_text(node, src);
+    Some(format!("// {}", text.replace('\n', "\n// ")))
+}
+
+/// Integers, booleans, addresses and byte vectors have defaults; structs, references
+/// and tuples don't
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    ("u8", "0"),
+    ("u16", "0"),
+    ("u32", "0"),
+    ("u64", "0"),
+    ("u128", "0"),
+    ("u256", "0"),
+    ("bool", "false"),
+    ("address", "@0x0"),
+    ("vector<*", "vector[]"),
+    ("*", ""),
+];
+
+/// The value of a `return` or the tail expression of a function body
+fn returned_value(node: Node) -> Option<Node> {
+    if node.kind() != nodes::BLOCK {
+        return patterns::returned_expression(node);
+    }
+    let function = node.parent()?;
+    if function.kind() != nodes::FUNCTION_DEFINITION
+        || function.child_by_field_name(fields::BODY) != Some(node)
+    {
+        return None;
+    }
+    let tail = node.named_child(node.named_child_count().checked_sub(1)?)?;
+    let kind = tail.kind();
+    let is_statement = kind.ends_with("statement")
+        || kind.ends_with("declaration")
+        || kind == "spec_block"
+        || kind.contains("comment");
+    (!is_statement).then_some(tail)
+}
+
+/// The declared return type of the function a value is returned from; lambdas are skipped
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = if node.kind() == nodes::BLOCK {
+        node.parent()?
+    } else {
+        patterns::enclosing(
+            node,
+            &[nodes::FUNCTION_DEFINITION, nodes::LAMBDA_EXPRESSION],
+        )?
+    };
+    if function.kind() != nodes::FUNCTION_DEFINITION {
+        return None;
+    }
+    let ty = function.child_by_field_name(fields::RETURN_TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// The unqualified name a call resolves to: `borrow_global_mut` for
+/// `borrow_global_mut<T>(addr)` and `transfer` for `coin::transfer(...)`
+fn called_name<'a>(call: &Node, src: &'a str) -> Option<&'a str> {
+    let mut function = call.child_by_field_name(fields::FUNCTION)?;
+    if function.kind() == nodes::GENERIC_NAME {
+        function = function.child_by_field_name(fields::NAME)?;
+    }
+    if function.kind() == nodes::SCOPED_IDENTIFIER {
+        function = function.child_by_field_name(fields::NAME)?;
+    }
+    Some(node_text(&function, src))
+}
+
+/// The variable or call a place expression starts from: `coin` for `*&mut coin.value[0]`
+fn place_root(node: Node) -> Node {
+    let mut current = node;
+    loop {
+        let inner = match current.kind() {
+            nodes::FIELD_EXPRESSION
+            | nodes::INDEX_EXPRESSION
+            | nodes::BORROW_EXPRESSION
+            | nodes::DEREFERENCE_EXPRESSION => current.child_by_field_name(fields::VALUE),
+            nodes::PARENTHESIZED_EXPRESSION => current.named_child(0),
+            _ => None,
+        };
+        match inner {
+            Some(inner) => current = inner,
+            None => return current,
+        }
+    }
+}
+
+/// Locals of a function holding a reference into global storage, such as
+/// `let balance = borrow_global_mut<Balance>(addr);` or `let value = &mut balance.value;`
+fn global_storage_refs(function: Node, src: &str) -> Vec<String> {
+    let mut refs: Vec<String> = Vec::new();
+    let mut pending = vec![function];
+    while let Some(node) = pending.pop() {
+        if node.kind() == nodes::LET_STATEMENT {
+            let pattern = node.child_by_field_name(fields::PATTERN);
+            let value = node.child_by_field_name(fields::VALUE);
+            if let (Some(pattern), Some(value)) = (pattern, value) {
+                let name = if pattern.kind() == nodes::MUT_PATTERN {
+                    pattern.named_child(1)
+                } else {
+                    Some(pattern)
+                };
+                if let Some(name) = name.filter(|n| n.kind() == nodes::IDENTIFIER) {
+                    if is_global_storage_place(value, src, &refs) {
+                        refs.push(node_text(&name, src).to_string());
+                    }
+                }
+            }
+        }
+        // Push children in reverse so lets are seen in source order
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        pending.extend(children.into_iter().rev());
+    }
+    refs
+}
+
+/// Whether an expression is a place in global storage: a `borrow_global_mut` call or a
+/// local holding a reference from one, possibly behind fields, indexes and borrows
+fn is_global_storage_place(node: Node, src: &str, refs: &[String]) -> bool {
+    let root = place_root(node);
+    match root.kind() {
+        nodes::CALL_EXPRESSION => called_name(&root, src) == Some("borrow_global_mut"),
+        nodes::IDENTIFIER => refs.iter().any(|r| r == node_text(&root, src)),
+        _ => false,
+    }
+}
+
+/// Whether an argument list lends a `&mut` reference into global storage
+fn lends_global_storage(arguments: Option<Node>, src: &str, refs: &[String]) -> bool {
+    let Some(arguments) = arguments else {
+        return false;
+    };
+    let mut cursor = arguments.walk();
+    arguments.named_children(&mut cursor).any(|argument| {
+        argument.kind() == nodes::BORROW_EXPRESSION
+            && node_text(&argument, src).starts_with("&mut")
+            && is_global_storage_place(argument, src, refs)
+    })
+}
+
+/// Statements that write global storage: `move_to` calls, assignments through a
+/// `borrow_global_mut` reference and calls handed `&mut` into one
+fn writes_global_storage(statement: &Node, src: &str, refs: &[String]) -> bool {
+    let Some(expression) = statement.named_child(0) else {
+        return false;
+    };
+    match expression.kind() {
+        nodes::CALL_EXPRESSION => {
+            called_name(&expression, src) == Some("move_to")
+                || lends_global_storage(
+                    expression.child_by_field_name(fields::ARGUMENTS),
+                    src,
+                    refs,
+                )
+        }
+        nodes::METHOD_CALL_EXPRESSION => {
+            expression
+                .child_by_field_name(fields::RECEIVER)
+                .is_some_and(|receiver| is_global_storage_place(receiver, src, refs))
+                || lends_global_storage(
+                    expression.child_by_field_name(fields::ARGUMENTS),
+                    src,
+                    refs,
+                )
+        }
+        nodes::ASSIGNMENT_EXPRESSION | nodes::COMPOUND_ASSIGNMENT => expression
+            .child_by_field_name(fields::LEFT)
+            .is_some_and(|left| is_global_storage_place(left, src, refs)),
+        _ => false,
+    }
+}
+
+/// GSD visits each function once, collecting the locals that borrow from global storage
+/// before deleting the statements that write through them
+struct GlobalStorageDeletion;
+
+impl patterns::Pattern for GlobalStorageDeletion {
+    fn kinds(&self) -> &[&'static str] {
+        &[nodes::FUNCTION_DEFINITION]
+    }
+
+    fn visit(
+        &self,
+        function: Node,
+        _ancestors: &[Node],
+        ctx: &patterns::PatternContext,
+        out: &mut Vec<PartialMutant>,
+    ) {
+        let refs = global_storage_refs(function, ctx.source);
+        let mut pending = vec![function];
+        while let Some(node) = pending.pop() {
+            if node.kind() == nodes::EXPRESSION_STATEMENT
+                && writes_global_storage(&node, ctx.source, &refs)
+            {
+                out.push(ctx.mutant(
+                    node.start_byte(),
+                    node_text(&node, ctx.source),
+                    String::new(),
+                ));
+            }
+            // Push children in reverse so statements are emitted in source order
+            let mut cursor = node.walk();
+            let children: Vec<Node> = node.named_children(&mut cursor).collect();
+            pending.extend(children.into_iter().rev());
+        }
+    }
+}
+
+/// `has key, store` without each of its abilities; a lone ability is removed with `has`
+fn ability_list_variants(node: &Node, src: &str) -> Vec<String> {
+    let abilities = ability_names(node, src);
+    if abilities.len() == 1 {
+        return vec![String::new()];
+    }
+    (0..abilities.len())
+        .map(|skip| format!("has {}", without(&abilities, skip).join(", ")))
+        .collect()
+}
+
+/// `T: copy + drop` without each of its constraints; a lone one is removed with the `:`
+fn type_parameter_variants(node: &Node, src: &str) -> Vec<String> {
+    let Some(constraints) = node.child_by_field_name(fields::CONSTRAINTS) else {
+        return Vec::new();
+    };
+    let head = src[node.start_byte()..constraints.start_byte()]
+        .trim_end()
+        .trim_end_matches(':')
+        .trim_end();
+    let abilities = ability_names(&constraints, src);
+    (0..abilities.len())
+        .map(|skip| {
+            let rest = without(&abilities, skip);
+            if rest.is_empty() {
+                head.to_string()
+            } else {
+                format!("{head}: {}", rest.join(" + "))
+            }
+        })
+        .collect()
+}
+
+fn ability_names<'a>(node: &Node, src: &'a str) -> Vec<&'a str> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|child| child.kind() == nodes::ABILITY)
+        .map(|child| node_text(&child, src))
+        .collect()
+}
+
+fn without<'a>(items: &[&'a str], skip: usize) -> Vec<&'a str> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != skip)
+        .map(|(_, item)| *item)
+        .collect()
+}
+
+/// Literals naming an address or module rather than a value: `module 0x1::coin`,
+/// `use 0x1::signer`, `friend 0x1::admin` and attribute arguments
+fn is_structural_literal(node: &Node) -> bool {
+    node.parent()
+        .is_some_and(|p| p.kind() == nodes::ADDRESS_BLOCK)
+        || patterns::enclosing(
+            *node,
+            &[
+                nodes::MODULE_IDENTITY,
+                nodes::SCOPED_IDENTIFIER,
+                nodes::USE_DECLARATION,
+                nodes::FRIEND_DECLARATION,
+                nodes::ATTRIBUTES,
+            ],
+        )
+        .is_some()
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "abort 0;", |node, src| {
+                    // Do not replace statements that already assert or abort
+                    !is_failing_statement(node_text(node, src))
+                }),
+            ),
+            "CR" => set.add(m.slug, patterns::ReplaceWith::new(STATEMENTS, comment_out)),
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
+                ),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_EXPRESSION,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
+                ),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(
+                    &[nodes::CALL_EXPRESSION, nodes::METHOD_CALL_EXPRESSION],
+                    fields::ARGUMENTS,
+                ),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_EXPRESSION, nodes::CONTINUE_EXPRESSION],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::BOOL_LITERAL], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_EXPRESSION, nodes::BLOCK],
+                    RETURN_DEFAULTS,
+                    returned_value,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUM_LITERAL], |node, src| {
+                    if is_structural_literal(node) {
+                        return Vec::new();
+                    }
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(STRINGS, |node, src| {
+                    if is_structural_literal(node) {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUM_LITERAL], |node, src| {
+                    if is_structural_literal(node) {
+                        return None;
+                    }
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(&[nodes::UNARY_EXPRESSION], &[("!", "")]),
+            ),
+            "UOR" => {
+                // No increment or decrement operators
+            }
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_EXPRESSION,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
+                ),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+", "-", "*", "/", "%"],
+                ),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::COMPOUND_ASSIGNMENT],
+                    &["+=", "-=", "*=", "/=", "%="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::COMPOUND_ASSIGNMENT], &["&=", "|=", "^="]),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::COMPOUND_ASSIGNMENT], &["<<=", ">>="]),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
+                ),
+            ),
+            // Move-specific mutations
+            "ASR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    let call = node.named_child(0)?;
+                    let name = call.child_by_field_name(fields::MACRO)?;
+                    (call.kind() == nodes::MACRO_CALL_EXPRESSION
+                        && node_text(&name, src).starts_with("assert"))
+                    .then(String::new)
+                }),
+            ),
+            "GSD" => set.add(m.slug, GlobalStorageDeletion),
+            "ABR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(
+                    &[nodes::ABILITIES, nodes::TYPE_PARAMETER],
+                    |node, src| {
+                        if node.kind() == nodes::ABILITIES {
+                            ability_list_variants(node, src)
+                        } else {
+                            type_parameter_variants(node, src)
+                        }
+                    },
+                ),
+            ),
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in Move engine: {}",
+                    m.slug
+                );
+            }
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = MoveLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(
+            dups.is_empty(),
+            "Duplicate mutation slugs found in Move engine: {dups:?}",
+        );
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text =
+            "module 0x1::m {\n    fun f(): u64 {\n        let x = 1 + 2;\n        x\n    }\n}\n";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.move"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "Move".to_string(),
+        };
+        let engine = MoveLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::types::{Mutation, MutationSeverity};
+
+pub const MOVE_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "ASR",
+        description: "Assert Removal: Delete an assert! statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "GSD",
+        description: "Global Storage Deletion: Delete a move_to or a write through a borrow_global_mut reference",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "ABR",
+        description: "Ability Removal: Drop one ability (copy, drop, store, key) from a type or type parameter",
+        severity: MutationSeverity::Medium,
+    },
+];
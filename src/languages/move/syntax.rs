@@ -0,0 +1,59 @@
+//! Node kinds of the vendored Move grammar
+
+pub mod nodes {
+    pub const FUNCTION_DEFINITION: &str = "function_definition";
+    pub const MODULE_DEFINITION: &str = "module_definition";
+    pub const LAMBDA_EXPRESSION: &str = "lambda_expression";
+    pub const ATTRIBUTES: &str = "attributes";
+    pub const ATTRIBUTE: &str = "attribute";
+    pub const BLOCK: &str = "block";
+    pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const LET_STATEMENT: &str = "let_statement";
+    pub const IF_EXPRESSION: &str = "if_expression";
+    pub const WHILE_EXPRESSION: &str = "while_expression";
+    pub const BREAK_EXPRESSION: &str = "break_expression";
+    pub const CONTINUE_EXPRESSION: &str = "continue_expression";
+    pub const RETURN_EXPRESSION: &str = "return_expression";
+    pub const BINARY_EXPRESSION: &str = "binary_expression";
+    pub const COMPOUND_ASSIGNMENT: &str = "compound_assignment_expression";
+    pub const ASSIGNMENT_EXPRESSION: &str = "assignment_expression";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const CALL_EXPRESSION: &str = "call_expression";
+    pub const METHOD_CALL_EXPRESSION: &str = "method_call_expression";
+    pub const MACRO_CALL_EXPRESSION: &str = "macro_call_expression";
+    pub const GENERIC_NAME: &str = "generic_name";
+    pub const SCOPED_IDENTIFIER: &str = "scoped_identifier";
+    pub const IDENTIFIER: &str = "identifier";
+    pub const FIELD_EXPRESSION: &str = "field_expression";
+    pub const INDEX_EXPRESSION: &str = "index_expression";
+    pub const BORROW_EXPRESSION: &str = "borrow_expression";
+    pub const DEREFERENCE_EXPRESSION: &str = "dereference_expression";
+    pub const PARENTHESIZED_EXPRESSION: &str = "parenthesized_expression";
+    pub const MUT_PATTERN: &str = "mut_pattern";
+    pub const ABILITIES: &str = "abilities";
+    pub const ABILITY: &str = "ability";
+    pub const TYPE_PARAMETER: &str = "type_parameter";
+    pub const BOOL_LITERAL: &str = "bool_literal";
+    pub const NUM_LITERAL: &str = "num_literal";
+    pub const BYTE_STRING_LITERAL: &str = "byte_string_literal";
+    pub const HEX_STRING_LITERAL: &str = "hex_string_literal";
+    pub const MODULE_IDENTITY: &str = "module_identity";
+    pub const ADDRESS_BLOCK: &str = "address_block";
+    pub const USE_DECLARATION: &str = "use_declaration";
+    pub const FRIEND_DECLARATION: &str = "friend_declaration";
+}
+
+pub mod fields {
+    pub const CONDITION: &str = "condition";
+    pub const ARGUMENTS: &str = "arguments";
+    pub const BODY: &str = "body";
+    pub const RETURN_TYPE: &str = "return_type";
+    pub const FUNCTION: &str = "function";
+    pub const MACRO: &str = "macro";
+    pub const NAME: &str = "name";
+    pub const LEFT: &str = "left";
+    pub const VALUE: &str = "value";
+    pub const RECEIVER: &str = "receiver";
+    pub const PATTERN: &str = "pattern";
+    pub const CONSTRAINTS: &str = "constraints";
+}
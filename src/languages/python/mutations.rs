@@ -0,0 +1,14 @@
+use crate::types::{Mutation, MutationSeverity};
+
+pub const PYTHON_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "IOS",
+        description: "Identity Operator Shuffle: Replace identity operators (is, is not)",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "MOS",
+        description: "Membership Operator Shuffle: Replace membership operators (in, not in)",
+        severity: MutationSeverity::Low,
+    },
+];
@@ -1,9 +1,9 @@
 use std::sync::OnceLock;
-use tree_sitter::Language as TsLanguage;
+use tree_sitter::{Language as TsLanguage, Node};
 
 use crate::LanguageEngine;
 use crate::mutations::COMMON_MUTATIONS;
-use crate::patterns;
+use crate::patterns::{self, PatternSet};
 use crate::types::{Mutant, Mutation, Target};
 use crate::utils::{node_text, parse_source};
 
@@ -18,6 +18,7 @@ unsafe extern "C" {
 
 pub struct RustLanguageEngine {
     mutations: Vec<Mutation>,
+    patterns: PatternSet,
 }
 
 impl Default for RustLanguageEngine {
@@ -31,7 +32,11 @@ impl RustLanguageEngine {
         let mut mutations: Vec<Mutation> = Vec::new();
         mutations.extend_from_slice(COMMON_MUTATIONS);
         mutations.extend_from_slice(RUST_MUTATIONS);
-        Self { mutations }
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
     }
 }
 
@@ -55,225 +60,437 @@ impl LanguageEngine for RustLanguageEngine {
     }
 
     fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
         let source = &target.text;
         let tree = match parse_source(source, &self.tree_sitter_language()) {
             Some(t) => t,
             None => return Vec::new(),
         };
         let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
 
-        let mut all_mutants = Vec::new();
-        for m in &self.mutations {
-            match m.slug {
-                "ER" => {
-                    all_mutants.extend(
-                        patterns::replace(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::LET_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOREACH_STATEMENT,
-                            ],
-                            "assert!(false);",
-                            &|node, src| {
-                                let text = node_text(node, src);
-                                // Do not replace statements that already contain an error
-                                !text.contains("assert!(")
-                            },
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "ER")),
-                    );
-                }
-                "CR" => {
-                    all_mutants.extend(
-                        patterns::wrap(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::LET_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOREACH_STATEMENT,
-                            ],
-                            "/* ",
-                            " */",
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "CR")),
-                    );
-                }
-                "IF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IF")),
-                ),
-                "IT" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "true",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IT")),
-                ),
-                "WF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::WHILE_STATEMENT,
-                        fields::CONDITION,
-                        &["while"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "WF")),
-                ),
-                "RZ" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::FOREACH_STATEMENT,
-                        fields::CONDITION,
-                        &["for"],
-                        "0",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "RZ")),
+/// Overflow-aware arithmetic methods, keyed by operation, with the prefixes std provides
+const OVERFLOW_METHODS: &[(&str, &[&str])] = &[
+    ("add", &["checked", "saturating", "wrapping"]),
+    ("sub", &["checked", "saturating", "wrapping"]),
+    ("mul", &["checked", "saturating", "wrapping"]),
+    ("div", &["checked", "saturating", "wrapping"]),
+    ("pow", &["checked", "saturating", "wrapping"]),
+    ("neg", &["checked", "saturating", "wrapping"]),
+    ("abs", &["checked", "saturating", "wrapping"]),
+    ("rem", &["checked", "wrapping"]),
+    ("shl", &["checked", "wrapping"]),
+    ("shr", &["checked", "wrapping"]),
+];
+
+/// Replace a function's body block, returning None for any other block
+fn replace_function_body(node: &Node, src: &str, default: bool) -> Option<String> {
+    let function = node.parent()?;
+    if function.kind() != nodes::FUNCTION_ITEM
+        || function.child_by_field_name(fields::BODY) != Some(*node)
+    {
+        return None;
+    }
+    if !default {
+        return Some("{ todo!() }".to_string());
+    }
+    match function.child_by_field_name(fields::RETURN_TYPE) {
+        // Unit functions just do nothing
+        None => Some("{}".to_string()),
+        Some(ret) => {
+            let ret = node_text(&ret, src);
+            // impl Trait and never types have no Default
+            (!ret.starts_with("impl ") && ret != "!").then(|| "{ Default::default() }".to_string())
+        }
+    }
+}
+
+/// The text of a call's callee when it is exactly `name`
+fn is_call_to(node: &Node, src: &str, name: &str) -> bool {
+    node.child_by_field_name(fields::FUNCTION)
+        .is_some_and(|f| node_text(&f, src) == name)
+}
+
+/// Swap between the checked_/saturating_/wrapping_ variants of a method call
+fn overflow_alternatives(node: &Node, src: &str) -> Vec<String> {
+    if node.parent().map(|p| p.kind()) != Some(nodes::FIELD_EXPRESSION) {
+        return Vec::new();
+    }
+    let Some((prefix, op)) = node_text(node, src).split_once('_') else {
+        return Vec::new();
+    };
+    match OVERFLOW_METHODS.iter().find(|(name, _)| *name == op) {
+        Some((_, prefixes)) if prefixes.contains(&prefix) => prefixes
+            .iter()
+            .filter(|p| **p != prefix)
+            .map(|p| format!("{p}_{op}"))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Delete a match arm when a sibling `_ =>` arm would catch its values instead
+fn delete_shadowed_arm(node: &Node, src: &str) -> Option<String> {
+    let is_wildcard = |arm: &Node| {
+        arm.child_by_field_name(fields::PATTERN)
+            .is_some_and(|p| node_text(&p, src) == "_")
+    };
+    if is_wildcard(node) {
+        return None;
+    }
+    let block = node.parent()?;
+    let mut cursor = block.walk();
+    let has_fallback = block
+        .named_children(&mut cursor)
+        .any(|arm| arm.kind() == nodes::MATCH_ARM && is_wildcard(&arm));
+    has_fallback.then(String::new)
+}
+
+/// Any returned type with a `Default` impl can be replaced by its default. `Result` has
+/// none (`OE` already turns `Ok` into `Err`) and neither do references.
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    ("impl *", ""),
+    ("!", ""),
+    ("()", ""),
+    ("Result", ""),
+    ("Result<*", ""),
+    ("&*", ""),
+    ("*", "Default::default()"),
+];
+
+/// The value of a `return` or the tail expression of a function body
+fn returned_value(node: Node) -> Option<Node> {
+    if node.kind() != nodes::BLOCK {
+        return patterns::returned_expression(node);
+    }
+    let function = node.parent()?;
+    if function.kind() != nodes::FUNCTION_ITEM
+        || function.child_by_field_name(fields::BODY) != Some(node)
+    {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node
+        .named_children(&mut cursor)
+        .filter(|child| !child.kind().contains("comment"))
+        .collect();
+    // A lone tail is the whole body, which `FBD` already replaces
+    let [.., _, tail] = children[..] else {
+        return None;
+    };
+    let kind = tail.kind();
+    let is_statement = kind == nodes::EXPRESSION_STATEMENT
+        || kind.ends_with("_item")
+        || kind.ends_with("declaration");
+    (!is_statement).then_some(tail)
+}
+
+/// The declared return type of the function a value is returned from; closures are skipped
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = if node.kind() == nodes::BLOCK {
+        node.parent()?
+    } else {
+        patterns::enclosing(node, &[nodes::FUNCTION_ITEM, nodes::CLOSURE_EXPRESSION])?
+    };
+    if function.kind() != nodes::FUNCTION_ITEM {
+        return None;
+    }
+    let ty = node_text(&function.child_by_field_name(fields::RETURN_TYPE)?, src);
+    if ty.starts_with('&') {
+        return Some(ty.to_string());
+    }
+    // Look types up without their path, so `io::Result<()>` is a `Result`
+    let base = ty.split('<').next().unwrap_or(ty);
+    let path = base.rfind("::").map_or(0, |i| i + 2);
+    Some(ty[path..].to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => {
+                set.add(
+                    m.slug,
+                    patterns::Replace::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::LET_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOREACH_STATEMENT,
+                        ],
+                        "assert!(false);",
+                        |node, src| {
+                            let text = node_text(node, src);
+                            // Do not replace statements that already contain an error
+                            !text.contains("assert!(")
+                        },
+                    ),
+                );
+            }
+            "CR" => {
+                set.add(
+                    m.slug,
+                    patterns::Wrap::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::LET_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOREACH_STATEMENT,
+                        ],
+                        "/* ",
+                        " */",
+                    ),
+                );
+            }
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
                 ),
-                "AS" => all_mutants.extend(
-                    patterns::swap_args(
-                        root,
-                        source,
-                        &[nodes::METHOD_CALL_EXPRESSION, nodes::STATIC_CALL_EXPRESSION],
-                        fields::ARGUMENTS,
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AS")),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
                 ),
-                // Shared operator shuffles
-                "AOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+", "-", "*", "/"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AOS")),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
                 ),
-                "AAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+=", "-=", "*=", "/="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AAOS")),
+            ),
+            "RZ" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::FOREACH_STATEMENT,
+                    fields::CONDITION,
+                    &["for"],
+                    "0",
                 ),
-                "BOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&", "|", "^"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BOS")),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(
+                    &[nodes::METHOD_CALL_EXPRESSION, nodes::STATIC_CALL_EXPRESSION],
+                    fields::ARGUMENTS,
                 ),
-                "BAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&=", "|=", "^="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BAOS")),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT, nodes::BLOCK],
+                    RETURN_DEFAULTS,
+                    returned_value,
+                    return_type,
                 ),
-                // "UF" (do-until) not applicable in Rust – no-op
-                "BL" => all_mutants.extend(
-                    patterns::shuffle_nodes(root, source, &[nodes::BOOLEAN], &["true", "false"])
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "BL")),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::INTEGER_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(
+                    &[nodes::STRING_LITERAL, nodes::RAW_STRING_LITERAL],
+                    |node, src| {
+                        if is_structural_string(node, src) {
+                            return None;
+                        }
+                        patterns::empty_string_literal(node_text(node, src))
+                    },
                 ),
-                "COS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["==", "!=", "<", "<=", ">", ">="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "COS")),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::INTEGER_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(&[nodes::UNARY_EXPRESSION], &[("!", ""), ("-", "")]),
+            ),
+            "UOR" => {
+                // No increment or decrement operators
+            }
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
                 ),
-                "LOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&&", "||"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LOS")),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["+", "-", "*", "/"]),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+=", "-=", "*=", "/="],
                 ),
-                "SOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<", ">>"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SOS")),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&=", "|=", "^="]),
+            ),
+            // "UF" (do-until) not applicable in Rust – no-op
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::BOOLEAN], &["true", "false"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
                 ),
-                "SAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<=", ">>="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SAOS")),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<=", ">>="]),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
                 ),
-                "LC" => all_mutants.extend(
-                    patterns::shuffle_nodes(
-                        root,
-                        source,
-                        &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
-                        &["break", "continue"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LC")),
+            ),
+            // Rust-specific mutations
+            "FBT" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::BLOCK], |node, src| {
+                    replace_function_body(node, src, false)
+                }),
+            ),
+            "FBD" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::BLOCK], |node, src| {
+                    replace_function_body(node, src, true)
+                }),
+            ),
+            "QMU" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::TRY_EXPRESSION], |node, src| {
+                    node.named_child(0)
+                        .map(|inner| format!("{}.unwrap()", node_text(&inner, src)))
+                }),
+            ),
+            "QMR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::TRY_EXPRESSION], |node, src| {
+                    node.named_child(0)
+                        .map(|inner| node_text(&inner, src).to_string())
+                }),
+            ),
+            "SN" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STATIC_CALL_EXPRESSION], |node, src| {
+                    is_call_to(node, src, "Some").then(|| "None".to_string())
+                }),
+            ),
+            "OE" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STATIC_CALL_EXPRESSION], |node, src| {
+                    is_call_to(node, src, "Ok").then(|| "Err(Default::default())".to_string())
+                }),
+            ),
+            "MAD" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::MATCH_ARM], delete_shadowed_arm),
+            ),
+            "RGS" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(
+                    &[nodes::RANGE_EXCLUSIVE, nodes::RANGE_INCLUSIVE],
+                    |node, _src| {
+                        // Open ranges (`a..`, `..b`) cannot be made inclusive
+                        let range = node.parent()?;
+                        if range.kind() != nodes::RANGE_EXPRESSION || range.named_child_count() != 2
+                        {
+                            return None;
+                        }
+                        match node.kind() {
+                            nodes::RANGE_EXCLUSIVE => Some("..=".to_string()),
+                            _ => Some("..".to_string()),
+                        }
+                    },
                 ),
-                _ => {
-                    panic!(
-                        "Unknown mutation slug encountered in Rust engine: {}",
-                        m.slug
-                    );
-                }
+            ),
+            "OAS" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::FIELD_IDENTIFIER], overflow_alternatives),
+            ),
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in Rust engine: {}",
+                    m.slug
+                );
             }
         }
-        all_mutants
     }
+    set
+}
+
+/// String literals the program cannot compile without changing: attribute arguments,
+/// `extern "C"` ABI names and format strings inside macro invocations
+fn is_structural_string(node: &Node, src: &str) -> bool {
+    if node
+        .parent()
+        .is_some_and(|p| p.kind() == nodes::TOKEN_TREE && node_text(node, src).contains('{'))
+    {
+        return true;
+    }
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if [nodes::ATTRIBUTE_ITEM, nodes::EXTERN_MODIFIER].contains(&n.kind()) {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
 }
 
 #[cfg(test)]
@@ -1,3 +1,49 @@
-use crate::types::Mutation;
+use crate::types::{Mutation, MutationSeverity};
 
-pub const RUST_MUTATIONS: &[Mutation] = &[];
+pub const RUST_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "FBT",
+        description: "Function Body Todo: Replace a function body with todo!()",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "FBD",
+        description: "Function Body Default: Replace a function body with Default::default()",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "QMU",
+        description: "Question Mark Unwrap: Replace the ? operator with .unwrap()",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "QMR",
+        description: "Question Mark Removal: Remove the ? operator",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "SN",
+        description: "Some to None: Replace Some(x) with None",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "OE",
+        description: "Ok to Err: Replace Ok(x) with Err(Default::default())",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "MAD",
+        description: "Match Arm Deletion: Delete a match arm that a wildcard arm would catch",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "RGS",
+        description: "Range Swap: Swap the .. and ..= range operators",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "OAS",
+        description: "Overflow Arithmetic Swap: Swap checked_*, saturating_* and wrapping_* methods",
+        severity: MutationSeverity::Low,
+    },
+];
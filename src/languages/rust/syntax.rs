@@ -11,9 +11,30 @@ pub mod nodes {
     pub const BREAK_STATEMENT: &str = "break_expression";
     pub const CONTINUE_STATEMENT: &str = "continue_expression";
     pub const FOREACH_STATEMENT: &str = "for_expression";
+    pub const FUNCTION_ITEM: &str = "function_item";
+    pub const BLOCK: &str = "block";
+    pub const TRY_EXPRESSION: &str = "try_expression";
+    pub const RANGE_EXPRESSION: &str = "range_expression";
+    pub const FIELD_EXPRESSION: &str = "field_expression";
+    pub const FIELD_IDENTIFIER: &str = "field_identifier";
+    pub const MATCH_ARM: &str = "match_arm";
+    pub const RANGE_EXCLUSIVE: &str = "..";
+    pub const RANGE_INCLUSIVE: &str = "..=";
+    pub const INTEGER_LITERAL: &str = "integer_literal";
+    pub const STRING_LITERAL: &str = "string_literal";
+    pub const RAW_STRING_LITERAL: &str = "raw_string_literal";
+    pub const ATTRIBUTE_ITEM: &str = "attribute_item";
+    pub const EXTERN_MODIFIER: &str = "extern_modifier";
+    pub const TOKEN_TREE: &str = "token_tree";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const CLOSURE_EXPRESSION: &str = "closure_expression";
 }
 
 pub mod fields {
     pub const CONDITION: &str = "condition";
     pub const ARGUMENTS: &str = "arguments";
+    pub const BODY: &str = "body";
+    pub const FUNCTION: &str = "function";
+    pub const PATTERN: &str = "pattern";
+    pub const RETURN_TYPE: &str = "return_type";
 }
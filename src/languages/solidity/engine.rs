@@ -1,9 +1,9 @@
 use std::sync::OnceLock;
-use tree_sitter::Language as TsLanguage;
+use tree_sitter::{Language as TsLanguage, Node};
 
 use crate::LanguageEngine;
 use crate::mutations::COMMON_MUTATIONS;
-use crate::patterns;
+use crate::patterns::{self, PatternSet};
 use crate::types::{Mutant, Mutation, Target};
 use crate::utils::{node_text, parse_source};
 
@@ -18,6 +18,7 @@ unsafe extern "C" {
 
 pub struct SolidityLanguageEngine {
     mutations: Vec<Mutation>,
+    patterns: PatternSet,
 }
 
 impl Default for SolidityLanguageEngine {
@@ -31,7 +32,11 @@ impl SolidityLanguageEngine {
         let mut mutations: Vec<Mutation> = Vec::new();
         mutations.extend_from_slice(COMMON_MUTATIONS);
         mutations.extend_from_slice(SOLIDITY_MUTATIONS);
-        Self { mutations }
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
     }
 }
 
@@ -55,212 +60,390 @@ impl LanguageEngine for SolidityLanguageEngine {
     }
 
     fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
         let source = &target.text;
         let tree = match parse_source(source, &self.tree_sitter_language()) {
             Some(t) => t,
             None => return Vec::new(),
         };
         let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
 
-        let mut all_mutants = Vec::new();
-        for m in &self.mutations {
-            match m.slug {
-                "ER" => {
-                    all_mutants.extend(
-                        patterns::replace(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::LET_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                            ],
-                            "require(false);",
-                            &|node, src| {
-                                let text = node_text(node, src);
-                                // Avoid replacing statements already containing a require
-                                !text.contains("require(")
-                            },
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "ER")),
-                    );
-                }
-                "CR" => {
-                    all_mutants.extend(
-                        patterns::wrap(
-                            root,
-                            source,
-                            &[
-                                nodes::EXPRESSION_STATEMENT,
-                                nodes::RETURN_STATEMENT,
-                                nodes::LET_STATEMENT,
-                                nodes::IF_STATEMENT,
-                                nodes::WHILE_STATEMENT,
-                                nodes::FOR_STATEMENT,
-                            ],
-                            "/* ",
-                            " */",
-                        )
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "CR")),
-                    );
-                }
-                "IF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IF")),
-                ),
-                "IT" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::IF_STATEMENT,
-                        fields::CONDITION,
-                        &["if"],
-                        "true",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "IT")),
-                ),
-                "WF" => all_mutants.extend(
-                    patterns::replace_condition(
-                        root,
-                        source,
-                        nodes::WHILE_STATEMENT,
-                        fields::CONDITION,
-                        &["while"],
-                        "false",
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "WF")),
+/// Skip the `expression` wrapper nodes this grammar puts around every expression
+fn unwrap_expression(mut node: Node) -> Node {
+    while node.kind() == nodes::EXPRESSION {
+        match node.named_child(0) {
+            Some(inner) => node = inner,
+            None => break,
+        }
+    }
+    node
+}
+
+/// The callee name of a call expression
+fn callee_name<'a>(call: &Node, src: &'a str) -> Option<&'a str> {
+    call.child_by_field_name(fields::FUNCTION)
+        .map(|f| node_text(&unwrap_expression(f), src))
+}
+
+/// The callee name when a statement is a bare call such as `require(...);`
+fn statement_call_name<'a>(stmt: &Node, src: &'a str) -> Option<&'a str> {
+    let call = unwrap_expression(stmt.named_child(0)?);
+    if call.kind() != nodes::CALL_EXPRESSION {
+        return None;
+    }
+    callee_name(&call, src)
+}
+
+/// Deleting the unbraced body of an if/else or loop would make the next statement
+/// its body, so those are replaced with an empty block instead
+fn deleted_statement(stmt: &Node) -> String {
+    let braceless = stmt
+        .parent()
+        .filter(|p| p.kind() == nodes::STATEMENT)
+        .and_then(|p| p.parent())
+        .is_some_and(|gp| gp.kind() != nodes::BLOCK_STATEMENT && gp.kind() != nodes::FUNCTION_BODY);
+    if braceless {
+        "{}".to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Return value defaults by declared type
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    ("address payable", "payable(address(0))"),
+    ("address", "address(0)"),
+    ("bool", "false"),
+    ("uint*", "0"),
+    ("int*", "0"),
+    ("string", "\"\""),
+    ("bytes", "\"\""),
+    ("bytes*", "{type}(0)"),
+];
+
+/// The declared return type of the function a `return` belongs to, if it has exactly one
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(
+        node,
+        &[nodes::FUNCTION_DEFINITION, nodes::MODIFIER_DEFINITION],
+    )?;
+    let returns = function.child_by_field_name(fields::RETURN_TYPE)?;
+    if returns.named_child_count() != 1 {
+        return None;
+    }
+    let ty = returns.named_child(0)?.child_by_field_name(fields::TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => {
+                set.add(
+                    m.slug,
+                    patterns::Replace::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::LET_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                        ],
+                        "require(false);",
+                        |node, src| {
+                            let text = node_text(node, src);
+                            // Avoid replacing statements already containing a require
+                            !text.contains("require(")
+                        },
+                    ),
+                );
+            }
+            "CR" => {
+                set.add(
+                    m.slug,
+                    patterns::Wrap::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::LET_STATEMENT,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                        ],
+                        "/* ",
+                        " */",
+                    ),
+                );
+            }
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
                 ),
-                "AS" => all_mutants.extend(
-                    patterns::swap_args(
-                        root,
-                        source,
-                        &[nodes::METHOD_CALL_EXPRESSION, nodes::STATIC_CALL_EXPRESSION],
-                        fields::ARGUMENTS,
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AS")),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
                 ),
-                // Shared operator shuffles
-                "AOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+", "-", "*", "/"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AOS")),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
                 ),
-                "AAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["+=", "-=", "*=", "/="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "AAOS")),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(
+                    &[nodes::METHOD_CALL_EXPRESSION, nodes::STATIC_CALL_EXPRESSION],
+                    fields::ARGUMENTS,
                 ),
-                "BOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&", "|", "^"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BOS")),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
                 ),
-                "BAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&=", "|=", "^="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "BAOS")),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STRING], |node, src| {
+                    if node
+                        .parent()
+                        .is_some_and(|p| p.kind() == nodes::IMPORT_DIRECTIVE)
+                    {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUMBER_LITERAL], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("~", "")],
                 ),
-                "BL" => all_mutants.extend(
-                    patterns::shuffle_nodes(root, source, &[nodes::BOOLEAN], &["true", "false"])
-                        .into_iter()
-                        .map(|p| Mutant::from_partial(p, target, "BL")),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UPDATE_EXPRESSION],
+                    &[("++", "--"), ("--", "++")],
                 ),
-                "COS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["==", "!=", "<", "<=", ">", ">="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "COS")),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
                 ),
-                "LOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["&&", "||"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LOS")),
+            ),
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["+", "-", "*", "/"]),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+=", "-=", "*=", "/="],
                 ),
-                "SOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<", ">>"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SOS")),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&=", "|=", "^="]),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::BOOLEAN], &["true", "false"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["==", "!=", "<", "<=", ">", ">="],
                 ),
-                "SAOS" => all_mutants.extend(
-                    patterns::shuffle_operators(
-                        root,
-                        source,
-                        &[nodes::BINARY_EXPRESSION],
-                        &["<<=", ">>="],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "SAOS")),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<=", ">>="]),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
                 ),
-                "LC" => all_mutants.extend(
-                    patterns::shuffle_nodes(
-                        root,
-                        source,
-                        &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
-                        &["break", "continue"],
-                    )
-                    .into_iter()
-                    .map(|p| Mutant::from_partial(p, target, "LC")),
+            ),
+            // Solidity-specific mutations
+            "MR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::MODIFIER_INVOCATION], |node, _src| {
+                    // Constructors use the same syntax to call base constructors
+                    let function = node.parent()?;
+                    (function.kind() == nodes::FUNCTION_DEFINITION).then(String::new)
+                }),
+            ),
+            "RD" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                        matches!(statement_call_name(node, src), Some("require" | "assert"))
+                            .then(|| deleted_statement(node))
+                    }),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::REVERT_STATEMENT], |node, _src| {
+                        Some(deleted_statement(node))
+                    }),
+                );
+            }
+            "MSR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::MEMBER_EXPRESSION], |node, src| {
+                    (node_text(node, src) == "msg.sender").then(|| "tx.origin".to_string())
+                }),
+            ),
+            "VW" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::VISIBILITY], |node, src| {
+                    let function = node.parent()?;
+                    let widened = function.kind() == nodes::FUNCTION_DEFINITION
+                        && matches!(node_text(node, src), "internal" | "private");
+                    widened.then(|| "public".to_string())
+                }),
+            ),
+            "RN" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::CALL_ARGUMENT], |node, src| {
+                    // Only the condition, which is the first argument
+                    if node.prev_named_sibling()?.kind() == nodes::CALL_ARGUMENT {
+                        return None;
+                    }
+                    let call = node.parent()?;
+                    if call.kind() != nodes::CALL_EXPRESSION
+                        || !matches!(callee_name(&call, src), Some("require" | "assert"))
+                    {
+                        return None;
+                    }
+                    let condition = node_text(node, src);
+                    match unwrap_expression(node.named_child(0)?).kind() {
+                        nodes::IDENTIFIER | nodes::MEMBER_EXPRESSION | nodes::CALL_EXPRESSION => {
+                            Some(format!("!{condition}"))
+                        }
+                        _ => Some(format!("!({condition})")),
+                    }
+                }),
+            ),
+            "UBU" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::BLOCK_STATEMENT], |node, src| {
+                    let keyword = node.child(0)?;
+                    if keyword.kind() != nodes::UNCHECKED {
+                        return None;
+                    }
+                    let block = keyword.next_sibling()?;
+                    Some(src[block.start_byte()..node.end_byte()].to_string())
+                }),
+            ),
+            "ED" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EMIT_STATEMENT], |node, _src| {
+                    Some(deleted_statement(node))
+                }),
+            ),
+            "DR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::EXPRESSION_STATEMENT], |node, src| {
+                    node_text(node, src)
+                        .starts_with("delete ")
+                        .then(|| deleted_statement(node))
+                }),
+            ),
+            "SMS" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::STORAGE, nodes::MEMORY],
+                    &["storage", "memory"],
                 ),
-                _ => {
-                    panic!(
-                        "Unknown mutation slug encountered in Solidity engine: {}",
-                        m.slug
-                    );
-                }
+            ),
+            "AZ" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::MEMBER_EXPRESSION], |node, src| {
+                        (node_text(node, src) == "msg.sender").then(|| "address(0)".to_string())
+                    }),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceWith::new(&[nodes::TYPE_CAST_EXPRESSION], |node, src| {
+                        node_text(node, src)
+                            .starts_with("address(")
+                            .then(|| "address(0)".to_string())
+                    }),
+                );
+            }
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in Solidity engine: {}",
+                    m.slug
+                );
             }
         }
-        all_mutants
     }
+    set
 }
 
 #[cfg(test)]
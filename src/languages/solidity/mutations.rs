@@ -1,3 +1,54 @@
-use crate::types::Mutation;
+use crate::types::{Mutation, MutationSeverity};
 
-pub const SOLIDITY_MUTATIONS: &[Mutation] = &[];
+pub const SOLIDITY_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "MR",
+        description: "Modifier Removal: Remove a modifier invocation from a function (e.g. onlyOwner)",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RD",
+        description: "Require Deletion: Delete a require, assert or revert statement",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "MSR",
+        description: "Msg Sender Replacement: Replace msg.sender with tx.origin",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "VW",
+        description: "Visibility Widening: Make an internal or private function public",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "RN",
+        description: "Require Negation: Negate the condition of a require or assert",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "UBU",
+        description: "Unchecked Block Unwrap: Replace unchecked { ... } with a checked block",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "ED",
+        description: "Emit Deletion: Delete an emit statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "DR",
+        description: "Delete Removal: Delete a delete statement",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "SMS",
+        description: "Storage Memory Swap: Swap storage and memory data locations",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "AZ",
+        description: "Address Zero: Replace msg.sender or an address(...) cast with address(0)",
+        severity: MutationSeverity::Medium,
+    },
+];
@@ -11,9 +11,35 @@ pub mod nodes {
     pub const FOR_STATEMENT: &str = "for_statement";
     pub const BREAK_STATEMENT: &str = "break_statement";
     pub const CONTINUE_STATEMENT: &str = "continue_statement";
+    pub const CALL_EXPRESSION: &str = "call_expression";
+    pub const CALL_ARGUMENT: &str = "call_argument";
+    pub const EXPRESSION: &str = "expression";
+    pub const IDENTIFIER: &str = "identifier";
+    pub const MEMBER_EXPRESSION: &str = "member_expression";
+    pub const TYPE_CAST_EXPRESSION: &str = "type_cast_expression";
+    pub const MODIFIER_INVOCATION: &str = "modifier_invocation";
+    pub const FUNCTION_DEFINITION: &str = "function_definition";
+    pub const VISIBILITY: &str = "visibility";
+    pub const REVERT_STATEMENT: &str = "revert_statement";
+    pub const EMIT_STATEMENT: &str = "emit_statement";
+    pub const BLOCK_STATEMENT: &str = "block_statement";
+    pub const FUNCTION_BODY: &str = "function_body";
+    pub const STATEMENT: &str = "statement";
+    pub const UNCHECKED: &str = "unchecked";
+    pub const STORAGE: &str = "storage";
+    pub const MEMORY: &str = "memory";
+    pub const NUMBER_LITERAL: &str = "number_literal";
+    pub const STRING: &str = "string";
+    pub const IMPORT_DIRECTIVE: &str = "import_directive";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const UPDATE_EXPRESSION: &str = "update_expression";
+    pub const MODIFIER_DEFINITION: &str = "modifier_definition";
 }
 
 pub mod fields {
     pub const CONDITION: &str = "condition";
     pub const ARGUMENTS: &str = "arguments";
+    pub const FUNCTION: &str = "function";
+    pub const RETURN_TYPE: &str = "return_type";
+    pub const TYPE: &str = "type";
 }
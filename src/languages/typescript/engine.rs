@@ -0,0 +1,416 @@
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, Target};
+use crate::utils::{node_text, parse_source};
+
+use crate::languages::javascript::engine::add_javascript_pattern;
+use crate::languages::javascript::mutations::JAVASCRIPT_MUTATIONS;
+
+use super::mutations::TYPESCRIPT_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static TS_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+static TSX_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_typescript() -> *const tree_sitter::ffi::TSLanguage;
+    fn tree_sitter_tsx() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+pub struct TypeScriptLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for TypeScriptLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeScriptLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(JAVASCRIPT_MUTATIONS);
+        mutations.extend_from_slice(TYPESCRIPT_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+
+    fn typescript_language(&self) -> TsLanguage {
+        TS_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_typescript()) })
+            .clone()
+    }
+
+    fn tsx_language(&self) -> TsLanguage {
+        TSX_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_tsx()) })
+            .clone()
+    }
+
+    fn get_extension(target: &Target) -> Option<String> {
+        target
+            .path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|s| s.to_string())
+    }
+}
+
+impl LanguageEngine for TypeScriptLanguageEngine {
+    fn name(&self) -> &'static str {
+        "TypeScript"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["ts", "tsx", "mts", "cts"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        self.typescript_language()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let source = &target.text;
+        let language = match Self::get_extension(target).as_deref() {
+            Some("tsx") => self.tsx_language(),
+            _ => self.typescript_language(),
+        };
+        let tree = match parse_source(source, &language) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Return value defaults by annotated return type; other types are left alone
+const RETURN_DEFAULTS: patterns::DefaultTable = &[
+    ("number", "0"),
+    ("bigint", "0n"),
+    ("boolean", "false"),
+    ("string", "\"\""),
+];
+
+/// The annotated return type of the function a `return` belongs to
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(
+        node,
+        &[
+            nodes::FUNCTION_DECLARATION,
+            nodes::FUNCTION_EXPRESSION,
+            nodes::ARROW_FUNCTION,
+            nodes::METHOD_DEFINITION,
+            nodes::GENERATOR_FUNCTION_DECLARATION,
+        ],
+    )?;
+    let annotation = function.child_by_field_name(fields::RETURN_TYPE)?;
+    let ty = node_text(&annotation, src).trim_start_matches(':').trim();
+    Some(ty.to_string())
+}
+
+/// Swap each pair of adjacent enum members that take implicit values, renumbering them.
+/// Members with an initializer keep their value wherever they are, so they're left alone.
+fn swap_enum_members(body: &Node, src: &str) -> Vec<String> {
+    let mut cursor = body.walk();
+    let members: Vec<Node> = body.named_children(&mut cursor).collect();
+    let text = node_text(body, src);
+    let start = body.start_byte();
+    members
+        .windows(2)
+        .filter(|pair| pair.iter().all(|m| m.kind() == nodes::PROPERTY_IDENTIFIER))
+        .map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            format!(
+                "{}{}{}{}{}",
+                &text[..a.start_byte() - start],
+                node_text(&b, src),
+                &text[a.end_byte() - start..b.start_byte() - start],
+                node_text(&a, src),
+                &text[b.end_byte() - start..],
+            )
+        })
+        .collect()
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => {
+                set.add(
+                    m.slug,
+                    patterns::Replace::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::VARIABLE_DECLARATION,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                            nodes::FOR_IN_STATEMENT,
+                            nodes::DO_STATEMENT,
+                        ],
+                        "throw new Error(\"mewt\");",
+                        |node, src| {
+                            let text = node_text(node, src);
+                            // Do not replace statements that already contain an error
+                            !text.contains("throw ")
+                        },
+                    ),
+                );
+            }
+            "CR" => {
+                set.add(
+                    m.slug,
+                    patterns::Wrap::new(
+                        &[
+                            nodes::EXPRESSION_STATEMENT,
+                            nodes::RETURN_STATEMENT,
+                            nodes::VARIABLE_DECLARATION,
+                            nodes::IF_STATEMENT,
+                            nodes::WHILE_STATEMENT,
+                            nodes::FOR_STATEMENT,
+                            nodes::FOR_IN_STATEMENT,
+                            nodes::DO_STATEMENT,
+                        ],
+                        "/* ",
+                        " */",
+                    ),
+                );
+            }
+            "IF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "false",
+                ),
+            ),
+            "IT" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "true",
+                ),
+            ),
+            "WF" => set.add(
+                m.slug,
+                patterns::ReplaceCondition::new(
+                    nodes::WHILE_STATEMENT,
+                    fields::CONDITION,
+                    &["while"],
+                    "false",
+                ),
+            ),
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL_EXPRESSION], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&["true", "false"], &["true", "false"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::NUMBER], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            // Module specifiers, directives and literal types are skipped
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(
+                    &[nodes::STRING, nodes::TEMPLATE_STRING],
+                    |node, src| {
+                        if node.parent().is_some_and(|p| {
+                            [
+                                nodes::IMPORT_STATEMENT,
+                                nodes::EXPORT_STATEMENT,
+                                nodes::EXPRESSION_STATEMENT,
+                                nodes::LITERAL_TYPE,
+                            ]
+                            .contains(&p.kind())
+                        }) {
+                            return None;
+                        }
+                        patterns::empty_string_literal(node_text(node, src))
+                    },
+                ),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NUMBER], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UNARY_EXPRESSION],
+                    &[("!", ""), ("-", ""), ("~", "")],
+                ),
+            ),
+            "UOR" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::UPDATE_EXPRESSION],
+                    &[("++", "--"), ("--", "++")],
+                ),
+            ),
+            "UOI" => set.add(
+                m.slug,
+                patterns::NegateCondition::new(
+                    nodes::IF_STATEMENT,
+                    fields::CONDITION,
+                    &["if"],
+                    "!",
+                ),
+            ),
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["+", "-", "*", "/", "%", "**"],
+                ),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::AUGMENTED_ASSIGNMENT_EXPRESSION],
+                    &["+=", "-=", "*=", "/=", "%=", "**="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::AUGMENTED_ASSIGNMENT_EXPRESSION],
+                    &["&=", "|=", "^="],
+                ),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["&&", "||"]),
+            ),
+            // Equality operators are left to EQN and EQS
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_EXPRESSION],
+                    &["<", "<=", ">", ">="],
+                ),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_EXPRESSION], &["<<", ">>", ">>>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::AUGMENTED_ASSIGNMENT_EXPRESSION],
+                    &["<<=", ">>=", ">>>="],
+                ),
+            ),
+            // TypeScript-specific mutations
+            "NNR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::NON_NULL_EXPRESSION], |node, src| {
+                    node.named_child(0)
+                        .map(|inner| node_text(&inner, src).to_string())
+                }),
+            ),
+            "TAR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::AS_EXPRESSION], |node, src| {
+                    node.named_child(0)
+                        .map(|inner| node_text(&inner, src).to_string())
+                }),
+            ),
+            "EMS" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::ENUM_BODY], swap_enum_members),
+            ),
+            // Shared JavaScript mutations
+            _ => add_javascript_pattern(&mut set, m.slug),
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = TypeScriptLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(dups.is_empty(), "Duplicate mutation slugs found: {dups:?}",);
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text = "enum E { A, B } function test(x?: { y: number }): number { x.y += 1; if (true) return (x!.y as number) ?? x?.y; }";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.ts"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "TypeScript".to_string(),
+        };
+        let engine = TypeScriptLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::types::{Mutation, MutationSeverity};
+
+pub const TYPESCRIPT_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "NNR",
+        description: "Non-null Removal: Remove a non-null assertion (x! -> x)",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "TAR",
+        description: "Type Assertion Removal: Remove an as cast (x as T -> x)",
+        severity: MutationSeverity::Low,
+    },
+    Mutation {
+        slug: "EMS",
+        description: "Enum Member Swap: Swap adjacent implicitly numbered enum members",
+        severity: MutationSeverity::Medium,
+    },
+];
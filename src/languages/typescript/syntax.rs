@@ -0,0 +1,41 @@
+pub mod nodes {
+    pub const BINARY_EXPRESSION: &str = "binary_expression";
+    pub const AUGMENTED_ASSIGNMENT_EXPRESSION: &str = "augmented_assignment_expression";
+    pub const CALL_EXPRESSION: &str = "call_expression";
+    pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const IF_STATEMENT: &str = "if_statement";
+    pub const WHILE_STATEMENT: &str = "while_statement";
+    pub const FOR_STATEMENT: &str = "for_statement";
+    pub const FOR_IN_STATEMENT: &str = "for_in_statement";
+    pub const DO_STATEMENT: &str = "do_statement";
+    pub const RETURN_STATEMENT: &str = "return_statement";
+    pub const VARIABLE_DECLARATION: &str = "variable_declaration";
+    pub const BREAK_STATEMENT: &str = "break_statement";
+    pub const CONTINUE_STATEMENT: &str = "continue_statement";
+    pub const NUMBER: &str = "number";
+    pub const STRING: &str = "string";
+    pub const TEMPLATE_STRING: &str = "template_string";
+    pub const IMPORT_STATEMENT: &str = "import_statement";
+    pub const EXPORT_STATEMENT: &str = "export_statement";
+    // TypeScript-specific node types
+    pub const NON_NULL_EXPRESSION: &str = "non_null_expression";
+    pub const AS_EXPRESSION: &str = "as_expression";
+    pub const ENUM_BODY: &str = "enum_body";
+    pub const PROPERTY_IDENTIFIER: &str = "property_identifier";
+    pub const TYPE_ARGUMENTS: &str = "type_arguments";
+    pub const TYPE_PARAMETERS: &str = "type_parameters";
+    pub const LITERAL_TYPE: &str = "literal_type";
+    pub const UNARY_EXPRESSION: &str = "unary_expression";
+    pub const UPDATE_EXPRESSION: &str = "update_expression";
+    pub const FUNCTION_DECLARATION: &str = "function_declaration";
+    pub const FUNCTION_EXPRESSION: &str = "function_expression";
+    pub const ARROW_FUNCTION: &str = "arrow_function";
+    pub const METHOD_DEFINITION: &str = "method_definition";
+    pub const GENERATOR_FUNCTION_DECLARATION: &str = "generator_function_declaration";
+}
+
+pub mod fields {
+    pub const CONDITION: &str = "condition";
+    pub const ARGUMENTS: &str = "arguments";
+    pub const RETURN_TYPE: &str = "return_type";
+}
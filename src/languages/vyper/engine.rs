@@ -0,0 +1,359 @@
+use std::sync::OnceLock;
+use tree_sitter::{Language as TsLanguage, Node};
+
+use crate::LanguageEngine;
+use crate::mutations::COMMON_MUTATIONS;
+use crate::patterns::{self, PatternSet};
+use crate::types::{Mutant, Mutation, Target};
+use crate::utils::{node_text, parse_source};
+
+use super::mutations::VYPER_MUTATIONS;
+use super::syntax::{fields, nodes};
+
+static VYPER_LANGUAGE: OnceLock<TsLanguage> = OnceLock::new();
+
+unsafe extern "C" {
+    fn tree_sitter_vyper() -> *const tree_sitter::ffi::TSLanguage;
+}
+
+/// Statements targeted by ER and CR
+const STATEMENTS: &[&str] = &[
+    nodes::EXPRESSION_STATEMENT,
+    nodes::RETURN_STATEMENT,
+    nodes::LOG_STATEMENT,
+    nodes::IF_STATEMENT,
+    nodes::FOR_STATEMENT,
+];
+
+pub struct VyperLanguageEngine {
+    mutations: Vec<Mutation>,
+    patterns: PatternSet,
+}
+
+impl Default for VyperLanguageEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VyperLanguageEngine {
+    pub fn new() -> Self {
+        let mut mutations: Vec<Mutation> = Vec::new();
+        mutations.extend_from_slice(COMMON_MUTATIONS);
+        mutations.extend_from_slice(VYPER_MUTATIONS);
+        let patterns = build_patterns(&mutations);
+        Self {
+            mutations,
+            patterns,
+        }
+    }
+}
+
+impl LanguageEngine for VyperLanguageEngine {
+    fn name(&self) -> &'static str {
+        "Vyper"
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["vy"]
+    }
+
+    fn tree_sitter_language(&self) -> TsLanguage {
+        VYPER_LANGUAGE
+            .get_or_init(|| unsafe { TsLanguage::from_raw(tree_sitter_vyper()) })
+            .clone()
+    }
+
+    fn get_mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    fn apply_all_mutations(&self, target: &Target) -> Vec<Mutant> {
+        self.apply_mutations(target, None)
+    }
+
+    fn apply_mutations(&self, target: &Target, enabled: Option<&[String]>) -> Vec<Mutant> {
+        let source = &target.text;
+        let tree = match parse_source(source, &self.tree_sitter_language()) {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+        let root = tree.root_node();
+        self.patterns.apply(root, target, enabled)
+    }
+}
+
+/// Only statements inside function bodies can be replaced; module-level lines are
+/// declarations, and the bodies of interface functions are mutability markers
+fn is_replaceable_statement(node: &Node) -> bool {
+    patterns::enclosing(*node, &[nodes::FUNCTION_DEFINITION])
+        .is_some_and(|f| patterns::enclosing(f, &[nodes::INTERFACE_DEFINITION]).is_none())
+}
+
+/// `empty(T)` is the zero value of any Vyper type
+const RETURN_DEFAULTS: patterns::DefaultTable = &[("*", "empty({type})")];
+
+/// The declared return type of the function a `return` belongs to
+fn return_type(node: Node, src: &str) -> Option<String> {
+    let function = patterns::enclosing(node, &[nodes::FUNCTION_DEFINITION])?;
+    let ty = function.child_by_field_name(fields::RETURN_TYPE)?;
+    Some(node_text(&ty, src).to_string())
+}
+
+/// Build the pattern set for this engine's mutations once, at construction time
+fn build_patterns(mutations: &[Mutation]) -> PatternSet {
+    let mut set = PatternSet::new();
+    for m in mutations {
+        match m.slug {
+            "ER" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "raise", |node, _| {
+                    is_replaceable_statement(node)
+                }),
+            ),
+            // Commenting out the only statement of a block would leave it empty, so
+            // statements are replaced with `pass`
+            "CR" => set.add(
+                m.slug,
+                patterns::Replace::new(STATEMENTS, "pass", |node, src| {
+                    is_replaceable_statement(node) && node_text(node, src) != "pass"
+                }),
+            ),
+            "IF" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceCondition::new(
+                        nodes::IF_STATEMENT,
+                        fields::CONDITION,
+                        &["if"],
+                        "False",
+                    ),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceCondition::new(
+                        nodes::ELIF_CLAUSE,
+                        fields::CONDITION,
+                        &["elif"],
+                        "False",
+                    ),
+                );
+            }
+            "IT" => {
+                set.add(
+                    m.slug,
+                    patterns::ReplaceCondition::new(
+                        nodes::IF_STATEMENT,
+                        fields::CONDITION,
+                        &["if"],
+                        "True",
+                    ),
+                );
+                set.add(
+                    m.slug,
+                    patterns::ReplaceCondition::new(
+                        nodes::ELIF_CLAUSE,
+                        fields::CONDITION,
+                        &["elif"],
+                        "True",
+                    ),
+                );
+            }
+            "AS" => set.add(
+                m.slug,
+                patterns::SwapArgs::new(&[nodes::CALL], fields::ARGUMENTS),
+            ),
+            "LC" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::BREAK_STATEMENT, nodes::CONTINUE_STATEMENT],
+                    &["break", "continue"],
+                ),
+            ),
+            "BL" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::TRUE, nodes::FALSE], &["True", "False"]),
+            ),
+            "RV" => set.add(
+                m.slug,
+                patterns::ReturnValue::new(
+                    &[nodes::RETURN_STATEMENT],
+                    RETURN_DEFAULTS,
+                    patterns::returned_expression,
+                    return_type,
+                ),
+            ),
+            // Literal mutations
+            "NLR" => set.add(
+                m.slug,
+                patterns::ReplaceWithEach::new(&[nodes::INTEGER], |node, src| {
+                    patterns::numeric_literal_variants(node_text(node, src))
+                }),
+            ),
+            // Docstrings are skipped
+            "SLR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::STRING], |node, src| {
+                    if node
+                        .parent()
+                        .is_some_and(|p| p.kind() == nodes::EXPRESSION_STATEMENT)
+                    {
+                        return None;
+                    }
+                    patterns::empty_string_literal(node_text(node, src))
+                }),
+            ),
+            "HLZ" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::INTEGER], |node, src| {
+                    patterns::zero_hex_literal(node_text(node, src))
+                }),
+            ),
+            // Unary operator mutations
+            "UOD" => set.add(
+                m.slug,
+                patterns::UnaryOperator::new(
+                    &[nodes::NOT_OPERATOR, nodes::UNARY_OPERATOR],
+                    &[("not", ""), ("-", ""), ("~", "")],
+                ),
+            ),
+            "UOR" => {
+                // No increment or decrement operators
+            }
+            "UOI" => {
+                set.add(
+                    m.slug,
+                    patterns::NegateCondition::new(
+                        nodes::IF_STATEMENT,
+                        fields::CONDITION,
+                        &["if"],
+                        "not ",
+                    ),
+                );
+                set.add(
+                    m.slug,
+                    patterns::NegateCondition::new(
+                        nodes::ELIF_CLAUSE,
+                        fields::CONDITION,
+                        &["elif"],
+                        "not ",
+                    ),
+                );
+            }
+            // Shared operator shuffles
+            "AOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::BINARY_OPERATOR],
+                    &["+", "-", "*", "/", "//", "%", "**"],
+                ),
+            ),
+            "AAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::AUGMENTED_ASSIGNMENT],
+                    &["+=", "-=", "*=", "/=", "//=", "%="],
+                ),
+            ),
+            "BOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_OPERATOR], &["&", "|", "^"]),
+            ),
+            "BAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::AUGMENTED_ASSIGNMENT],
+                    &["&=", "|=", "^="],
+                ),
+            ),
+            "LOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BOOLEAN_OPERATOR], &["and", "or"]),
+            ),
+            "COS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(
+                    &[nodes::COMPARISON_OPERATOR],
+                    &["==", "!=", "<", "<=", ">", ">="],
+                ),
+            ),
+            "SOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::BINARY_OPERATOR], &["<<", ">>"]),
+            ),
+            "SAOS" => set.add(
+                m.slug,
+                patterns::ShuffleOperators::new(&[nodes::AUGMENTED_ASSIGNMENT], &["<<=", ">>="]),
+            ),
+            // Vyper-specific mutations
+            "DS" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(&[nodes::DECORATOR], &["@external", "@internal"]),
+            ),
+            "MSR" => set.add(
+                m.slug,
+                patterns::ReplaceWith::new(&[nodes::ATTRIBUTE], |node, src| {
+                    (node_text(node, src) == "msg.sender").then(|| "tx.origin".to_string())
+                }),
+            ),
+            "ASR" => set.add(
+                m.slug,
+                patterns::Replace::new(&[nodes::ASSERT_STATEMENT], "pass", |_, _| true),
+            ),
+            "UMS" => set.add(
+                m.slug,
+                patterns::ShuffleNodes::new(
+                    &[nodes::IDENTIFIER],
+                    &["unsafe_add", "unsafe_sub", "unsafe_mul", "unsafe_div"],
+                ),
+            ),
+            // Vyper has no while loops
+            "WF" => {}
+            _ => {
+                panic!(
+                    "Unknown mutation slug encountered in Vyper engine: {}",
+                    m.slug
+                );
+            }
+        }
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    #[test]
+    fn no_duplicate_slugs_in_combined_mutations() {
+        let engine = VyperLanguageEngine::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut dups: BTreeSet<String> = BTreeSet::new();
+        for m in engine.get_mutations() {
+            if !seen.insert(m.slug) {
+                dups.insert(m.slug.to_string());
+            }
+        }
+        assert!(
+            dups.is_empty(),
+            "Duplicate mutation slugs found in Vyper engine: {dups:?}",
+        );
+    }
+
+    #[test]
+    fn all_defined_slugs_have_match_arms() {
+        let text: &str =
+            "@external\ndef f(a: uint256) -> uint256:\n    assert a > 0\n    return a\n";
+        let target = Target {
+            id: 0,
+            path: PathBuf::from("test.vy"),
+            file_hash: crate::types::Hash::digest(text.to_string()),
+            text: text.to_string(),
+            language: "Vyper".to_string(),
+        };
+        let engine = VyperLanguageEngine::new();
+        let _ = engine.apply_all_mutations(&target);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::types::{Mutation, MutationSeverity};
+
+pub const VYPER_MUTATIONS: &[Mutation] = &[
+    Mutation {
+        slug: "DS",
+        description: "Decorator Swap: Swap @external and @internal function decorators",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "MSR",
+        description: "Msg Sender Replacement: Replace msg.sender with tx.origin",
+        severity: MutationSeverity::High,
+    },
+    Mutation {
+        slug: "ASR",
+        description: "Assert Removal: Replace an assert statement with pass",
+        severity: MutationSeverity::Medium,
+    },
+    Mutation {
+        slug: "UMS",
+        description: "Unsafe Math Shuffle: Replace unsafe math builtins (unsafe_add, unsafe_sub, unsafe_mul, unsafe_div)",
+        severity: MutationSeverity::Low,
+    },
+];
@@ -0,0 +1,35 @@
+//! Node kinds of the vendored Vyper grammar, which extends the Python grammar
+
+pub mod nodes {
+    pub const BINARY_OPERATOR: &str = "binary_operator";
+    pub const AUGMENTED_ASSIGNMENT: &str = "augmented_assignment";
+    pub const BOOLEAN_OPERATOR: &str = "boolean_operator";
+    pub const COMPARISON_OPERATOR: &str = "comparison_operator";
+    pub const CALL: &str = "call";
+    pub const ATTRIBUTE: &str = "attribute";
+    pub const IDENTIFIER: &str = "identifier";
+    pub const DECORATOR: &str = "decorator";
+    pub const FUNCTION_DEFINITION: &str = "function_definition";
+    pub const INTERFACE_DEFINITION: &str = "interface_definition";
+    pub const EXPRESSION_STATEMENT: &str = "expression_statement";
+    pub const RETURN_STATEMENT: &str = "return_statement";
+    pub const LOG_STATEMENT: &str = "log_statement";
+    pub const ASSERT_STATEMENT: &str = "assert_statement";
+    pub const IF_STATEMENT: &str = "if_statement";
+    pub const ELIF_CLAUSE: &str = "elif_clause";
+    pub const FOR_STATEMENT: &str = "for_statement";
+    pub const BREAK_STATEMENT: &str = "break_statement";
+    pub const CONTINUE_STATEMENT: &str = "continue_statement";
+    pub const TRUE: &str = "true";
+    pub const FALSE: &str = "false";
+    pub const INTEGER: &str = "integer";
+    pub const STRING: &str = "string";
+    pub const NOT_OPERATOR: &str = "not_operator";
+    pub const UNARY_OPERATOR: &str = "unary_operator";
+}
+
+pub mod fields {
+    pub const CONDITION: &str = "condition";
+    pub const ARGUMENTS: &str = "arguments";
+    pub const RETURN_TYPE: &str = "return_type";
+}
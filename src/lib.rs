@@ -2,6 +2,9 @@ pub mod core;
 pub mod languages;
 
 // Re-export key items for easy importing in this crate
+pub use core::campaign::{
+    Campaign, CampaignBuilder, CampaignEvent, CampaignResult, MutantResult, SkipReason,
+};
 pub use core::store::SqlStore;
 pub use core::types;
 
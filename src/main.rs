@@ -7,10 +7,17 @@ use mewt::run_main;
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create language registry and register supported languages
     let mut registry = LanguageRegistry::new();
+    registry.register(mewt::languages::c::engine::CLanguageEngine::new());
+    registry.register(mewt::languages::cairo::engine::CairoLanguageEngine::new());
+    registry.register(mewt::languages::cpp::engine::CppLanguageEngine::new());
     registry.register(mewt::languages::go::engine::GoLanguageEngine::new());
     registry.register(mewt::languages::javascript::engine::JavaScriptLanguageEngine::new());
+    registry.register(mewt::languages::r#move::engine::MoveLanguageEngine::new());
+    registry.register(mewt::languages::python::engine::PythonLanguageEngine::new());
     registry.register(mewt::languages::rust::engine::RustLanguageEngine::new());
     registry.register(mewt::languages::solidity::engine::SolidityLanguageEngine::new());
+    registry.register(mewt::languages::typescript::engine::TypeScriptLanguageEngine::new());
+    registry.register(mewt::languages::vyper::engine::VyperLanguageEngine::new());
 
     // Run the shared main function
     run_main(Arc::new(registry), "mewt", "Mutation testing framework").await?;
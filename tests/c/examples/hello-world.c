@@ -0,0 +1,45 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+struct session {
+    unsigned char key[32];
+    int refs;
+};
+
+static int checksum(const unsigned char *buf, size_t len) {
+    int sum = 0;
+    for (size_t i = 0; i < len; i++) {
+        sum += buf[i] ^ 0x5a;
+    }
+    return sum % 251;
+}
+
+int last_byte(const unsigned char *buf, size_t len) {
+    if (len == 0) {
+        return -1;
+    }
+    return *(buf + len - 1);
+}
+
+void session_close(struct session *s) {
+    memset(s->key, 0, sizeof(s->key));
+    s->refs -= 1;
+    if (s->refs <= 0) {
+        free(s);
+    }
+}
+
+int main(void) {
+    struct session *s = calloc(1, sizeof(*s));
+    if (s == NULL) {
+        abort();
+    }
+    s->refs = 1;
+    while (s->refs > 0 && s->key[0] != 1) {
+        s->key[0] = 1;
+    }
+    printf("%d %d\n", checksum(s->key, sizeof(s->key)), last_byte(s->key, 32));
+    session_close(s);
+    return 0;
+}
@@ -0,0 +1,132 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
+use mewt::LanguageEngine;
+use mewt::languages::c::engine::CLanguageEngine;
+use mewt::types::Target;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.c");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "C".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_example_mutations() {
+    let source = include_str!("examples/hello-world.c");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(pairs(&mutants, "FCR"), vec![("free(s);", "")]);
+    assert_eq!(
+        pairs(&mutants, "MCR"),
+        vec![("memset(s->key, 0, sizeof(s->key));", "")]
+    );
+
+    // Both operators of the dereferenced offset are swapped
+    assert_eq!(pairs(&mutants, "PAS"), vec![("+", "-"), ("-", "+")]);
+
+    let aio = pairs(&mutants, "AIO");
+    assert!(aio.contains(&("buf[i]", "buf[i + 1]")));
+    assert!(aio.contains(&("buf[i]", "buf[i - 1]")));
+    assert_eq!(aio.len(), 6);
+}
+
+#[test]
+fn test_error_replacement_uses_abort() {
+    let source = include_str!("examples/hello-world.c");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er: Vec<_> = mutants.iter().filter(|m| m.mutation_slug == "ER").collect();
+    assert!(!er.is_empty());
+    for mutant in &er {
+        assert_eq!(mutant.new_text, "abort();");
+        // Statements that already abort are left alone
+        assert!(!mutant.old_text.contains("abort("));
+    }
+
+    // Conditions are hardcoded with integers so no <stdbool.h> is needed
+    let if_false = pairs(&mutants, "IF");
+    assert!(if_false.contains(&("(len == 0)", "(0)")));
+    assert_eq!(
+        pairs(&mutants, "WF"),
+        vec![("(s->refs > 0 && s->key[0] != 1)", "(0)")]
+    );
+}
+
+#[test]
+fn test_pointer_arithmetic_left_to_pas() {
+    let source =
+        "int f(int *p, int n) {\n    int *q = p + n;\n    return *(q - 1) + n - (q - p);\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // `p + n` and `q - 1` are pointers; `q - p` is a pointer difference
+    let pas: Vec<_> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug == "PAS")
+        .map(|m| &source[m.byte_offset as usize - 2..][..5])
+        .collect();
+    assert_eq!(pas, vec!["p + n", "q - 1"]);
+
+    // Only the integer arithmetic is shuffled; `p * n` wouldn't compile
+    let aos: Vec<_> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug == "AOS")
+        .map(|m| m.byte_offset as usize)
+        .collect();
+    let plus = source.find(") + n").unwrap() + 2;
+    let minus = source.find("n - (").unwrap() + 2;
+    assert_eq!(aos, [[plus; 4], [minus; 4]].concat());
+}
+
+#[test]
+fn test_array_index_wraps_complex_expressions() {
+    let source = "int f(int *a, int i, int j) {\n    return a[i * j];\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "AIO"),
+        vec![
+            ("a[i * j]", "a[(i * j) + 1]"),
+            ("a[i * j]", "a[(i * j) - 1]")
+        ]
+    );
+}
+
+#[test]
+fn test_unary_mutations() {
+    let source = "int clamp(int x) {\n    if (x < 0) {\n        return -x;\n    }\n    x--;\n    return !x ? 0 : ~x;\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "UOD"),
+        vec![("-", ""), ("!", ""), ("~", "")]
+    );
+    assert_eq!(pairs(&mutants, "UOR"), vec![("--", "++")]);
+    assert_eq!(pairs(&mutants, "UOI"), vec![("(x < 0)", "(!(x < 0))")]);
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = CLanguageEngine::new();
+    let source = include_str!("examples/hello-world.c");
+    let (_temp_dir, target) = create_test_target(source);
+    assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+}
@@ -0,0 +1,4 @@
+mod common;
+mod c {
+    mod integration_tests;
+}
@@ -0,0 +1,118 @@
+use core::num::traits::Zero;
+use starknet::{ContractAddress, get_caller_address};
+
+#[derive(Drop, Serde, Copy, starknet::Store, PartialEq)]
+pub enum Status {
+    Active,
+    Paused: felt252,
+}
+
+pub const MAX: u256 = 1_000_u256;
+
+#[starknet::interface]
+pub trait ICounter<TContractState> {
+    fn get(self: @TContractState) -> u32;
+    fn bump(ref self: TContractState, by: u32);
+}
+
+#[starknet::component]
+pub mod OwnableComponent {
+    #[storage]
+    pub struct Storage {}
+}
+
+#[starknet::contract]
+mod Counter {
+    use super::{ICounter, Status};
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess, Map};
+
+    component!(path: OwnableComponent, storage: ownable, event: OwnableEvent);
+
+    #[abi(embed_v0)]
+    impl OwnableImpl = OwnableComponent::OwnableImpl<ContractState>;
+
+    #[storage]
+    struct Storage {
+        value: u32,
+        balances: Map<ContractAddress, u256>,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Bumped: Bumped,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Bumped {
+        #[key]
+        by: u32,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, initial: u32) {
+        self.value.write(initial);
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn get(self: @ContractState) -> u32 {
+            self.value.read()
+        }
+
+        fn bump(ref self: ContractState, by: u32) {
+            assert(by != 0, 'zero');
+            let current = self.value.read();
+            self.value.write(current + by);
+            self.emit(Bumped { by });
+            self.emit(Event::Bumped(Bumped { by: by }));
+        }
+    }
+
+    fn classify(x: felt252) -> felt252 {
+        match x {
+            0 => 'zero',
+            1 | 2 => 'small',
+            _ => 'big',
+        }
+    }
+
+    fn snapshots(arr: @Array<u32>, ref other: Array<u32>) -> u32 {
+        let len = arr.len();
+        let first = *arr.at(0);
+        other.append(first);
+        let mut data: Array<felt252> = array![];
+        let span = data.span();
+        let x: u8 = 0xff_u8;
+        let s = 'hello';
+        if let Option::Some(v) = span.get(0) {
+            return len;
+        }
+        for i in 0..len {
+            data.append(i.into());
+        };
+        let mut i = 0;
+        loop {
+            if i == 10 {
+                break;
+            }
+            i += 1;
+        };
+        let v = Point { x: 1, y: 2 };
+        let Point { x, y: _ } = v;
+        let t = (x, 1_u32);
+        let p = @v;
+        let c = |a: u32, b| a + b;
+        let r = foo::<u32>(ref data, :len, key: 2)?;
+        let arr2: [u32; 3] = [1, 2, 3];
+        !(x > 0) && y < 3 || ~z == 0
+    }
+
+    #[generate_trait]
+    impl Internal<T, +Drop<T>, impl TCopy: Copy<T>, const N: usize> of InternalTrait<T> {
+        fn helper(self: @ContractState) nopanic {}
+    }
+}
+
+extern fn felt252_add(a: felt252, b: felt252) -> felt252 nopanic;
+extern fn bar(a: felt252) -> felt252 implicits(RangeCheck) nopanic;
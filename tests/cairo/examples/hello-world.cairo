@@ -0,0 +1,47 @@
+#[starknet::interface]
+trait IVault<TContractState> {
+    fn deposit(ref self: TContractState, amount: u128);
+    fn withdraw(ref self: TContractState, amount: u128);
+    fn balance_of(self: @TContractState, account: felt252) -> u128;
+}
+
+fn fee(amount: u128, bps: u128) -> u128 {
+    let mut total = amount * bps / 10000;
+    if total == 0 && amount > 0 {
+        total += 1;
+    }
+    total
+}
+
+fn sum_to(n: felt252) -> felt252 {
+    let mut acc: felt252 = 0;
+    let mut i: felt252 = 0;
+    while i != n {
+        i = i + 1;
+        acc = acc + i;
+    }
+    acc
+}
+
+#[starknet::contract]
+mod Vault {
+    use starknet::get_caller_address;
+    use super::fee;
+
+    #[storage]
+    struct Storage {
+        paused: bool,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn check_amount(self: @ContractState, amount: u128, limit: u128) {
+            assert(amount > 0, 'zero amount');
+            assert!(amount <= limit, "over limit");
+            let charged = fee(amount, 30);
+            if charged >= amount {
+                panic!("fee too high");
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
+use mewt::LanguageEngine;
+use mewt::languages::cairo::engine::CairoLanguageEngine;
+use mewt::types::Target;
+use mewt::utils::parse_source;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.cairo");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "Cairo".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_example_contract_mutations() {
+    let source = include_str!("examples/hello-world.cairo");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CairoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let asr = pairs(&mutants, "ASR");
+    assert_eq!(
+        asr,
+        vec![
+            ("assert(amount > 0, 'zero amount');", ""),
+            ("assert!(amount <= limit, \"over limit\");", ""),
+        ]
+    );
+
+    let wf = pairs(&mutants, "WF");
+    assert_eq!(wf, vec![("i != n", "false")]);
+
+    let aaos = pairs(&mutants, "AAOS");
+    assert_eq!(aaos.len(), 4, "`+=` should shuffle to every other operator");
+
+    // Cairo has no shift operators
+    assert!(mutants.iter().all(|m| m.mutation_slug != "SOS"));
+}
+
+#[test]
+fn test_error_replacement_uses_panic() {
+    let source = include_str!("examples/hello-world.cairo");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CairoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er: Vec<_> = mutants.iter().filter(|m| m.mutation_slug == "ER").collect();
+    assert!(!er.is_empty());
+    for mutant in &er {
+        assert_eq!(mutant.new_text, "panic!(\"mewt\");");
+        // Asserts and existing panics are left to ASR
+        assert!(!mutant.old_text.starts_with("assert"));
+        assert!(!mutant.old_text.contains("panic"));
+    }
+}
+
+#[test]
+fn test_comment_replacement_uses_line_comments() {
+    let source = "fn f(x: u128) -> u128 {\n    if x > 1 {\n        foo(x);\n    }\n    x\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CairoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Only the outermost statement is commented, one `//` per line
+    let cr = pairs(&mutants, "CR");
+    assert_eq!(
+        cr,
+        vec![(
+            "if x > 1 {\n        foo(x);\n    }",
+            "// if x > 1 {\n//         foo(x);\n//     }"
+        )]
+    );
+}
+
+#[test]
+fn test_felt_arithmetic_shuffles() {
+    let source = "fn f(a: felt252, b: felt252) -> felt252 {\n    let c = a * b;\n    c - a\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CairoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let aos = pairs(&mutants, "AOS");
+    assert_eq!(aos.len(), 8);
+    assert!(aos.contains(&("*", "/")));
+    assert!(aos.contains(&("-", "+")));
+}
+
+#[test]
+fn test_cairo_syntax_parses() {
+    let engine = CairoLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.cairo"),
+        include_str!("examples/counter.cairo"),
+    ] {
+        let tree = parse_source(source, &engine.tree_sitter_language()).unwrap();
+        assert!(!tree.root_node().has_error(), "{}", tree.root_node());
+    }
+}
+
+#[test]
+fn test_contract_bodies_mutate() {
+    let source = include_str!("examples/counter.cairo");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CairoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Statements in `ref self` methods of an `impl ... of ...` block
+    let er = pairs(&mutants, "ER");
+    assert!(er.contains(&("self.value.write(current + by);", "panic!(\"mewt\");")));
+
+    // Short strings are felt252 values, including the ones a match on a felt returns
+    let slr = pairs(&mutants, "SLR");
+    for text in ["'zero'", "'small'", "'big'", "'hello'"] {
+        assert!(
+            slr.contains(&(text, "''")),
+            "{text} should be emptied: {slr:?}"
+        );
+    }
+
+    // Match arms on felts are literal patterns
+    let nlr = pairs(&mutants, "NLR");
+    assert!(nlr.iter().any(|(old, _)| *old == "0"));
+
+    // `component!` and the other module-level items aren't statements
+    assert!(
+        mutants
+            .iter()
+            .all(|m| !m.old_text.starts_with("component!") && !m.old_text.starts_with("impl"))
+    );
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = CairoLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.cairo"),
+        include_str!("examples/counter.cairo"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
+}
@@ -0,0 +1,4 @@
+mod common;
+mod cairo {
+    mod integration_tests;
+}
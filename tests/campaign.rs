@@ -0,0 +1,205 @@
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use mewt::core::queue::TestQueue;
+use mewt::types::Status;
+use mewt::types::config::{Config, TestConfig};
+use mewt::{Campaign, CampaignEvent, LanguageRegistry, SkipReason, SqlStore};
+
+const SOURCE: &str = "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+
+fn registry() -> Arc<LanguageRegistry> {
+    let mut registry = LanguageRegistry::new();
+    registry.register(mewt::languages::rust::engine::RustLanguageEngine::new());
+    Arc::new(registry)
+}
+
+async fn store(dir: &Path) -> SqlStore {
+    let db = dir.join("mewt.sqlite");
+    SqlStore::new(format!("sqlite:{}?mode=rwc", db.display()))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn campaigns_run_side_by_side() {
+    let (first, second) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+    let (first_src, second_src) = (first.path().join("lib.rs"), second.path().join("lib.rs"));
+    fs::write(&first_src, SOURCE).unwrap();
+    fs::write(&second_src, SOURCE.replace("add", "sum")).unwrap();
+
+    // The "test suite" passes as long as the addition is intact
+    let check = |path: &Path| format!("grep -q 'a + b' '{}'", path.display());
+
+    // One campaign takes its test command from its config, the other from the builder
+    let config = Config {
+        test: Some(TestConfig {
+            cmd: Some(check(&first_src)),
+            timeout: Some(30),
+            per_target: None,
+        }),
+        ..Default::default()
+    };
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    let first_campaign = Campaign::builder(config, registry(), store(first.path()).await)
+        .targets(vec![first_src.display().to_string()])
+        .on_event(move |event| recorded.lock().unwrap().push(event.clone()))
+        .build();
+    let second_campaign =
+        Campaign::builder(Config::default(), registry(), store(second.path()).await)
+            .targets(vec![second_src.display().to_string()])
+            .test_cmd(check(&second_src))
+            .test_timeout(30)
+            .build();
+
+    let (first_result, second_result) = tokio::join!(first_campaign.run(), second_campaign.run());
+    let (first_result, second_result) = (first_result.unwrap(), second_result.unwrap());
+
+    for result in [&first_result, &second_result] {
+        assert!(!result.interrupted);
+        assert!(result.generated > 0);
+        assert_eq!(result.results.len(), result.summary.tested);
+        assert!(
+            result
+                .results
+                .iter()
+                .any(|r| r.status == Status::TestFail && r.line == 2)
+        );
+    }
+    assert!(first_result.results.iter().all(|r| r.target == first_src));
+    assert!(second_result.results.iter().all(|r| r.target == second_src));
+
+    // Sources are restored after testing
+    assert_eq!(fs::read_to_string(&first_src).unwrap(), SOURCE);
+
+    let events = events.lock().unwrap();
+    assert!(matches!(
+        events.first(),
+        Some(CampaignEvent::MutantsGenerated { .. })
+    ));
+    assert!(matches!(
+        events.last(),
+        Some(CampaignEvent::Finished {
+            interrupted: false,
+            ..
+        })
+    ));
+    let tested = events
+        .iter()
+        .filter(|e| matches!(e, CampaignEvent::MutantTested(_)))
+        .count();
+    assert_eq!(tested, first_result.results.len());
+}
+
+#[tokio::test]
+async fn campaigns_default_the_database_and_ignore_file_without_a_namespace() {
+    // No binary has set a namespace, so the library defaults apply
+    assert_eq!(Config::default().db(), "mewt.sqlite");
+
+    let dir = tempfile::tempdir().unwrap();
+    let (kept, ignored) = (dir.path().join("lib.rs"), dir.path().join("gen.rs"));
+    fs::write(&kept, SOURCE).unwrap();
+    fs::write(&ignored, SOURCE).unwrap();
+    fs::write(dir.path().join(".mewtignore"), "gen.rs\n").unwrap();
+
+    let result = Campaign::builder(Config::default(), registry(), store(dir.path()).await)
+        .targets(vec![dir.path().display().to_string()])
+        .test_cmd("true")
+        .test_timeout(30)
+        .build()
+        .run()
+        .await
+        .unwrap();
+    assert!(result.generated > 0);
+    assert!(result.results.iter().all(|r| r.target == kept));
+}
+
+#[tokio::test]
+async fn campaigns_name_the_database_and_ignore_file_after_the_config_namespace() {
+    let config = Config::for_namespace("acme");
+    assert_eq!(config.db(), "acme.sqlite");
+    assert_eq!(config.config_filename(), "acme.toml");
+
+    let dir = tempfile::tempdir().unwrap();
+    let (kept, ignored) = (dir.path().join("lib.rs"), dir.path().join("gen.rs"));
+    fs::write(&kept, SOURCE).unwrap();
+    fs::write(&ignored, SOURCE).unwrap();
+    fs::write(dir.path().join(".acmeignore"), "gen.rs\n").unwrap();
+    // Another tool's ignore file is not ours
+    fs::write(dir.path().join(".mewtignore"), "lib.rs\n").unwrap();
+
+    let result = Campaign::builder(config, registry(), store(dir.path()).await)
+        .targets(vec![dir.path().display().to_string()])
+        .test_cmd("true")
+        .test_timeout(30)
+        .build()
+        .run()
+        .await
+        .unwrap();
+    assert!(result.generated > 0);
+    assert!(result.results.iter().all(|r| r.target == kept));
+}
+
+#[tokio::test]
+async fn queued_mutants_skip_targets_edited_since_generation() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("lib.rs");
+    fs::write(&src, SOURCE).unwrap();
+    let store = store(dir.path()).await;
+
+    Campaign::builder(Config::default(), registry(), store.clone())
+        .targets(vec![src.display().to_string()])
+        .test_cmd("true")
+        .test_timeout(30)
+        .build()
+        .run()
+        .await
+        .unwrap();
+
+    // The file is edited and saved after its mutants were generated
+    let edited = SOURCE.replace("a + b", "b + a");
+    fs::write(&src, &edited).unwrap();
+
+    let target = store.get_all_targets().await.unwrap().remove(0);
+    let ids: Vec<i64> = store
+        .get_mutants(target.id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|m| m.id)
+        .collect();
+    let tested = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&tested);
+    let skipped = Arc::new(Mutex::new(0));
+    let skips = Arc::clone(&skipped);
+    let queue = Arc::new(TestQueue::new(
+        store,
+        registry(),
+        Config::default(),
+        Some("true".to_string()),
+        Some(30),
+        false,
+        Arc::new(move |event| match event {
+            CampaignEvent::MutantTested(_) => *counter.lock().unwrap() += 1,
+            CampaignEvent::MutantSkipped {
+                reason: SkipReason::TargetChanged,
+                ..
+            } => *skips.lock().unwrap() += 1,
+            _ => {}
+        }),
+    ));
+    let worker = tokio::spawn(Arc::clone(&queue).work());
+    assert_eq!(queue.push(ids.clone()), ids.len());
+    while queue.snapshot() != (None, vec![]) {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    queue.stop();
+    worker.await.unwrap();
+
+    // Nothing was tested against the old text, and the edit survives
+    assert_eq!(*tested.lock().unwrap(), 0);
+    assert_eq!(*skipped.lock().unwrap(), ids.len());
+    assert_eq!(fs::read_to_string(&src).unwrap(), edited);
+}
@@ -0,0 +1,29 @@
+//! Helpers shared by the language integration tests
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use mewt::types::Mutant;
+
+/// The `(old_text, new_text)` pairs of the mutants generated by one operator
+pub fn pairs<'a>(mutants: &'a [Mutant], slug: &str) -> Vec<(&'a str, &'a str)> {
+    mutants
+        .iter()
+        .filter(|m| m.mutation_slug == slug)
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect()
+}
+
+/// Fail if two mutants make the same edit, which would test the same program twice
+pub fn assert_no_duplicate_mutants(mutants: &[Mutant]) {
+    let mut seen: HashMap<(u32, &str, &str), &str> = HashMap::new();
+    for m in mutants {
+        let key = (m.byte_offset, m.old_text.as_str(), m.new_text.as_str());
+        if let Some(first) = seen.insert(key, &m.mutation_slug) {
+            panic!(
+                "{} and {} both replace {:?} with {:?} at byte {}",
+                first, m.mutation_slug, m.old_text, m.new_text, m.byte_offset
+            );
+        }
+    }
+}
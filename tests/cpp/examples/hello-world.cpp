@@ -0,0 +1,46 @@
+#include <cstdlib>
+#include <cstring>
+#include <iostream>
+#include <vector>
+
+class Buffer {
+public:
+    explicit Buffer(std::size_t n) : data_(static_cast<char *>(std::malloc(n))), size_(n) {}
+
+    ~Buffer() {
+        std::memset(data_, 0, size_);
+        std::free(data_);
+    }
+
+    char at(std::size_t i) const {
+        if (i >= size_) {
+            return 0;
+        }
+        return *(data_ + i);
+    }
+
+private:
+    char *data_;
+    std::size_t size_;
+};
+
+int sum(const std::vector<int> &values) {
+    int total = 0;
+    for (int v : values) {
+        if (v < 0) {
+            continue;
+        }
+        total += v;
+    }
+    return total;
+}
+
+int main() {
+    std::vector<int> values{1, 2, 3};
+    bool verbose = true;
+    Buffer buf(16);
+    if (verbose && values[0] == 1) {
+        std::cout << sum(values) << buf.at(2) << std::endl;
+    }
+    return 0;
+}
@@ -0,0 +1,86 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
+use mewt::LanguageEngine;
+use mewt::languages::cpp::engine::CppLanguageEngine;
+use mewt::types::Target;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.cpp");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "C++".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_example_mutations() {
+    let source = include_str!("examples/hello-world.cpp");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CppLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // std::-qualified calls are removed too
+    assert_eq!(pairs(&mutants, "FCR"), vec![("std::free(data_);", "")]);
+    assert_eq!(
+        pairs(&mutants, "MCR"),
+        vec![("std::memset(data_, 0, size_);", "")]
+    );
+    assert_eq!(pairs(&mutants, "PAS"), vec![("+", "-")]);
+    assert_eq!(
+        pairs(&mutants, "AIO"),
+        vec![
+            ("values[0]", "values[0 + 1]"),
+            ("values[0]", "values[0 - 1]")
+        ]
+    );
+    assert_eq!(pairs(&mutants, "LC"), vec![("continue;", "break;")]);
+}
+
+#[test]
+fn test_error_replacement_uses_std_abort() {
+    let source = include_str!("examples/hello-world.cpp");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CppLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er: Vec<_> = mutants.iter().filter(|m| m.mutation_slug == "ER").collect();
+    assert!(!er.is_empty());
+    assert!(er.iter().all(|m| m.new_text == "std::abort();"));
+    // Range-based for loops are statements too
+    assert!(
+        er.iter()
+            .any(|m| m.old_text.starts_with("for (int v : values)"))
+    );
+
+    let if_true = pairs(&mutants, "IT");
+    assert!(if_true.contains(&("(i >= size_)", "(true)")));
+}
+
+#[test]
+fn test_pointer_offsets_not_shuffled_by_aos() {
+    let source = "const char *skip(const char *p, int n) {\n    const char *q = p + n;\n    return q + n * 2;\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = CppLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(pairs(&mutants, "PAS"), vec![("+", "-"), ("+", "-")]);
+    // Only `n * 2` is integer arithmetic
+    let aos = pairs(&mutants, "AOS");
+    assert_eq!(aos.len(), 4);
+    assert!(aos.iter().all(|(old, _)| *old == "*"));
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = CppLanguageEngine::new();
+    let source = include_str!("examples/hello-world.cpp");
+    let (_temp_dir, target) = create_test_target(source);
+    assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+}
@@ -0,0 +1,4 @@
+mod common;
+mod cpp {
+    mod integration_tests;
+}
@@ -1,3 +1,4 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
 use mewt::LanguageEngine;
 use mewt::languages::go::engine::GoLanguageEngine;
 use mewt::types::Target;
@@ -194,3 +195,173 @@ func testFunc() int {
         "AST mutations should affect multiple lines"
     );
 }
+
+const WORKER: &str = r#"package main
+
+func process(ch chan int, items []int) (int, error) {
+	mu.Lock()
+	defer mu.Unlock()
+	n, err := load()
+	if err != nil {
+		return 0, err
+	}
+	if cfg, cfgErr := config(); cfgErr == nil {
+		n += cfg.Size
+	}
+	go notify(ch)
+	ch <- n
+	head := items[0:n]
+	for len(head) > 0 {
+		head = head[1:]
+	}
+	return n, nil
+}
+"#;
+
+#[test]
+fn test_error_handling_mutations() {
+    let (_temp_dir, target) = create_test_target(WORKER);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // `return n, nil` has nothing left to replace
+    assert_eq!(pairs(&mutants, "ERN"), vec![("err", "nil")]);
+    assert_eq!(pairs(&mutants, "ENN"), vec![("!=", "=="), ("==", "!=")]);
+}
+
+#[test]
+fn test_nil_comparisons_not_shuffled_as_orderings() {
+    let source = r#"package main
+
+func lookup(m map[string]*int, k string) int {
+	p := m[k]
+	if p == nil {
+		return 0
+	}
+	if err := check(); err != nil {
+		return -1
+	}
+	return *p
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Nil can't be ordered, and the error check belongs to ENN
+    assert_eq!(pairs(&mutants, "COS"), vec![("==", "!=")]);
+    assert_eq!(pairs(&mutants, "ENN"), vec![("!=", "==")]);
+
+    let (_temp_dir, target) = create_test_target(WORKER);
+    let mutants = engine.apply_all_mutations(&target);
+    assert_eq!(
+        pairs(&mutants, "COS").len(),
+        5,
+        "Only len(head) > 0 is shuffled"
+    );
+}
+
+#[test]
+fn test_concurrency_mutations() {
+    let (_temp_dir, target) = create_test_target(WORKER);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(pairs(&mutants, "DSR"), vec![("defer mu.Unlock()", "")]);
+    assert_eq!(
+        pairs(&mutants, "GSC"),
+        vec![("go notify(ch)", "notify(ch)")]
+    );
+    assert_eq!(pairs(&mutants, "CSR"), vec![("ch <- n", "<-ch")]);
+}
+
+#[test]
+fn test_slice_bounds_and_loops() {
+    let (_temp_dir, target) = create_test_target(WORKER);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // A literal 0 lower bound is never decremented
+    assert_eq!(
+        pairs(&mutants, "SBM"),
+        vec![
+            ("items[0:n]", "items[0 + 1:n]"),
+            ("items[0:n]", "items[0:n + 1]"),
+            ("items[0:n]", "items[0:n - 1]"),
+            ("head[1:]", "head[1 + 1:]"),
+            ("head[1:]", "head[1 - 1:]"),
+        ]
+    );
+    assert_eq!(pairs(&mutants, "WF"), vec![("len(head) > 0", "false")]);
+    assert_eq!(pairs(&mutants, "AAOS").len(), 4);
+}
+
+#[test]
+fn test_unary_mutations() {
+    let source = r#"package main
+
+func count(items []int, done bool) int {
+	n := 0
+	for _, item := range items {
+		if !done {
+			n++
+		}
+		if item > 0 {
+			n -= -item
+		}
+	}
+	return n
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(pairs(&mutants, "UOD"), vec![("!", ""), ("-", "")]);
+    assert_eq!(pairs(&mutants, "UOR"), vec![("++", "--")]);
+    // `!done` is already negated, so only the second condition is
+    assert_eq!(pairs(&mutants, "UOI"), vec![("item > 0", "!(item > 0)")]);
+}
+
+#[test]
+fn test_return_value_mutations() {
+    let source = r#"package main
+
+func name(u *User) string {
+	return u.Name
+}
+
+func find(id int) *User {
+	return users[id]
+}
+
+func lookup(id int) (*User, error) {
+	return nil, errNotFound
+}
+
+func origin() Point {
+	return Point{}
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = GoLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Multi-value returns and struct results are left alone
+    assert_eq!(
+        pairs(&mutants, "RV"),
+        vec![("u.Name", "\"\""), ("users[id]", "nil")]
+    );
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = GoLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.go"),
+        include_str!("examples/simple.go"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
+}
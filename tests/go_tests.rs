@@ -1,3 +1,4 @@
+mod common;
 mod go {
     mod integration_tests;
 }
@@ -1,3 +1,4 @@
+use crate::common::assert_no_duplicate_mutants;
 use mewt::LanguageEngine;
 use mewt::languages::javascript::engine::JavaScriptLanguageEngine;
 use mewt::types::Target;
@@ -39,31 +40,6 @@ function testFunc() {
     assert!(slugs.len() > 1, "Should generate diverse mutation types");
 }
 
-#[test]
-fn test_typescript_support() {
-    let source = r#"
-interface User {
-    name: string;
-    age: number;
-}
-
-function greet(user: User): string {
-    if (user.age > 18) {
-        return `Hello, ${user.name}!`;
-    }
-    return "Hello!";
-}
-"#;
-    let (_temp_dir, target) = create_test_target(source, "test.ts");
-    let engine = JavaScriptLanguageEngine::new();
-    let mutants = engine.apply_all_mutations(&target);
-
-    assert!(
-        !mutants.is_empty(),
-        "Should generate mutations for TypeScript"
-    );
-}
-
 #[test]
 fn test_jsx_support() {
     let source = r#"
@@ -111,133 +87,133 @@ function calc(a, b) {
     assert!(los_count > 0, "Should generate logical operator mutations");
 }
 
-#[test]
-fn test_typescript_generics_not_mutated() {
-    let source = r#"
-// TypeScript generics should NOT be mutated
-const emitter = module.get<EventEmitter2>(EventEmitter2);
-const result = foo<string, number>(arg1, arg2);
-
-function generic<T>(value: T): T {
-    return value;
-}
-
-// Real comparisons SHOULD be mutated
-if (a < b && c > d) {
-    return true;
+const CHECKOUT: &str = r#"
+async function checkout(cart, user) {
+    const profile = await loadProfile(user?.id);
+    if (cart.items.some(item => item.price === 0)) {
+        return Promise.reject(new Error("free item"));
+    }
+    const limit = profile.limit ?? 100;
+    if (cart.total != limit) {
+        cart.log.push(limit);
+    }
+    const match = cart.items.find(item => item.sku == profile.sku);
+    return match;
 }
-
-const max = x >= y ? x : y;
 "#;
-    let (_temp_dir, target) = create_test_target(source, "test.ts");
-    let engine = JavaScriptLanguageEngine::new();
-    let mutants = engine.apply_all_mutations(&target);
 
-    // Filter to just COS mutations
-    let cos_mutants: Vec<_> = mutants
+fn mutated_by(slug: &str) -> Vec<String> {
+    let (_temp_dir, target) = create_test_target(CHECKOUT, "checkout.js");
+    let engine = JavaScriptLanguageEngine::new();
+    engine
+        .apply_all_mutations(&target)
         .iter()
-        .filter(|m| m.mutation_slug.starts_with("COS"))
-        .collect();
+        .filter(|m| m.mutation_slug == slug)
+        .map(|m| target.mutate(m).expect("Failed to apply mutant"))
+        .collect()
+}
 
-    // Should have COS mutations (from the actual comparison operators)
+#[test]
+fn test_equality_mutations() {
+    let negated = mutated_by("EQN");
+    assert_eq!(negated.len(), 3);
+    assert!(negated.iter().any(|t| t.contains("item.price !== 0")));
+    assert!(negated.iter().any(|t| t.contains("cart.total == limit")));
     assert!(
-        !cos_mutants.is_empty(),
-        "Should generate COS mutations for real comparison operators"
+        negated
+            .iter()
+            .any(|t| t.contains("item.sku != profile.sku"))
     );
 
-    // Verify no mutations contain "get<", "foo<", or "generic<"
-    // (these would indicate mutations of TypeScript generics)
-    for mutant in &cos_mutants {
-        assert!(
-            !mutant.new_text.contains("get<")
-                && !mutant.new_text.contains("get==")
-                && !mutant.new_text.contains("get!=")
-                && !mutant.new_text.contains("get<=")
-                && !mutant.new_text.contains("get>=")
-                && !mutant.new_text.contains("foo<")
-                && !mutant.new_text.contains("foo==")
-                && !mutant.new_text.contains("foo!=")
-                && !mutant.new_text.contains("foo<=")
-                && !mutant.new_text.contains("foo>=")
-                && !mutant.new_text.contains("generic<")
-                && !mutant.new_text.contains("generic==")
-                && !mutant.new_text.contains("generic!=")
-                && !mutant.new_text.contains("generic<=")
-                && !mutant.new_text.contains("generic>="),
-            "COS mutation should not mutate TypeScript generic brackets: {}",
-            mutant.new_text
-        );
-    }
-
-    // Verify we have mutations for the actual comparison operators
-    // (The old_text will just be the operator, not the full expression)
-    let has_less_than_mutation = cos_mutants.iter().any(|m| m.old_text == "<");
-    let has_greater_than_mutation = cos_mutants.iter().any(|m| m.old_text == ">");
-    let has_gte_mutation = cos_mutants.iter().any(|m| m.old_text == ">=");
-
+    let strictness = mutated_by("EQS");
+    assert_eq!(strictness.len(), 3);
+    assert!(strictness.iter().any(|t| t.contains("item.price == 0")));
+    assert!(
+        strictness
+            .iter()
+            .any(|t| t.contains("cart.total !== limit"))
+    );
     assert!(
-        has_less_than_mutation && has_greater_than_mutation && has_gte_mutation,
-        "Should mutate actual comparison operators (<, >, >=) in conditions"
+        strictness
+            .iter()
+            .any(|t| t.contains("item.sku === profile.sku"))
     );
 }
 
 #[test]
-fn test_tsx_jsx_and_generics_not_mutated() {
-    let source = r#"
-// TSX with both JSX elements and TypeScript generics
-function App<T>(props: { value: T }) {
-    return <div>Hello</div>;
+fn test_equality_operators_not_shuffled_by_cos() {
+    // CHECKOUT only compares for equality, which EQN and EQS already cover
+    assert!(mutated_by("COS").is_empty());
 }
 
-const result = foo<string, number>(arg1, arg2);
-const element = <Component prop="value" />;
+#[test]
+fn test_await_and_optional_chaining_removal() {
+    let awaited = mutated_by("AR");
+    assert_eq!(awaited.len(), 1);
+    assert!(awaited[0].contains("const profile = loadProfile(user?.id);"));
 
-// Real comparisons SHOULD be mutated
-if (a < b && c > d) {
-    return true;
+    let unchained = mutated_by("OCR");
+    assert_eq!(unchained.len(), 1);
+    assert!(unchained[0].contains("loadProfile(user.id)"));
 }
-"#;
-    let (_temp_dir, target) = create_test_target(source, "test.tsx");
-    let engine = JavaScriptLanguageEngine::new();
-    let mutants = engine.apply_all_mutations(&target);
 
-    // Filter to just COS mutations
-    let cos_mutants: Vec<_> = mutants
-        .iter()
-        .filter(|m| m.mutation_slug.starts_with("COS"))
-        .collect();
+#[test]
+fn test_nullish_coalescing_swap() {
+    let swapped = mutated_by("NCS");
+    assert_eq!(swapped.len(), 1);
+    assert!(swapped[0].contains("profile.limit || 100"));
+}
 
-    // Should have COS mutations (from the actual comparison operators)
+#[test]
+fn test_array_method_swaps() {
+    let swapped = mutated_by("AMS");
+    assert_eq!(swapped.len(), 3);
+    assert!(swapped.iter().any(|t| t.contains("cart.items.every(")));
     assert!(
-        !cos_mutants.is_empty(),
-        "Should generate COS mutations for real comparison operators"
+        swapped
+            .iter()
+            .any(|t| t.contains("cart.log.unshift(limit)"))
     );
+    assert!(swapped.iter().any(|t| t.contains("cart.items.filter(")));
+    // Property reads are not method calls
+    assert!(swapped.iter().all(|t| t.contains("cart.items.")));
+}
 
-    // Verify no mutations contain JSX tags or generic brackets
-    for mutant in &cos_mutants {
-        let text = &mutant.new_text;
-        assert!(
-            !text.contains("<div")
-                && !text.contains("</div")
-                && !text.contains("<Component")
-                && !text.contains("App<")
-                && !text.contains("foo<")
-                && !text.contains("foo==")
-                && !text.contains("foo!=")
-                && !text.contains("App==")
-                && !text.contains("App!="),
-            "COS mutation should not mutate TSX JSX elements or TypeScript generics: {}",
-            text
-        );
-    }
+#[test]
+fn test_empty_return_values() {
+    let emptied = mutated_by("ERV");
+    assert_eq!(emptied.len(), 6, "Two returns, three empty values each");
+    assert!(emptied.iter().any(|t| t.contains("return [];")));
+    assert!(emptied.iter().any(|t| t.contains("return \"\";")));
+    assert!(emptied.iter().any(|t| t.contains("return undefined;")));
+}
 
-    // Verify we have mutations for the actual comparison operators
-    let has_comparison_mutations = cos_mutants
-        .iter()
-        .any(|m| m.old_text == "<" || m.old_text == ">" || m.old_text == ">=");
+#[test]
+fn test_promise_resolve_swap() {
+    let swapped = mutated_by("PRS");
+    assert_eq!(swapped.len(), 1);
+    assert!(swapped[0].contains("return Promise.resolve(new Error(\"free item\"));"));
+}
 
-    assert!(
-        has_comparison_mutations,
-        "Should mutate actual comparison operators in TSX files"
-    );
+#[test]
+fn test_literal_mutations() {
+    let emptied = mutated_by("SLR");
+    assert_eq!(emptied.len(), 1);
+    assert!(emptied[0].contains("new Error(\"\")"));
+
+    let numbers = mutated_by("NLR");
+    assert_eq!(numbers.len(), 5, "0 -> 1 and 100 -> 0, 1, 101, 99");
+    assert!(numbers.iter().any(|t| t.contains("profile.limit ?? 101")));
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = JavaScriptLanguageEngine::new();
+    for (source, name) in [
+        (include_str!("examples/simple.js"), "simple.js"),
+        (include_str!("examples/jsx-example.jsx"), "jsx-example.jsx"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source, name);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
 }
@@ -0,0 +1,59 @@
+module examples::counter;
+
+use sui::event;
+
+const ENotOwner: u64 = 0;
+const EOverflow: u64 = 1;
+
+public struct Counter has key {
+    id: UID,
+    owner: address,
+    value: u64,
+}
+
+public struct Incremented(u64) has copy, drop;
+
+public enum Mode has copy, drop, store {
+    Open,
+    Capped { limit: u64 },
+}
+
+public fun create(ctx: &mut TxContext) {
+    let counter = Counter { id: object::new(ctx), owner: ctx.sender(), value: 0 };
+    transfer::share_object(counter);
+}
+
+public fun increment(counter: &mut Counter, mode: &Mode) {
+    let next = counter.value + 1;
+    match (mode) {
+        Mode::Open => {},
+        Mode::Capped { limit } => assert!(next <= *limit, EOverflow),
+    };
+    counter.value = next;
+    event::emit(Incremented(next));
+}
+
+public fun set_value(counter: &mut Counter, value: u64, ctx: &TxContext) {
+    assert!(counter.owner == ctx.sender(), ENotOwner);
+    counter.value = value;
+}
+
+public fun value(counter: &Counter): u64 {
+    counter.value
+}
+
+public fun sum_to(n: u64): u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while (i < n) {
+        i = i + 1;
+        if (i % 2 == 0) continue;
+        total = total + i;
+    };
+    total
+}
+
+#[test]
+fun test_sum_to() {
+    assert!(sum_to(4) == 4, 0);
+}
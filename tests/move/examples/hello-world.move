@@ -0,0 +1,85 @@
+/// A coin whose balances live in global storage
+module 0x42::basic_coin {
+    use std::signer;
+
+    friend 0x42::admin;
+
+    /// The account already has a balance
+    const EALREADY_HAS_BALANCE: u64 = 1;
+    /// Not enough balance to withdraw
+    const EINSUFFICIENT_BALANCE: u64 = 2;
+    const MAX_TRANSFER: u64 = 1_000_000u64;
+    const SYMBOL: vector<u8> = b"BASIC";
+
+    struct Coin<phantom CoinType> has store {
+        value: u64,
+    }
+
+    struct Balance<phantom CoinType> has key {
+        coin: Coin<CoinType>,
+        frozen: bool,
+    }
+
+    struct Receipt<T: copy + drop> has copy, drop {
+        amount: u64,
+        memo: T,
+    }
+
+    public fun publish_balance<CoinType>(account: &signer) {
+        let empty_coin = Coin<CoinType> { value: 0 };
+        assert!(!exists<Balance<CoinType>>(signer::address_of(account)), EALREADY_HAS_BALANCE);
+        move_to(account, Balance<CoinType> { coin: empty_coin, frozen: false });
+    }
+
+    public fun balance_of<CoinType>(owner: address): u64 acquires Balance {
+        borrow_global<Balance<CoinType>>(owner).coin.value
+    }
+
+    public fun is_frozen<CoinType>(owner: address): bool acquires Balance {
+        if (!exists<Balance<CoinType>>(owner)) {
+            return false
+        };
+        borrow_global<Balance<CoinType>>(owner).frozen
+    }
+
+    public(friend) fun freeze_account<CoinType>(owner: address) acquires Balance {
+        let balance = borrow_global_mut<Balance<CoinType>>(owner);
+        balance.frozen = true;
+    }
+
+    fun withdraw<CoinType>(addr: address, amount: u64): Coin<CoinType> acquires Balance {
+        let balance = balance_of<CoinType>(addr);
+        assert!(balance >= amount, EINSUFFICIENT_BALANCE);
+        let balance_ref = &mut borrow_global_mut<Balance<CoinType>>(addr).coin.value;
+        *balance_ref = balance - amount;
+        Coin<CoinType> { value: amount }
+    }
+
+    fun deposit<CoinType>(addr: address, check: Coin<CoinType>) acquires Balance {
+        let Coin { value } = check;
+        let coin = &mut borrow_global_mut<Balance<CoinType>>(addr).coin;
+        coin.value = coin.value + value;
+    }
+
+    public entry fun transfer<CoinType>(from: &signer, to: address, amount: u64) acquires Balance {
+        let from_addr = signer::address_of(from);
+        assert!(from_addr != to, 0);
+        assert!(amount <= MAX_TRANSFER && !is_frozen<CoinType>(from_addr), EINSUFFICIENT_BALANCE);
+        let check = withdraw<CoinType>(from_addr, amount);
+        deposit<CoinType>(to, check);
+    }
+
+    public fun receipt(amount: u64): Receipt<vector<u8>> {
+        Receipt { amount, memo: SYMBOL }
+    }
+
+    #[test_only]
+    struct TestCoin {}
+
+    #[test(account = @0x42)]
+    #[expected_failure(abort_code = EINSUFFICIENT_BALANCE)]
+    fun test_withdraw_too_much(account: signer) acquires Balance {
+        publish_balance<TestCoin>(&account);
+        let Coin { value: _ } = withdraw<TestCoin>(@0x42, 10);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
+use mewt::LanguageEngine;
+use mewt::languages::r#move::engine::MoveLanguageEngine;
+use mewt::types::Target;
+use mewt::utils::parse_source;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.move");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "Move".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_move_syntax_parses() {
+    let engine = MoveLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.move"),
+        include_str!("examples/counter.move"),
+    ] {
+        let tree = parse_source(source, &engine.tree_sitter_language()).unwrap();
+        assert!(!tree.root_node().has_error(), "{}", tree.root_node());
+    }
+}
+
+#[test]
+fn test_example_module_mutations() {
+    let source = include_str!("examples/hello-world.move");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let asr = pairs(&mutants, "ASR");
+    assert_eq!(
+        asr,
+        vec![
+            (
+                "assert!(!exists<Balance<CoinType>>(signer::address_of(account)), EALREADY_HAS_BALANCE);",
+                ""
+            ),
+            ("assert!(balance >= amount, EINSUFFICIENT_BALANCE);", ""),
+            ("assert!(from_addr != to, 0);", ""),
+            (
+                "assert!(amount <= MAX_TRANSFER && !is_frozen<CoinType>(from_addr), EINSUFFICIENT_BALANCE);",
+                ""
+            ),
+        ]
+    );
+
+    // Generic calls aren't mistaken for comparisons
+    let cos = pairs(&mutants, "COS");
+    assert!(cos.iter().all(|(old, _)| *old != "<" && *old != ">"));
+}
+
+#[test]
+fn test_global_storage_writes_are_deleted() {
+    let source = include_str!("examples/hello-world.move");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // `move_to`, and writes through `borrow_global_mut` directly or through a local
+    // holding a reference from it; reads and the borrows themselves are left alone
+    let gsd = pairs(&mutants, "GSD");
+    assert_eq!(
+        gsd,
+        vec![
+            (
+                "move_to(account, Balance<CoinType> { coin: empty_coin, frozen: false });",
+                ""
+            ),
+            ("balance.frozen = true;", ""),
+            ("*balance_ref = balance - amount;", ""),
+            ("coin.value = coin.value + value;", ""),
+        ]
+    );
+}
+
+#[test]
+fn test_abilities_are_removed_one_at_a_time() {
+    let source = include_str!("examples/hello-world.move");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let abr = pairs(&mutants, "ABR");
+    assert_eq!(
+        abr,
+        vec![
+            ("has store", ""),
+            ("has key", ""),
+            ("T: copy + drop", "T: drop"),
+            ("T: copy + drop", "T: copy"),
+            ("has copy, drop", "has drop"),
+            ("has copy, drop", "has copy"),
+        ]
+    );
+}
+
+#[test]
+fn test_error_replacement_uses_abort() {
+    let source = include_str!("examples/counter.move");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er: Vec<_> = mutants.iter().filter(|m| m.mutation_slug == "ER").collect();
+    assert!(!er.is_empty());
+    for mutant in &er {
+        assert_eq!(mutant.new_text, "abort 0;");
+        // Asserts are left to ASR
+        assert!(!mutant.old_text.starts_with("assert"));
+    }
+}
+
+#[test]
+fn test_conditions_keep_their_parentheses() {
+    let source = include_str!("examples/counter.move");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(pairs(&mutants, "IF"), vec![("i % 2 == 0", "false")]);
+    assert_eq!(pairs(&mutants, "WF"), vec![("i < n", "false")]);
+    assert_eq!(
+        pairs(&mutants, "UOI"),
+        vec![("i % 2 == 0", "!(i % 2 == 0)")]
+    );
+    assert_eq!(
+        pairs(&mutants, "RV"),
+        vec![("counter.value", "0"), ("total", "0")]
+    );
+}
+
+#[test]
+fn test_unit_tests_are_left_alone() {
+    let engine = MoveLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.move"),
+        include_str!("examples/counter.move"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source);
+        let mutants = engine.apply_all_mutations(&target);
+        let first_test = source.find("#[test").unwrap();
+        assert!(
+            mutants
+                .iter()
+                .all(|m| (m.byte_offset as usize) < first_test),
+            "{:?}",
+            mutants
+                .iter()
+                .filter(|m| (m.byte_offset as usize) >= first_test)
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_module_addresses_are_left_alone() {
+    let source = "module 0x1::vault {\n    use 0x1::signer;\n    friend 0x1::admin;\n\n    fun treasury(): address {\n        @0xcafe\n    }\n}\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = MoveLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Only the address value is zeroed; the ones naming modules are not
+    assert_eq!(pairs(&mutants, "HLZ"), vec![("0xcafe", "0x0000")]);
+    assert!(mutants.iter().all(|m| m.old_text != "0x1"));
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = MoveLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.move"),
+        include_str!("examples/counter.move"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
+}
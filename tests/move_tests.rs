@@ -0,0 +1,4 @@
+mod common;
+mod r#move {
+    mod integration_tests;
+}
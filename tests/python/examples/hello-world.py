@@ -0,0 +1,26 @@
+def add(a, b):
+    return a + b
+
+
+def classify(values, needle):
+    # Membership and identity checks
+    if needle in values:
+        return "present"
+    elif needle is None:
+        return "missing"
+
+    total = 0
+    for value in values:
+        if value < 0 and total > 10:
+            break
+        total += value
+
+    while total > 100:
+        total //= 2
+
+    print("Total:", add(total, 1))
+    return total
+
+
+if __name__ == "__main__":
+    classify([1, 2, 3], 2)
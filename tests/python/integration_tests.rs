@@ -0,0 +1,182 @@
+use crate::common::assert_no_duplicate_mutants;
+use mewt::LanguageEngine;
+use mewt::languages::python::engine::PythonLanguageEngine;
+use mewt::types::{Mutant, Target};
+use std::collections::HashSet;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.py");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "Python".to_string(),
+    };
+    (temp_dir, target)
+}
+
+fn with_slug<'a>(mutants: &'a [Mutant], slug: &str) -> Vec<&'a Mutant> {
+    mutants.iter().filter(|m| m.mutation_slug == slug).collect()
+}
+
+#[test]
+fn test_example_generates_diverse_mutations() {
+    let source = include_str!("examples/hello-world.py");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let slugs: HashSet<_> = mutants.iter().map(|m| m.mutation_slug.as_str()).collect();
+    for slug in [
+        "ER", "CR", "IF", "IT", "WF", "AS", "LC", "AOS", "AAOS", "LOS", "COS",
+    ] {
+        assert!(
+            slugs.contains(slug),
+            "Expected {slug} mutants, got {slugs:?}"
+        );
+    }
+}
+
+#[test]
+fn test_error_and_comment_replacement() {
+    let source = "def f(x):\n    print(x)\n    pass\n    return x\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er = with_slug(&mutants, "ER");
+    assert_eq!(er.len(), 2);
+    assert!(er.iter().all(|m| m.new_text == "raise Exception(\"mewt\")"));
+
+    // CR replaces statements with `pass` and never touches an existing `pass`
+    let cr = with_slug(&mutants, "CR");
+    assert_eq!(cr.len(), 2);
+    assert!(
+        cr.iter()
+            .all(|m| m.new_text == "pass" && m.old_text != "pass")
+    );
+}
+
+#[test]
+fn test_conditions_use_python_literals() {
+    let source = "if a:\n    x = 1\nelif b:\n    x = 2\nwhile c:\n    x += 1\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let if_false = with_slug(&mutants, "IF");
+    assert_eq!(if_false.len(), 2, "Should cover both if and elif");
+    assert!(if_false.iter().all(|m| m.new_text == "False"));
+    assert!(
+        with_slug(&mutants, "IT")
+            .iter()
+            .all(|m| m.new_text == "True")
+    );
+    assert_eq!(with_slug(&mutants, "WF")[0].new_text, "False");
+}
+
+#[test]
+fn test_python_specific_operators() {
+    let source = r#"
+ok = a and b
+same = x is None
+different = x is not None
+found = k in d
+missing = k not in d
+"#;
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let los = with_slug(&mutants, "LOS");
+    assert_eq!(los.len(), 1);
+    assert_eq!(
+        (los[0].old_text.as_str(), los[0].new_text.as_str()),
+        ("and", "or")
+    );
+
+    let ios: Vec<_> = with_slug(&mutants, "IOS")
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(ios, vec![("is", "is not"), ("is not", "is")]);
+
+    let mos: Vec<_> = with_slug(&mutants, "MOS")
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(mos, vec![("in", "not in"), ("not in", "in")]);
+}
+
+#[test]
+fn test_comments_not_mutated() {
+    let source = "# if a > b and c is None:\n#     return a + b\nx = 1\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert!(
+        mutants.iter().all(|m| m.line_offset == 2),
+        "Only the code line should be mutated: {mutants:?}"
+    );
+}
+
+#[test]
+fn test_literal_mutations_skip_docstrings() {
+    let source = "def fee(amount):\n    \"\"\"Fee in basis points.\"\"\"\n    label = 'bps'\n    return amount * 30\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let slr: Vec<_> = with_slug(&mutants, "SLR")
+        .iter()
+        .map(|m| (m.old_text.as_str(), m.new_text.as_str()))
+        .collect();
+    assert_eq!(slr, vec![("'bps'", "''")]);
+
+    let nlr: Vec<_> = with_slug(&mutants, "NLR")
+        .iter()
+        .map(|m| m.new_text.as_str())
+        .collect();
+    assert_eq!(nlr, vec!["0", "1", "31", "29"]);
+}
+
+#[test]
+fn test_unary_mutations() {
+    let source = "def sign(x, strict):\n    if not strict:\n        return -x\n    elif x > 0:\n        return 1\n";
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = PythonLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let pairs = |slug: &str| -> Vec<(String, String)> {
+        with_slug(&mutants, slug)
+            .iter()
+            .map(|m| (m.old_text.clone(), m.new_text.clone()))
+            .collect()
+    };
+    assert_eq!(
+        pairs("UOD"),
+        vec![
+            ("not ".to_string(), String::new()),
+            ("-".to_string(), String::new())
+        ]
+    );
+    assert!(pairs("UOR").is_empty());
+    assert_eq!(
+        pairs("UOI"),
+        vec![("x > 0".to_string(), "not (x > 0)".to_string())]
+    );
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = PythonLanguageEngine::new();
+    let source = include_str!("examples/hello-world.py");
+    let (_temp_dir, target) = create_test_target(source);
+    assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+}
@@ -0,0 +1,4 @@
+mod common;
+mod python {
+    mod integration_tests;
+}
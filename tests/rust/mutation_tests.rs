@@ -1,3 +1,4 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
 use mewt::LanguageEngine;
 use mewt::languages::rust::engine::RustLanguageEngine;
 use mewt::types::{Hash, Target};
@@ -210,3 +211,226 @@ fn test_func() -> i32 {
         "Should generate variable-related mutations"
     );
 }
+
+#[test]
+fn test_function_body_mutations() {
+    let source = r#"
+fn total(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn log(msg: &str) {
+    println!("{msg}");
+}
+
+fn iter() -> impl Iterator<Item = u32> {
+    0..3
+}
+"#;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let fbt = pairs(&mutants, "FBT");
+    assert_eq!(fbt.len(), 3);
+    assert!(fbt.iter().all(|&(_, new)| new == "{ todo!() }"));
+
+    // Unit functions are emptied and `impl Trait` returns are skipped
+    assert_eq!(
+        pairs(&mutants, "FBD"),
+        vec![
+            ("{\n    a + b\n}", "{ Default::default() }"),
+            ("{\n    println!(\"{msg}\");\n}", "{}"),
+        ]
+    );
+}
+
+#[test]
+fn test_result_and_option_mutations() {
+    let source = r#"
+fn parse(s: &str) -> Result<Option<u32>, std::num::ParseIntError> {
+    let n = s.parse::<u32>()?;
+    if n == 0 {
+        return Ok(None);
+    }
+    Ok(Some(n))
+}
+"#;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "QMU"),
+        vec![("s.parse::<u32>()?", "s.parse::<u32>().unwrap()")]
+    );
+    assert_eq!(
+        pairs(&mutants, "QMR"),
+        vec![("s.parse::<u32>()?", "s.parse::<u32>()")]
+    );
+    assert_eq!(pairs(&mutants, "SN"), vec![("Some(n)", "None")]);
+    assert_eq!(
+        pairs(&mutants, "OE"),
+        vec![
+            ("Ok(None)", "Err(Default::default())"),
+            ("Ok(Some(n))", "Err(Default::default())"),
+        ]
+    );
+}
+
+#[test]
+fn test_match_arm_deletion_requires_wildcard() {
+    let source = r#"
+fn name(n: u8) -> &'static str {
+    match n {
+        0 => "zero",
+        1 => {
+            "one"
+        }
+        _ => "many",
+    }
+}
+
+fn exhaustive(b: bool) -> u8 {
+    match b {
+        true => 1,
+        false => 0,
+    }
+}
+"#;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "MAD"),
+        vec![
+            ("0 => \"zero\",", ""),
+            ("1 => {\n            \"one\"\n        }", ""),
+        ]
+    );
+}
+
+#[test]
+fn test_range_and_overflow_mutations() {
+    let source = r#"
+fn sum(a: u8, b: u8, xs: &[u8]) -> u8 {
+    let mut acc = a.checked_add(b).unwrap_or(0);
+    for i in 0..xs.len() {
+        acc = acc.wrapping_shl(xs[i] as u32);
+    }
+    for x in &xs[1..] {
+        acc = acc.saturating_mul(*x);
+    }
+    (1..=3).count() as u8 + acc
+}
+"#;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // The open range `1..` is left alone
+    assert_eq!(pairs(&mutants, "RGS"), vec![("..", "..="), ("..=", "..")]);
+    assert_eq!(
+        pairs(&mutants, "OAS"),
+        vec![
+            ("checked_add", "saturating_add"),
+            ("checked_add", "wrapping_add"),
+            ("wrapping_shl", "checked_shl"),
+            ("saturating_mul", "checked_mul"),
+            ("saturating_mul", "wrapping_mul"),
+        ]
+    );
+}
+
+#[test]
+fn test_literal_mutations() {
+    let source = r##"
+#[cfg(feature = "fees")]
+const FEE_BPS: u16 = 30_u16;
+const MASK: u32 = 0xFF;
+
+fn label(fee: u16) -> String {
+    format!("{}", fee + FEE_BPS) + r#"bps"#
+}
+"##;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "NLR"),
+        vec![
+            ("30_u16", "0_u16"),
+            ("30_u16", "1_u16"),
+            ("30_u16", "31_u16"),
+            ("30_u16", "29_u16"),
+        ]
+    );
+    // Attribute arguments and format strings must stay intact to compile
+    assert_eq!(pairs(&mutants, "SLR"), vec![("r#\"bps\"#", "r#\"\"#")]);
+    assert_eq!(pairs(&mutants, "HLZ"), vec![("0xFF", "0x00")]);
+}
+
+#[test]
+fn test_return_value_mutations() {
+    let source = r#"
+fn total(xs: &[u32]) -> u32 {
+    if xs.is_empty() {
+        return 0;
+    }
+    let double = |x: u32| -> u32 { return x * 2; };
+    xs.iter().map(|x| double(*x)).sum()
+}
+
+fn log(msg: &str) {
+    println!("{msg}")
+}
+
+fn first() -> Option<u32> {
+    Some(3)
+}
+
+fn parse(s: &str) -> std::io::Result<u32> {
+    let n = s.len();
+    Ok(n as u32)
+}
+
+fn name(names: &[String]) -> &str {
+    let first = &names[0];
+    first.as_str()
+}
+
+fn count(xs: &[u32]) -> usize {
+    let n = xs.len();
+    n
+}
+"#;
+
+    let target = rust_target_from_source(source);
+    let engine = RustLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Closures, unit functions, `Result`s, references, literals and tails that make up the
+    // whole body are skipped
+    assert_eq!(
+        pairs(&mutants, "RV"),
+        vec![
+            ("xs.iter().map(|x| double(*x)).sum()", "Default::default()"),
+            ("n", "Default::default()"),
+        ]
+    );
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = RustLanguageEngine::new();
+    let source = include_str!("examples/hello-world.rs");
+    let target = rust_target_from_source(source);
+    assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+}
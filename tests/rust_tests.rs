@@ -1,3 +1,4 @@
+mod common;
 mod rust {
     mod comment_ignorance_tests;
     mod integration_tests;
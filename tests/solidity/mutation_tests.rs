@@ -1,3 +1,4 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
 use mewt::LanguageEngine;
 use mewt::languages::solidity::engine::SolidityLanguageEngine;
 use mewt::types::{Hash, Target};
@@ -255,3 +256,188 @@ contract Test {
         "Should generate loop-related mutations"
     );
 }
+
+const VAULT: &str = r#"
+pragma solidity ^0.8.0;
+
+contract Vault is Base {
+    mapping(address => uint256) balances;
+    event Withdrawn(address to, uint256 amount);
+
+    constructor() Base("vault") {}
+
+    function withdraw(address to, uint256 amount) internal onlyOwner nonReentrant {
+        require(amount > 0, "zero");
+        assert(to != address(0));
+        if (balances[msg.sender] < amount) revert Insufficient(amount);
+        unchecked {
+            balances[msg.sender] -= amount;
+        }
+        Position storage p = positions[to];
+        emit Withdrawn(to, amount);
+        delete positions[to];
+    }
+
+    function helper(bytes memory data) private pure returns (bool) {
+        require(valid);
+        return data.length > 0;
+    }
+}
+"#;
+
+#[test]
+fn test_access_control_mutations() {
+    let target = solidity_target_from_source(VAULT);
+    let engine = SolidityLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Base constructor calls are not modifiers
+    assert_eq!(
+        pairs(&mutants, "MR"),
+        vec![("onlyOwner", ""), ("nonReentrant", "")]
+    );
+    assert_eq!(
+        pairs(&mutants, "VW"),
+        vec![("internal", "public"), ("private", "public")]
+    );
+
+    let msr = pairs(&mutants, "MSR");
+    assert_eq!(msr.len(), 2);
+    assert!(
+        msr.iter()
+            .all(|&(old, new)| old == "msg.sender" && new == "tx.origin")
+    );
+
+    // address(0) itself is not replaced with address(0)
+    let az = pairs(&mutants, "AZ");
+    assert_eq!(az.len(), 2);
+    assert!(az.iter().all(|&(old, _)| old == "msg.sender"));
+}
+
+#[test]
+fn test_require_mutations() {
+    let target = solidity_target_from_source(VAULT);
+    let engine = SolidityLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // A braceless if body becomes an empty block rather than vanishing
+    assert_eq!(
+        pairs(&mutants, "RD"),
+        vec![
+            ("require(amount > 0, \"zero\");", ""),
+            ("assert(to != address(0));", ""),
+            ("require(valid);", ""),
+            ("revert Insufficient(amount);", "{}"),
+        ]
+    );
+    assert_eq!(
+        pairs(&mutants, "RN"),
+        vec![
+            ("amount > 0", "!(amount > 0)"),
+            ("to != address(0)", "!(to != address(0))"),
+            ("valid", "!valid"),
+        ]
+    );
+}
+
+#[test]
+fn test_state_mutations() {
+    let target = solidity_target_from_source(VAULT);
+    let engine = SolidityLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "UBU"),
+        vec![(
+            "unchecked {\n            balances[msg.sender] -= amount;\n        }",
+            "{\n            balances[msg.sender] -= amount;\n        }"
+        )]
+    );
+    assert_eq!(
+        pairs(&mutants, "ED"),
+        vec![("emit Withdrawn(to, amount);", "")]
+    );
+    assert_eq!(pairs(&mutants, "DR"), vec![("delete positions[to];", "")]);
+    assert_eq!(
+        pairs(&mutants, "SMS"),
+        vec![("storage", "memory"), ("memory", "storage")]
+    );
+}
+
+#[test]
+fn test_literal_mutations() {
+    let source = r#"
+import "./IERC20.sol";
+
+contract Fees {
+    address constant TREASURY = 0x000000000000000000000000000000000000dEaD;
+    uint256 constant FEE = 1 ether;
+
+    function check(uint256 amount) public pure {
+        require(amount > 0, "zero");
+    }
+}
+"#;
+
+    let target = solidity_target_from_source(source);
+    let engine = SolidityLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "HLZ"),
+        vec![(
+            "0x000000000000000000000000000000000000dEaD",
+            "0x0000000000000000000000000000000000000000"
+        )]
+    );
+    assert_eq!(
+        pairs(&mutants, "NLR"),
+        vec![("1 ether", "0 ether"), ("1 ether", "2 ether"), ("0", "1")]
+    );
+    // The import path is left alone
+    assert_eq!(pairs(&mutants, "SLR"), vec![("\"zero\"", "\"\"")]);
+}
+
+#[test]
+fn test_return_value_mutations() {
+    let source = r#"
+contract Registry {
+    function ownerOf(uint256 id) public view returns (address) {
+        return owners[id];
+    }
+
+    function isActive(uint256 id) public view returns (bool) {
+        return active[id];
+    }
+
+    function slot(uint256 id) public pure returns (bytes32) {
+        return keccak256(abi.encode(id));
+    }
+
+    function both(uint256 id) public view returns (address, bool) {
+        return (owners[id], active[id]);
+    }
+}
+"#;
+
+    let target = solidity_target_from_source(source);
+    let engine = SolidityLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert_eq!(
+        pairs(&mutants, "RV"),
+        vec![
+            ("owners[id]", "address(0)"),
+            ("active[id]", "false"),
+            ("keccak256(abi.encode(id))", "bytes32(0)"),
+        ]
+    );
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = SolidityLanguageEngine::new();
+    let source = include_str!("examples/hello-world.sol");
+    let target = solidity_target_from_source(source);
+    assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+}
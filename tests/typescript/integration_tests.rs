@@ -0,0 +1,318 @@
+use crate::common::assert_no_duplicate_mutants;
+use mewt::LanguageEngine;
+use mewt::languages::typescript::engine::TypeScriptLanguageEngine;
+use mewt::types::Target;
+use tempfile::tempdir;
+
+fn create_test_target(content: &str, filename: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join(filename);
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "TypeScript".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_typescript_support() {
+    let source = r#"
+interface User {
+    name: string;
+    age: number;
+}
+
+function greet(user: User): string {
+    if (user.age > 18) {
+        return `Hello, ${user.name}!`;
+    }
+    return "Hello!";
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source, "test.ts");
+    let engine = TypeScriptLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    assert!(
+        !mutants.is_empty(),
+        "Should generate mutations for TypeScript"
+    );
+}
+
+#[test]
+fn test_typescript_generics_not_mutated() {
+    let source = r#"
+// TypeScript generics should NOT be mutated
+const emitter = module.get<EventEmitter2>(EventEmitter2);
+const result = foo<string, number>(arg1, arg2);
+
+function generic<T>(value: T): T {
+    return value;
+}
+
+// Real comparisons SHOULD be mutated
+if (a < b && c > d) {
+    return true;
+}
+
+const max = x >= y ? x : y;
+"#;
+    let (_temp_dir, target) = create_test_target(source, "test.ts");
+    let engine = TypeScriptLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Filter to just COS mutations
+    let cos_mutants: Vec<_> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug.starts_with("COS"))
+        .collect();
+
+    // Should have COS mutations (from the actual comparison operators)
+    assert!(
+        !cos_mutants.is_empty(),
+        "Should generate COS mutations for real comparison operators"
+    );
+
+    // Verify no mutations contain "get<", "foo<", or "generic<"
+    // (these would indicate mutations of TypeScript generics)
+    for mutant in &cos_mutants {
+        assert!(
+            !mutant.new_text.contains("get<")
+                && !mutant.new_text.contains("get==")
+                && !mutant.new_text.contains("get!=")
+                && !mutant.new_text.contains("get<=")
+                && !mutant.new_text.contains("get>=")
+                && !mutant.new_text.contains("foo<")
+                && !mutant.new_text.contains("foo==")
+                && !mutant.new_text.contains("foo!=")
+                && !mutant.new_text.contains("foo<=")
+                && !mutant.new_text.contains("foo>=")
+                && !mutant.new_text.contains("generic<")
+                && !mutant.new_text.contains("generic==")
+                && !mutant.new_text.contains("generic!=")
+                && !mutant.new_text.contains("generic<=")
+                && !mutant.new_text.contains("generic>="),
+            "COS mutation should not mutate TypeScript generic brackets: {}",
+            mutant.new_text
+        );
+    }
+
+    // Verify we have mutations for the actual comparison operators
+    // (The old_text will just be the operator, not the full expression)
+    let has_less_than_mutation = cos_mutants.iter().any(|m| m.old_text == "<");
+    let has_greater_than_mutation = cos_mutants.iter().any(|m| m.old_text == ">");
+    let has_gte_mutation = cos_mutants.iter().any(|m| m.old_text == ">=");
+
+    assert!(
+        has_less_than_mutation && has_greater_than_mutation && has_gte_mutation,
+        "Should mutate actual comparison operators (<, >, >=) in conditions"
+    );
+}
+
+#[test]
+fn test_tsx_jsx_and_generics_not_mutated() {
+    let source = r#"
+// TSX with both JSX elements and TypeScript generics
+function App<T>(props: { value: T }) {
+    return <div>Hello</div>;
+}
+
+const result = foo<string, number>(arg1, arg2);
+const element = <Component prop="value" />;
+
+// Real comparisons SHOULD be mutated
+if (a < b && c > d) {
+    return true;
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source, "test.tsx");
+    let engine = TypeScriptLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Filter to just COS mutations
+    let cos_mutants: Vec<_> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug.starts_with("COS"))
+        .collect();
+
+    // Should have COS mutations (from the actual comparison operators)
+    assert!(
+        !cos_mutants.is_empty(),
+        "Should generate COS mutations for real comparison operators"
+    );
+
+    // Verify no mutations contain JSX tags or generic brackets
+    for mutant in &cos_mutants {
+        let text = &mutant.new_text;
+        assert!(
+            !text.contains("<div")
+                && !text.contains("</div")
+                && !text.contains("<Component")
+                && !text.contains("App<")
+                && !text.contains("foo<")
+                && !text.contains("foo==")
+                && !text.contains("foo!=")
+                && !text.contains("App==")
+                && !text.contains("App!="),
+            "COS mutation should not mutate TSX JSX elements or TypeScript generics: {}",
+            text
+        );
+    }
+
+    // Verify we have mutations for the actual comparison operators
+    let has_comparison_mutations = cos_mutants
+        .iter()
+        .any(|m| m.old_text == "<" || m.old_text == ">" || m.old_text == ">=");
+
+    assert!(
+        has_comparison_mutations,
+        "Should mutate actual comparison operators in TSX files"
+    );
+}
+
+#[test]
+fn test_non_null_assertion_removal() {
+    let source = r#"
+function len(user?: { name: string }): number {
+    return user!.name.length;
+}
+"#;
+    let (_temp_dir, target) = create_test_target(source, "test.ts");
+    let engine = TypeScriptLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let nnr: Vec<_> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug == "NNR")
+        .collect();
+    assert_eq!(nnr.len(), 1, "Should remove the single non-null assertion");
+    assert_eq!(nnr[0].old_text, "user!");
+    assert_eq!(nnr[0].new_text, "user");
+}
+
+#[test]
+fn test_optional_chaining_removal() {
+    let source = r#"
+const a = user?.name;
+const b = items?.[0];
+const c = callback?.();
+"#;
+    let (_temp_dir, target) = create_test_target(source, "test.ts");
+    let engine = TypeScriptLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let mutated: Vec<String> = mutants
+        .iter()
+        .filter(|m| m.mutation_slug == "OCR")
+        .map(|m| target.mutate(m).expect("Failed to apply mutant"))
+        .collect();
+
+    assert_eq!(mutated.len(), 3, "Should mutate each optional chain");
+    assert!(mutated.iter().any(|t| t.contains("const a = user.name;")));
+    assert!(mutated.iter().any(|t| t.contains("const b = items[0];")));
+    assert!(mutated.iter().any(|t| t.contains("const c = callback();")));
+}
+
+fn mutated_by(source: &str, slug: &str) -> Vec<String> {
+    let (_temp_dir, target) = create_test_target(source, "test.ts");
+    let engine = TypeScriptLanguageEngine::new();
+    engine
+        .apply_all_mutations(&target)
+        .iter()
+        .filter(|m| m.mutation_slug == slug)
+        .map(|m| target.mutate(m).expect("Failed to apply mutant"))
+        .collect()
+}
+
+#[test]
+fn test_assignment_operator_shuffles() {
+    let source = r#"
+function update(state: { total: number; mask: number; bits: number }, n: number): void {
+    state.total += n;
+    state.mask &= n;
+    state.bits <<= n;
+}
+"#;
+    let aaos = mutated_by(source, "AAOS");
+    assert_eq!(
+        aaos.len(),
+        5,
+        "Should shuffle += with the other five operators"
+    );
+    assert!(aaos.iter().any(|t| t.contains("state.total -= n;")));
+    assert!(aaos.iter().any(|t| t.contains("state.total **= n;")));
+
+    let baos = mutated_by(source, "BAOS");
+    assert_eq!(baos.len(), 2);
+    assert!(baos.iter().any(|t| t.contains("state.mask |= n;")));
+    assert!(baos.iter().any(|t| t.contains("state.mask ^= n;")));
+
+    let saos = mutated_by(source, "SAOS");
+    assert_eq!(saos.len(), 2);
+    assert!(saos.iter().any(|t| t.contains("state.bits >>= n;")));
+    assert!(saos.iter().any(|t| t.contains("state.bits >>>= n;")));
+
+    // Compound assignments aren't binary expressions
+    assert!(mutated_by(source, "AOS").is_empty());
+}
+
+#[test]
+fn test_type_assertion_removal() {
+    let source = r#"
+const input = document.getElementById("name") as HTMLInputElement;
+const size = (payload as { size: number }).size;
+"#;
+    let mutated = mutated_by(source, "TAR");
+    assert_eq!(mutated.len(), 2, "Should remove each as cast");
+    assert!(
+        mutated
+            .iter()
+            .any(|t| t.contains("const input = document.getElementById(\"name\");"))
+    );
+    assert!(
+        mutated
+            .iter()
+            .any(|t| t.contains("const size = (payload).size;"))
+    );
+}
+
+#[test]
+fn test_enum_member_swap() {
+    let source = r#"
+enum Role { Guest, Member, Admin = 10, Owner }
+"#;
+    let mutated = mutated_by(source, "EMS");
+    // Only Guest/Member both take implicit values; Admin keeps its initializer
+    assert_eq!(
+        mutated,
+        vec!["\nenum Role { Member, Guest, Admin = 10, Owner }\n".to_string()]
+    );
+}
+
+#[test]
+fn test_javascript_files_not_claimed() {
+    let engine = TypeScriptLanguageEngine::new();
+    assert_eq!(engine.name(), "TypeScript");
+    assert!(engine.extensions().contains(&"ts"));
+    assert!(engine.extensions().contains(&"tsx"));
+    assert!(!engine.extensions().contains(&"js"));
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = TypeScriptLanguageEngine::new();
+    for (source, name) in [
+        (include_str!("examples/typescript.ts"), "typescript.ts"),
+        (
+            include_str!("examples/typescript-generics.ts"),
+            "typescript-generics.ts",
+        ),
+    ] {
+        let (_temp_dir, target) = create_test_target(source, name);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
+}
@@ -0,0 +1,29 @@
+# @version ^0.3.9
+
+event Transfer:
+    sender: indexed(address)
+    amount: uint256
+
+owner: public(address)
+balances: HashMap[address, uint256]
+
+@external
+def __init__():
+    self.owner = msg.sender
+
+@internal
+def _check(amount: uint256):
+    assert amount > 0, "zero"
+
+@external
+@payable
+def transfer(to: address, amount: uint256) -> bool:
+    self._check(amount)
+    assert self.balances[msg.sender] >= amount
+    self.balances[msg.sender] = unsafe_sub(self.balances[msg.sender], amount)
+    self.balances[to] += amount
+    for i in range(10):
+        if i == 3:
+            break
+    log Transfer(msg.sender, amount)
+    return True
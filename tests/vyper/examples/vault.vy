@@ -0,0 +1,41 @@
+# pragma version ^0.4.0
+from ethereum.ercs import IERC20
+
+implements: IERC20
+uses: ownable
+exports: ownable.owner
+
+interface Oracle:
+    def price(asset: address) -> uint256: view
+    def update(asset: address, value: uint256): nonpayable
+
+event Transfer:
+    sender: indexed(address)
+    receiver: indexed(address)
+    value: uint256
+
+struct Position:
+    owner: address
+    size: uint256
+
+flag Role:
+    ADMIN
+    USER
+
+MAX: constant(uint256) = 100
+balances: public(HashMap[address, uint256])
+
+@external
+def transfer(to: address, amount: uint256) -> bool:
+    assert self.balances[msg.sender] >= amount, "insufficient"
+    self.balances[msg.sender] -= amount
+    self.balances[to] += amount
+    log Transfer(msg.sender, to, amount)
+    for i: uint256 in range(10):
+        if i > 5:
+            break
+    p: uint256 = staticcall Oracle(self).price(to)
+    extcall IERC20(to).transfer(msg.sender, p + 1)
+    if amount == 0:
+        raise "zero"
+    return True
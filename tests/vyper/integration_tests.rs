@@ -0,0 +1,138 @@
+use crate::common::{assert_no_duplicate_mutants, pairs};
+use mewt::LanguageEngine;
+use mewt::languages::vyper::engine::VyperLanguageEngine;
+use mewt::types::Target;
+use mewt::utils::parse_source;
+use tempfile::tempdir;
+
+/// Helper to create test target
+fn create_test_target(content: &str) -> (tempfile::TempDir, Target) {
+    let temp_dir = tempdir().expect("Failed to create temp directory");
+    let file_path = temp_dir.path().join("test.vy");
+    std::fs::write(&file_path, content).expect("Failed to write test file");
+    let target = Target {
+        id: 1,
+        path: file_path,
+        file_hash: mewt::types::Hash::digest(content.to_string()),
+        text: content.to_string(),
+        language: "Vyper".to_string(),
+    };
+    (temp_dir, target)
+}
+
+#[test]
+fn test_example_contract_mutations() {
+    let source = include_str!("examples/hello-world.vy");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = VyperLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Decorators swap between external and internal; @payable is left alone
+    let ds = pairs(&mutants, "DS");
+    assert_eq!(
+        ds,
+        vec![
+            ("@external", "@internal"),
+            ("@internal", "@external"),
+            ("@external", "@internal"),
+        ]
+    );
+
+    let msr = pairs(&mutants, "MSR");
+    assert_eq!(msr.len(), 5, "Every msg.sender should be replaced");
+    assert!(
+        msr.iter()
+            .all(|&(old, new)| old == "msg.sender" && new == "tx.origin")
+    );
+
+    let asr = pairs(&mutants, "ASR");
+    assert_eq!(asr.len(), 2);
+    assert!(
+        asr.iter()
+            .all(|&(old, new)| old.starts_with("assert") && new == "pass")
+    );
+
+    let ums = pairs(&mutants, "UMS");
+    assert_eq!(ums.len(), 3);
+    assert!(ums.iter().all(|&(old, _)| old == "unsafe_sub"));
+}
+
+#[test]
+fn test_error_replacement_only_inside_functions() {
+    let source = include_str!("examples/hello-world.vy");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = VyperLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    let er: Vec<_> = mutants.iter().filter(|m| m.mutation_slug == "ER").collect();
+    assert!(!er.is_empty());
+    for mutant in &er {
+        assert_eq!(mutant.new_text, "raise");
+        // Storage declarations and event members are never replaced
+        assert!(!mutant.old_text.starts_with("owner:"));
+        assert!(!mutant.old_text.starts_with("sender:"));
+    }
+    assert!(
+        er.iter()
+            .any(|m| m.old_text == "log Transfer(msg.sender, amount)")
+    );
+    assert!(mutants.iter().all(|m| m.mutation_slug != "WF"));
+}
+
+#[test]
+fn test_vyper_declarations_parse() {
+    let engine = VyperLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.vy"),
+        include_str!("examples/vault.vy"),
+    ] {
+        let tree = parse_source(source, &engine.tree_sitter_language()).unwrap();
+        assert!(!tree.root_node().has_error(), "{}", tree.root_node());
+    }
+}
+
+#[test]
+fn test_declarations_are_left_alone() {
+    let source = include_str!("examples/vault.vy");
+    let (_temp_dir, target) = create_test_target(source);
+    let engine = VyperLanguageEngine::new();
+    let mutants = engine.apply_all_mutations(&target);
+
+    // Interface mutability markers, event and struct members and flag values aren't
+    // statements
+    for mutant in mutants
+        .iter()
+        .filter(|m| ["ER", "CR"].contains(&m.mutation_slug.as_str()))
+    {
+        for declared in [
+            "view",
+            "nonpayable",
+            "owner: address",
+            "ADMIN",
+            "implements",
+        ] {
+            assert!(!mutant.old_text.starts_with(declared), "{mutant:?}");
+        }
+    }
+
+    // Statements in typed `for` loops and external calls are
+    let cr = pairs(&mutants, "CR");
+    assert!(
+        cr.iter()
+            .any(|&(old, _)| old.starts_with("for i: uint256 in"))
+    );
+    assert!(cr.contains(&("extcall IERC20(to).transfer(msg.sender, p + 1)", "pass")));
+    assert!(cr.contains(&("log Transfer(msg.sender, to, amount)", "pass")));
+}
+
+#[test]
+fn test_no_duplicate_mutants() {
+    let engine = VyperLanguageEngine::new();
+    for source in [
+        include_str!("examples/hello-world.vy"),
+        include_str!("examples/vault.vy"),
+    ] {
+        let (_temp_dir, target) = create_test_target(source);
+        assert_no_duplicate_mutants(&engine.apply_all_mutations(&target));
+    }
+}
@@ -0,0 +1,4 @@
+mod common;
+mod vyper {
+    mod integration_tests;
+}